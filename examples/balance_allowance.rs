@@ -7,10 +7,8 @@ use std::env;
 
 /// Helper to fail fast if a required environment variable is missing.
 fn env_var(key: &str) -> String {
-    env::var(key).expect(&format!(
-        "{} must be set for the balance allowance example",
-        key
-    ))
+    env::var(key)
+        .unwrap_or_else(|_| panic!("{} must be set for the balance allowance example", key))
 }
 
 #[tokio::main]
@@ -27,8 +25,10 @@ async fn main() -> Result<()> {
     let creds = l1_client.create_or_derive_api_key(None).await?;
     let client = ClobClient::with_l2_headers(&base_url, &private_key, chain_id, creds.clone());
 
-    let mut params = BalanceAllowanceParams::default();
-    params.asset_type = Some(AssetType::COLLATERAL);
+    let params = BalanceAllowanceParams {
+        asset_type: Some(AssetType::COLLATERAL),
+        ..Default::default()
+    };
 
     let balances = client.get_balance_allowance(Some(params)).await?;
     println!("balance allowance response: {balances:#}");
@@ -3,10 +3,7 @@ use std::env;
 
 /// Helper to fail fast if a required environment variable is missing.
 fn env_var(key: &str) -> String {
-    env::var(key).expect(&format!(
-        "{} must be set for the new_with_auth example",
-        key
-    ))
+    env::var(key).unwrap_or_else(|_| panic!("{} must be set for the new_with_auth example", key))
 }
 
 #[tokio::main]
@@ -33,7 +33,7 @@ async fn main() -> Result<()> {
         market.condition_id, market.liquidity_num
     );
 
-    let asset_ids = derive_asset_ids(market).unwrap_or_else(|| Vec::new());
+    let asset_ids = derive_asset_ids(market).unwrap_or_default();
 
     if asset_ids.is_empty() {
         return Err(PolyError::validation(
@@ -62,6 +62,14 @@ async fn main() -> Result<()> {
                     book.asks.len()
                 );
             }
+            Ok(WssMarketEvent::AggBook(book)) => {
+                println!(
+                    "agg book {} bids={} asks={}",
+                    book.market,
+                    book.bids.len(),
+                    book.asks.len()
+                );
+            }
             Ok(WssMarketEvent::TickSizeChange(change)) => {
                 println!(
                     "tick size change {} from {} to {}",
@@ -74,6 +82,9 @@ async fn main() -> Result<()> {
                     trade.market, trade.side, trade.price
                 );
             }
+            Ok(WssMarketEvent::Resync { asset_id }) => {
+                println!("missed updates on {} during reconnect, resyncing", asset_id);
+            }
             Err(err) => {
                 eprintln!("stream error: {}", err);
                 break;
@@ -0,0 +1,59 @@
+use polysqueeze::errors::{PolyError, Result};
+use polysqueeze::shutdown_signal;
+use polysqueeze::wss::{WssMarketClient, WssMarketEvent};
+use std::env;
+
+/// Stream market events until Ctrl-C or SIGTERM, then exit cleanly.
+///
+/// Set `POLY_WSS_ASSET_IDS` to a comma-separated list of token IDs.
+#[tokio::main]
+async fn main() -> Result<()> {
+    let asset_ids: Vec<String> = env::var("POLY_WSS_ASSET_IDS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect();
+    if asset_ids.is_empty() {
+        return Err(PolyError::validation(
+            "POLY_WSS_ASSET_IDS must list at least one token ID",
+        ));
+    }
+
+    let mut client = WssMarketClient::new();
+    client.subscribe(asset_ids.clone()).await?;
+    println!("Subscribed to {:?}; press Ctrl-C to stop", asset_ids);
+
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => {
+                println!("shutdown requested, stopping");
+                break;
+            }
+            event = client.next_event() => match event {
+                Ok(WssMarketEvent::Book(book)) => {
+                    println!("book {} bids={} asks={}", book.asset_id, book.bids.len(), book.asks.len());
+                }
+                Ok(WssMarketEvent::LastTrade(trade)) => {
+                    println!("last_trade {} {:?}@{}", trade.asset_id, trade.side, trade.price);
+                }
+                Ok(other) => println!("{}", other.event_type()),
+                Err(err) => {
+                    eprintln!("stream error: {}", err);
+                    break;
+                }
+            },
+        }
+    }
+
+    let stats = client.stats();
+    println!(
+        "received {} messages, {} reconnects",
+        stats.messages_received, stats.reconnect_count
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Pagination cursors returned by [`GammaClient::get_markets`](super::GammaClient::get_markets).
+//!
+//! Gamma itself paginates with plain `limit`/`offset` query parameters. To
+//! keep the `next_cursor` shape of the CLOB API, the client wraps the next
+//! offset as standard base64 of its decimal string, so offset `50` becomes
+//! `"NTA="`. Cursors can be stored and handed back to `get_markets` later.
+//!
+//! The CLOB endpoints (`/markets`, `/sampling-markets`, ...) use their own
+//! opaque cursors (`"MA=="` to start, `"LTE="` at the end); do not decode
+//! those with this module.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Encode a Gamma offset as a cursor.
+pub fn encode(offset: u64) -> String {
+    STANDARD.encode(offset.to_string())
+}
+
+/// Decode a cursor produced by [`encode`] back into an offset.
+///
+/// Returns `None` for anything that is not base64 of a decimal `u64`.
+pub fn decode(cursor: &str) -> Option<u64> {
+    STANDARD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|s| s.parse::<u64>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        assert_eq!(encode(50), "NTA=");
+        for offset in [0, 1, 50, 12_345, u64::MAX] {
+            assert_eq!(decode(&encode(offset)), Some(offset));
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_cursor() {
+        assert_eq!(decode(""), None);
+        assert_eq!(decode("not base64!"), None);
+        // Valid base64, but of "abc".
+        assert_eq!(decode("YWJj"), None);
+        // Valid base64 of "-1".
+        assert_eq!(decode("LTE="), None);
+    }
+}
@@ -0,0 +1,336 @@
+//! Data API client for Polymarket wallet positions and trades
+
+use crate::errors::{PolyError, Result};
+use crate::types::{DataPositionValue, Position, PositionListParams, Trade, TradeQuery};
+use reqwest::Client;
+
+const DEFAULT_DATA_BASE: &str = "https://data-api.polymarket.com";
+
+#[derive(Debug, Clone)]
+pub struct DataClient {
+    http_client: Client,
+    base_url: String,
+}
+
+impl DataClient {
+    pub fn new() -> Self {
+        Self {
+            http_client: Client::new(),
+            base_url: DEFAULT_DATA_BASE.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, url: &str) -> Self {
+        self.base_url = url.to_string();
+        self
+    }
+
+    pub fn data_url(&self, path: &str) -> String {
+        let base = self.base_url.trim_end_matches('/');
+        let path = path.trim_start_matches('/');
+        if path.is_empty() {
+            base.to_string()
+        } else {
+            format!("{}/{}", base, path)
+        }
+    }
+
+    /// Total value of the positions held by `user`, from `/value`.
+    pub async fn get_total_positions_value(&self, user: &str) -> Result<Vec<DataPositionValue>> {
+        let response = self
+            .http_client
+            .get(self.data_url("value"))
+            .query(&[("user", user)])
+            .send()
+            .await
+            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to fetch total positions value",
+            ));
+        }
+
+        response
+            .json::<Vec<DataPositionValue>>()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse positions value: {}", e), None))
+    }
+
+    /// Every open position held by `user` (a proxy wallet address).
+    pub async fn get_positions(&self, user: &str) -> Result<Vec<Position>> {
+        self.get_positions_with_params(user, &PositionListParams::default())
+            .await
+    }
+
+    /// Positions held by `user`, narrowed by market and redeemable status.
+    pub async fn get_positions_with_params(
+        &self,
+        user: &str,
+        params: &PositionListParams,
+    ) -> Result<Vec<Position>> {
+        let mut query = vec![("user", user.to_string())];
+        query.extend(params.to_query_params());
+
+        let response = self
+            .http_client
+            .get(self.data_url("positions"))
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to fetch positions",
+            ));
+        }
+
+        response
+            .json::<Vec<Position>>()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse positions: {}", e), None))
+    }
+
+    /// Trades matching `params`, newest first.
+    pub async fn get_trades(&self, params: &TradeQuery) -> Result<Vec<Trade>> {
+        let response = self
+            .http_client
+            .get(self.data_url("trades"))
+            .query(&params.to_query_params())
+            .send()
+            .await
+            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to fetch trades",
+            ));
+        }
+
+        let trades = response
+            .json::<Vec<Trade>>()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse trades: {}", e), None))?;
+        Ok(trades
+            .into_iter()
+            .filter(|trade| params.in_range(trade))
+            .collect())
+    }
+}
+
+impl Default for DataClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Side;
+    use chrono::{TimeZone, Utc};
+    use mockito::{Matcher, Server};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_data_url_joins_base_and_path() {
+        let client = DataClient::new().with_base_url("http://localhost:1234/");
+        assert_eq!(
+            client.data_url("/positions"),
+            "http://localhost:1234/positions"
+        );
+        assert_eq!(client.data_url(""), "http://localhost:1234");
+        assert_eq!(
+            DataClient::new().data_url("positions"),
+            "https://data-api.polymarket.com/positions"
+        );
+    }
+
+    #[test]
+    fn test_position_params_to_query() {
+        let params = PositionListParams::builder()
+            .market(vec!["0xa".to_string(), "0xb".to_string()])
+            .redeemable(true)
+            .limit(25);
+        assert_eq!(
+            params.to_query_params(),
+            vec![
+                ("market", "0xa,0xb".to_string()),
+                ("redeemable", "true".to_string()),
+                ("limit", "25".to_string()),
+            ]
+        );
+        assert!(PositionListParams::default().to_query_params().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_parses_and_filters_time_range() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/trades")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("user".into(), "0xwallet".into()),
+                Matcher::UrlEncoded("market".into(), "0xcond".into()),
+                Matcher::UrlEncoded("takerOnly".into(), "false".into()),
+                Matcher::UrlEncoded("limit".into(), "3".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"proxyWallet": "0xwallet", "side": "SELL", "asset": "123",
+                     "conditionId": "0xcond", "size": 40, "price": 0.61,
+                     "timestamp": 1700003600, "outcome": "Yes", "outcomeIndex": 0,
+                     "transactionHash": "0xt3"},
+                    {"proxyWallet": "0xwallet", "side": "BUY", "asset": "123",
+                     "conditionId": "0xcond", "size": 12.5, "price": 0.55,
+                     "timestamp": 1700000000, "outcome": "Yes", "outcomeIndex": 0},
+                    {"proxyWallet": "0xwallet", "side": "BUY", "asset": "456",
+                     "conditionId": "0xcond", "size": 1, "price": 0.4,
+                     "timestamp": 1699990000}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = DataClient::new().with_base_url(&server.url());
+        let start = Utc.timestamp_opt(1_699_999_000, 0).unwrap();
+        let end = Utc.timestamp_opt(1_700_010_000, 0).unwrap();
+        let query = TradeQuery::builder()
+            .user("0xwallet")
+            .market(vec!["0xcond".to_string()])
+            .taker_only(false)
+            .time_range(start, end)
+            .limit(3);
+        let trades = client.get_trades(&query).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].side, Side::SELL);
+        assert_eq!(trades[0].price, dec!(0.61));
+        assert_eq!(trades[0].size, dec!(40));
+        assert_eq!(
+            trades[0].timestamp,
+            Utc.with_ymd_and_hms(2023, 11, 14, 23, 13, 20).unwrap()
+        );
+        assert_eq!(trades[1].side, Side::BUY);
+        assert_eq!(trades[1].size, dec!(12.5));
+        assert_eq!(trades[1].timestamp.timestamp(), 1_700_000_000);
+        assert_eq!(trades[1].transaction_hash, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_parses_rows() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/positions")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("user".into(), "0xwallet".into()),
+                Matcher::UrlEncoded("market".into(), "0xcond".into()),
+                Matcher::UrlEncoded("redeemable".into(), "false".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{
+                    "proxyWallet": "0xwallet",
+                    "asset": "123",
+                    "conditionId": "0xcond",
+                    "size": 150.5,
+                    "avgPrice": 0.42,
+                    "curPrice": 0.5,
+                    "currentValue": 75.25,
+                    "cashPnl": 12.04,
+                    "realizedPnl": 3,
+                    "redeemable": false,
+                    "mergeable": false,
+                    "title": "Will it rain?",
+                    "slug": "will-it-rain",
+                    "outcome": "Yes",
+                    "outcomeIndex": 0,
+                    "negativeRisk": false
+                }]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = DataClient::new().with_base_url(&server.url());
+        let params = PositionListParams::builder()
+            .market(vec!["0xcond".to_string()])
+            .redeemable(false);
+        let positions = client
+            .get_positions_with_params("0xwallet", &params)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(positions.len(), 1);
+        let position = &positions[0];
+        assert_eq!(position.token_id, "123");
+        assert_eq!(position.size, dec!(150.5));
+        assert_eq!(position.avg_price, dec!(0.42));
+        assert_eq!(position.unrealized_pnl, dec!(12.04));
+        assert_eq!(position.realized_pnl, dec!(3));
+        assert_eq!(position.title.as_deref(), Some("Will it rain?"));
+        assert_eq!(position.outcome_index, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_data_api_client_delegates_positions() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/positions")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("user".into(), "0xwallet".into()),
+                Matcher::UrlEncoded("sizeThreshold".into(), "5".into()),
+                Matcher::UrlEncoded("sortBy".into(), "CASHPNL".into()),
+                Matcher::UrlEncoded("sortDirection".into(), "ASC".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{
+                    "proxyWallet": "0xwallet",
+                    "asset": "123",
+                    "conditionId": "0xcond",
+                    "size": 10,
+                    "avgPrice": 0.5,
+                    "initialValue": 5,
+                    "currentValue": 6,
+                    "cashPnl": 1,
+                    "percentPnl": 20,
+                    "totalBought": 10,
+                    "realizedPnl": 0,
+                    "percentRealizedPnl": 0,
+                    "curPrice": 0.6,
+                    "redeemable": false,
+                    "mergeable": false,
+                    "oppositeAsset": "456"
+                }]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = crate::DataApiClient::new().with_base_url(&server.url());
+        let params = crate::types::DataApiPositionsParams::builder()
+            .size_threshold(5)
+            .sort(
+                crate::types::DataApiSortBy::CashPnl,
+                crate::types::DataApiSortDirection::Asc,
+            );
+        let positions = client
+            .get_positions("0xwallet", Some(params))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(positions[0].proxy_wallet, "0xwallet");
+        assert_eq!(positions[0].token_id, "123");
+        assert_eq!(positions[0].unrealized_pnl, dec!(1));
+        assert_eq!(positions[0].opposite_asset.as_deref(), Some("456"));
+    }
+}
@@ -1,33 +1,137 @@
 //! Gamma API client for Polymarket markets, events, and tags
 
-use crate::errors::{PolyError, Result};
+use super::cursor;
+use crate::errors::{MarketDataErrorKind, PolyError, Result};
 use crate::types::{
-    GammaEvent, GammaListParams, GammaMarket, GammaTag, Market, SimplifiedMarketsResponse,
-    SportsResponse,
+    GammaEvent, GammaListParams, GammaMarket, GammaTag, Market, ResolutionDetails, SearchResults,
+    SocialStats,
 };
-use base64::Engine;
-use chrono::{Duration, Utc};
-use reqwest::Client;
+use chrono::{DateTime, Duration, Utc};
+use futures::{Stream, StreamExt};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, Method, RequestBuilder, Response};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 const DEFAULT_GAMMA_BASE: &str = "https://gamma-api.polymarket.com";
 const GAMMA_MARKETS_LIMIT: u32 = 50;
+/// Idle connections are kept well past reqwest's 90s default so a bot polling
+/// every few minutes doesn't redo the TLS handshake each time.
+const DEFAULT_POOL_IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 8;
+/// Keys `GammaMarket` cannot be decoded without, plus `enableOrderBook` so
+/// projected markets keep an accurate `has_order_book`.
+const GAMMA_MARKET_REQUIRED_FIELDS: [&str; 5] =
+    ["conditionId", "slug", "active", "closed", "enableOrderBook"];
+/// Keys needed to tell which outcome won and when.
+const GAMMA_RESOLUTION_FIELDS: [&str; 4] = ["outcomes", "outcomePrices", "closedTime", "endDate"];
+
+/// When and how often [`GammaClient`] retries a failed GET.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total tries per request, including the first.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles with each further attempt. A
+    /// `Retry-After` header on a 429 takes precedence.
+    pub base_delay: std::time::Duration,
+    /// Upper bound on any single delay, including one asked for by
+    /// `Retry-After`.
+    pub max_delay: std::time::Duration,
+    /// Response statuses worth retrying. Connection errors such as resets
+    /// are always retried.
+    pub retry_statuses: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(200),
+            max_delay: std::time::Duration::from_secs(30),
+            retry_statuses: vec![429, 502, 503, 504],
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn backoff(&self, attempt: u32) -> std::time::Duration {
+        self.base_delay
+            .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+    }
+
+    fn delay_for(&self, attempt: u32, response: &Response) -> std::time::Duration {
+        let retry_after = (response.status().as_u16() == 429)
+            .then(|| response.headers().get(RETRY_AFTER))
+            .flatten()
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(std::time::Duration::from_secs);
+        retry_after
+            .unwrap_or_else(|| self.backoff(attempt))
+            .min(self.max_delay)
+    }
+}
+
+/// A cached list response and when it was fetched.
+#[derive(Debug)]
+struct CacheSlot<T>(Mutex<Option<(Instant, Vec<T>)>>);
+
+impl<T: Clone> CacheSlot<T> {
+    fn new() -> Self {
+        Self(Mutex::new(None))
+    }
+
+    fn get(&self, ttl: std::time::Duration) -> Option<Vec<T>> {
+        let slot = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        slot.as_ref()
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < ttl)
+            .map(|(_, items)| items.clone())
+    }
+
+    fn put(&self, items: &[T]) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), items.to_vec()));
+    }
+
+    fn clear(&self) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+/// Near-static Gamma lists served from memory for `ttl`.
+#[derive(Debug)]
+struct ListCache {
+    ttl: std::time::Duration,
+    tags: CacheSlot<GammaTag>,
+    sports: CacheSlot<crate::types::Sport>,
+}
 
 #[derive(Debug, Clone)]
 pub struct GammaClient {
     http_client: Client,
     base_url: String,
+    pool_idle_timeout: std::time::Duration,
+    pool_max_idle_per_host: usize,
+    retry: Option<RetryPolicy>,
+    cache: Option<Arc<ListCache>>,
 }
 
 impl GammaClient {
     pub fn new() -> Self {
-        Self {
+        let mut client = Self {
             http_client: Client::new(),
             base_url: DEFAULT_GAMMA_BASE.to_string(),
-        }
+            pool_idle_timeout: DEFAULT_POOL_IDLE_TIMEOUT,
+            pool_max_idle_per_host: DEFAULT_POOL_MAX_IDLE_PER_HOST,
+            retry: None,
+            cache: None,
+        };
+        client.rebuild_http_client();
+        client
     }
 
     pub fn with_base_url(mut self, url: &str) -> Self {
@@ -35,6 +139,92 @@ impl GammaClient {
         self
     }
 
+    /// How long an idle pooled connection is kept open for reuse.
+    pub fn with_pool_idle_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.pool_idle_timeout = timeout;
+        self.rebuild_http_client();
+        self
+    }
+
+    /// Maximum idle connections kept per host.
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = max;
+        self.rebuild_http_client();
+        self
+    }
+
+    /// Retry GETs that fail with a connection error or one of
+    /// `policy.retry_statuses`. Off by default.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Serve [`GammaClient::get_tags`] and [`GammaClient::get_sports`] from
+    /// memory for `ttl` after each fetch. The cache is shared by clones of
+    /// this client.
+    pub fn with_cache(mut self, ttl: std::time::Duration) -> Self {
+        self.cache = Some(Arc::new(ListCache {
+            ttl,
+            tags: CacheSlot::new(),
+            sports: CacheSlot::new(),
+        }));
+        self
+    }
+
+    /// Drop cached responses so the next call goes to the network.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.tags.clear();
+            cache.sports.clear();
+        }
+    }
+
+    /// Send `request`, retrying under the configured [`RetryPolicy`] when it
+    /// is a GET. Once attempts run out the last response is returned as-is.
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let network = |e: reqwest::Error| PolyError::network(format!("Request failed: {}", e), e);
+        let request = request.build().map_err(network)?;
+        let policy = self
+            .retry
+            .as_ref()
+            .filter(|_| request.method() == Method::GET);
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let retry = policy
+                .filter(|policy| attempt < policy.max_attempts)
+                .zip(request.try_clone());
+            let Some((policy, current)) = retry else {
+                return self.http_client.execute(request).await.map_err(network);
+            };
+
+            let delay = match self.http_client.execute(current).await {
+                Ok(response) if policy.retry_statuses.contains(&response.status().as_u16()) => {
+                    policy.delay_for(attempt, &response)
+                }
+                Ok(response) => return Ok(response),
+                Err(err) => {
+                    let err = network(err);
+                    if !err.is_retryable() {
+                        return Err(err);
+                    }
+                    policy.backoff(attempt)
+                }
+            };
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    fn rebuild_http_client(&mut self) {
+        self.http_client = Client::builder()
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .build()
+            .unwrap_or_else(|_| Client::new());
+    }
+
     fn build_url(&self, path: &str) -> String {
         let base = self.base_url.trim_end_matches('/');
         let path = path.trim_start_matches('/');
@@ -49,163 +239,153 @@ impl GammaClient {
         self.build_url(path)
     }
 
-    fn encode_cursor(cursor: u64) -> String {
-        base64::engine::general_purpose::STANDARD.encode(cursor.to_string())
-    }
-
-    fn decode_cursor(cursor: &str) -> Option<u64> {
-        base64::engine::general_purpose::STANDARD
-            .decode(cursor)
-            .ok()
-            .and_then(|bytes| String::from_utf8(bytes).ok())
-            .and_then(|s| s.parse::<u64>().ok())
-    }
-
+    /// One page of markets. `params` is sent exactly as for
+    /// [`GammaClient::get_events`], except that unset fields get these
+    /// defaults: `limit` 50 (the page size `next_cursor` advances by),
+    /// `offset` from `next_cursor`, `liquidity_num_min` 10,000, and
+    /// `end_date_max` three weeks out (an earlier `end_date_max` is raised to
+    /// that floor). `closed` is only sent when set, so closed markets are
+    /// included unless `params` excludes them.
     pub async fn get_markets(
         &self,
         next_cursor: Option<&str>,
         params: Option<&crate::types::GammaListParams>,
     ) -> Result<crate::types::MarketsResponse> {
-        let offset = params
-            .and_then(|options| options.offset.map(u64::from))
-            .or_else(|| next_cursor.and_then(Self::decode_cursor))
-            .unwrap_or(0);
-
-        let limit = params
-            .and_then(|options| options.limit)
-            .unwrap_or(GAMMA_MARKETS_LIMIT);
-
-        let mut query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
-
-        let liquidity_min = params
-            .and_then(|options| options.liquidity_num_min)
-            .unwrap_or_else(|| Decimal::from(10_000));
-        query.push(("liquidity_num_min", liquidity_min.to_string()));
-
-        let min_end_date = Utc::now() + Duration::weeks(3);
-        let end_date_max = params
-            .and_then(|options| options.end_date_max)
-            .unwrap_or(min_end_date);
-        let end_date_max = if end_date_max < min_end_date {
-            min_end_date
-        } else {
-            end_date_max
-        };
-        query.push(("end_date_max", end_date_max.to_rfc3339()));
-
-        if let Some(start_date_min) = params.and_then(|options| options.start_date_min) {
-            query.push(("start_date_min", start_date_min.to_rfc3339()));
-        }
-
-        if let Some(options) = params {
-            if let Some(closed) = options.closed {
-                query.push(("closed", closed.to_string()));
-            } else {
-                query.push(("closed", "false".to_string()));
-            }
-
-            if let Some(tag_id) = &options.tag_id {
-                query.push(("tag_id", tag_id.clone()));
-            }
-            if let Some(exclude_tag_id) = &options.exclude_tag_id {
-                query.push(("exclude_tag_id", exclude_tag_id.clone()));
-            }
-            if let Some(related_tags) = &options.related_tags {
-                query.push(("related_tags", related_tags.clone()));
-            }
-            if let Some(order) = &options.order {
-                query.push(("order", order.clone()));
-            }
-            if let Some(ascending) = options.ascending {
-                query.push(("ascending", ascending.to_string()));
-            }
+        let (gamma_markets, limit, next_cursor) =
+            self.fetch_gamma_markets(next_cursor, params).await?;
 
-            if let Some(cyom) = options.cyom {
-                query.push(("cyom", cyom.to_string()));
-            }
-            if let Some(include_tag) = options.include_tag {
-                query.push(("include_tag", include_tag.to_string()));
-            }
+        let count = gamma_markets.len();
+        let markets = gamma_markets
+            .into_iter()
+            .map(|gamma| gamma.into())
+            .collect::<Vec<_>>();
 
-            if let Some(uma_resolution_status) = &options.uma_resolution_status {
-                query.push(("uma_resolution_status", uma_resolution_status.clone()));
-            }
-            if let Some(game_id) = &options.game_id {
-                query.push(("game_id", game_id.clone()));
-            }
+        Ok(crate::types::MarketsResponse {
+            limit: Decimal::from(limit),
+            count: Decimal::from_i64(count as i64).unwrap_or(Decimal::ZERO),
+            next_cursor,
+            data: markets,
+        })
+    }
 
-            if let Some(liquidity_num_max) = options.liquidity_num_max {
-                query.push(("liquidity_num_max", liquidity_num_max.to_string()));
-            }
-            if let Some(volume_num_min) = options.volume_num_min {
-                query.push(("volume_num_min", volume_num_min.to_string()));
-            }
-            if let Some(volume_num_max) = options.volume_num_max {
-                query.push(("volume_num_max", volume_num_max.to_string()));
-            }
-            if let Some(rewards_min_size) = options.rewards_min_size {
-                query.push(("rewards_min_size", rewards_min_size.to_string()));
-            }
+    /// Every market matching `params`, one at a time, following `next_cursor`
+    /// until Gamma runs out of pages.
+    ///
+    /// A failed page is yielded as an `Err` and ends the stream.
+    pub fn markets_stream(
+        &self,
+        params: Option<GammaListParams>,
+    ) -> impl Stream<Item = Result<Market>> + '_ {
+        struct State {
+            params: Option<GammaListParams>,
+            next_cursor: Option<String>,
+            buffered: VecDeque<Market>,
+            done: bool,
+        }
 
-            if let Some(start_date_max) = options.start_date_max {
-                query.push(("start_date_max", start_date_max.to_rfc3339()));
-            }
-            if let Some(end_date_min) = options.end_date_min {
-                query.push(("end_date_min", end_date_min.to_rfc3339()));
-            }
+        let state = State {
+            params,
+            next_cursor: None,
+            buffered: VecDeque::new(),
+            done: false,
+        };
 
-            if let Some(id) = &options.id {
-                if !id.is_empty() {
-                    query.push((
-                        "id",
-                        id.iter()
-                            .map(|i| i.to_string())
-                            .collect::<Vec<_>>()
-                            .join(","),
-                    ));
-                }
-            }
-            if let Some(slug) = &options.slug {
-                if !slug.is_empty() {
-                    query.push(("slug", slug.join(",")));
-                }
-            }
-            if let Some(clob_token_ids) = &options.clob_token_ids {
-                if !clob_token_ids.is_empty() {
-                    query.push(("clob_token_ids", clob_token_ids.join(",")));
+        futures::stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(market) = state.buffered.pop_front() {
+                    return Some((Ok(market), state));
                 }
-            }
-            if let Some(condition_ids) = &options.condition_ids {
-                if !condition_ids.is_empty() {
-                    query.push(("condition_ids", condition_ids.join(",")));
+                if state.done {
+                    return None;
                 }
-            }
-            if let Some(market_maker_address) = &options.market_maker_address {
-                if !market_maker_address.is_empty() {
-                    query.push(("market_maker_address", market_maker_address.join(",")));
+                match self
+                    .fetch_gamma_markets(state.next_cursor.as_deref(), state.params.as_ref())
+                    .await
+                {
+                    Ok((page, _, cursor)) => {
+                        state.buffered.extend(page.into_iter().map(Market::from));
+                        state.done = cursor.is_none();
+                        state.next_cursor = cursor;
+                        // An explicit offset would pin every request to the first page.
+                        if let Some(params) = state.params.as_mut() {
+                            params.offset = None;
+                        }
+                    }
+                    Err(err) => {
+                        state.done = true;
+                        return Some((Err(err), state));
+                    }
                 }
             }
-            if let Some(sports_market_types) = &options.sports_market_types {
-                if !sports_market_types.is_empty() {
-                    query.push(("sports_market_types", sports_market_types.join(",")));
+        })
+    }
+
+    /// Closed markets that resolved at or after `since`, each with
+    /// [`Market::resolved_outcome`] set.
+    ///
+    /// `closed` and `end_date_min` are forced on `params` and every page is
+    /// fetched. Markets whose resolution can't be determined yet (prices not
+    /// settled) are skipped. The server-side window is on the scheduled end
+    /// date, so a market that ended before `since` but resolved later is not
+    /// returned.
+    pub async fn get_recently_resolved(
+        &self,
+        since: DateTime<Utc>,
+        params: Option<&GammaListParams>,
+    ) -> Result<Vec<Market>> {
+        let mut query = params.cloned().unwrap_or_default();
+        query.closed = Some(true);
+        query.end_date_min = Some(query.end_date_min.map_or(since, |min| min.max(since)));
+        // Resolved markets have drained liquidity; drop the default floor.
+        query.liquidity_num_min.get_or_insert(Decimal::ZERO);
+        if let Some(fields) = query.fields.as_mut() {
+            for field in GAMMA_RESOLUTION_FIELDS {
+                if !fields.iter().any(|f| f == field) {
+                    fields.push(field.to_string());
                 }
             }
-            if let Some(question_ids) = &options.question_ids {
-                if !question_ids.is_empty() {
-                    query.push(("question_ids", question_ids.join(",")));
+        }
+
+        let mut resolved = Vec::new();
+        let mut next_cursor: Option<String> = None;
+        loop {
+            let (page, _, cursor) = self
+                .fetch_gamma_markets(next_cursor.as_deref(), Some(&query))
+                .await?;
+            resolved.extend(
+                page.into_iter()
+                    .filter(|gamma| gamma.resolved_at().is_some_and(|at| at >= since))
+                    .filter(|gamma| gamma.resolved_outcome().is_some())
+                    .map(Market::from),
+            );
+            match cursor {
+                Some(cursor) => {
+                    next_cursor = Some(cursor);
+                    query.offset = None;
                 }
+                None => break,
             }
-        } else {
-            query.push(("closed", "false".to_string()));
         }
+        Ok(resolved)
+    }
+
+    async fn fetch_gamma_markets(
+        &self,
+        next_cursor: Option<&str>,
+        params: Option<&crate::types::GammaListParams>,
+    ) -> Result<(Vec<GammaMarket>, u32, Option<String>)> {
+        let options = markets_params(next_cursor, params, Utc::now());
+        let offset = u64::from(options.offset.unwrap_or(0));
+        let limit = options.limit.unwrap_or(GAMMA_MARKETS_LIMIT);
+        let query = options.to_query_params();
 
         let response = self
-            .http_client
-            .get(self.gamma_url("markets"))
-            .query(&query)
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send(
+                self.http_client
+                    .get(self.gamma_url("markets"))
+                    .query(&query),
+            )
+            .await?;
 
         if !response.status().is_success() {
             return Err(PolyError::api(
@@ -219,30 +399,296 @@ impl GammaClient {
             .await
             .map_err(|e| PolyError::parse(format!("Failed to read response body: {}", e), None))?;
 
-        let gamma_markets: Vec<crate::types::GammaMarket> = serde_json::from_str(&body)
+        let mut raw_markets: Vec<Value> = serde_json::from_str(&body)
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+        if let Some(fields) = params.and_then(|options| options.fields.as_deref())
+            && !fields.is_empty()
+        {
+            project_fields(&mut raw_markets, fields);
+        }
+        let gamma_markets: Vec<GammaMarket> = raw_markets
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<std::result::Result<_, _>>()
             .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
 
         let count = gamma_markets.len();
         let next_cursor = if count < limit as usize {
             None
         } else {
-            Some(Self::encode_cursor(offset + count as u64))
+            Some(cursor::encode(offset + count as u64))
         };
-        let markets = gamma_markets
+        let mut gamma_markets = gamma_markets;
+        if options.order_book_only == Some(true) {
+            gamma_markets.retain(|market| market.enable_order_book != Some(false));
+        }
+        Ok((gamma_markets, limit, next_cursor))
+    }
+
+    /// Map each condition ID to the neg-risk market it belongs to, or `None`
+    /// for standalone markets.
+    ///
+    /// Conditions are resolved in batches through the comma-separated
+    /// `condition_ids` filter. Conditions Gamma doesn't know about are left
+    /// out of the returned map.
+    pub async fn neg_risk_groups_for(
+        &self,
+        condition_ids: &[String],
+    ) -> Result<HashMap<String, Option<String>>> {
+        let mut groups = HashMap::with_capacity(condition_ids.len());
+
+        for chunk in condition_ids.chunks(GAMMA_MARKETS_LIMIT as usize) {
+            for market in self.fetch_markets_by_condition_ids(chunk).await? {
+                let group = market
+                    .neg_risk_market_id
+                    .filter(|id| market.neg_risk != Some(false) && !id.is_empty());
+                groups.insert(market.condition_id, group);
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// Markets trading the given CLOB token ids, looked up in batches through
+    /// the `clob_token_ids` filter. Tokens Gamma doesn't know are skipped.
+    ///
+    /// With `order_book_only`, markets that have no CLOB order book (see
+    /// [`Market::has_order_book`]) are left out; subscribing to their tokens
+    /// would never produce events.
+    pub async fn get_markets_by_token(
+        &self,
+        token_ids: &[String],
+        order_book_only: bool,
+    ) -> Result<Vec<Market>> {
+        for token_id in token_ids {
+            require("token_id", token_id)?;
+        }
+        let mut markets: Vec<Market> = Vec::new();
+
+        for chunk in token_ids.chunks(GAMMA_MARKETS_LIMIT as usize) {
+            let query = [
+                ("clob_token_ids", chunk.join(",")),
+                ("limit", chunk.len().to_string()),
+            ];
+
+            let response = self
+                .send(
+                    self.http_client
+                        .get(self.gamma_url("markets"))
+                        .query(&query),
+                )
+                .await?;
+
+            if !response.status().is_success() {
+                return Err(PolyError::api(
+                    response.status().as_u16(),
+                    "Failed to fetch markets",
+                ));
+            }
+
+            let payload: Value = response
+                .json()
+                .await
+                .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+
+            for gamma in self.parse_gamma_list::<GammaMarket>(payload, "Gamma markets")? {
+                let market = Market::from(gamma);
+                // Both tokens of a market can be in the same request.
+                if (!order_book_only || market.has_order_book)
+                    && !markets
+                        .iter()
+                        .any(|known| known.condition_id == market.condition_id)
+                {
+                    markets.push(market);
+                }
+            }
+        }
+
+        Ok(markets)
+    }
+
+    /// Look up a single market by its condition ID.
+    pub async fn get_market_by_condition_id(&self, condition_id: &str) -> Result<Market> {
+        self.fetch_markets_by_condition_ids(&[condition_id.to_string()])
+            .await?
             .into_iter()
-            .map(|gamma| gamma.into())
-            .collect::<Vec<_>>();
+            .next()
+            .map(Market::from)
+            .ok_or_else(|| {
+                PolyError::market_data(
+                    format!("Market {} not found", condition_id),
+                    MarketDataErrorKind::MarketNotFound,
+                )
+            })
+    }
 
-        Ok(crate::types::MarketsResponse {
-            limit: Decimal::from(limit),
-            count: Decimal::from_i64(count as i64).unwrap_or(Decimal::ZERO),
-            next_cursor,
-            data: markets,
-        })
+    /// Resolution criteria, source, and UMA request data for a market.
+    pub async fn get_resolution_details(&self, condition_id: &str) -> Result<ResolutionDetails> {
+        self.fetch_markets_by_condition_ids(&[condition_id.to_string()])
+            .await?
+            .first()
+            .map(GammaMarket::resolution_details)
+            .ok_or_else(|| {
+                PolyError::market_data(
+                    format!("Market {} not found", condition_id),
+                    MarketDataErrorKind::MarketNotFound,
+                )
+            })
     }
 
+    async fn fetch_markets_by_condition_ids(
+        &self,
+        condition_ids: &[String],
+    ) -> Result<Vec<GammaMarket>> {
+        for condition_id in condition_ids {
+            require("condition_id", condition_id)?;
+        }
+        let query = [
+            ("condition_ids", condition_ids.join(",")),
+            ("limit", condition_ids.len().to_string()),
+        ];
+
+        let response = self
+            .send(
+                self.http_client
+                    .get(self.gamma_url("markets"))
+                    .query(&query),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to fetch markets",
+            ));
+        }
+
+        let payload: Value = response
+            .json()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+
+        self.parse_gamma_list(payload, "Gamma markets")
+    }
+
+    /// Fetch every market instance belonging to a recurring Gamma series.
+    ///
+    /// Walks the series' events page by page and flattens their markets.
+    /// Each returned market has `series_id` set to `series_id`.
+    pub async fn get_series_markets(&self, series_id: &str) -> Result<Vec<Market>> {
+        require("series_id", series_id)?;
+        let mut markets = Vec::new();
+        let mut offset = 0u32;
+
+        loop {
+            let query = [
+                ("series_id", series_id.to_string()),
+                ("limit", GAMMA_MARKETS_LIMIT.to_string()),
+                ("offset", offset.to_string()),
+            ];
+
+            let response = self
+                .send(self.http_client.get(self.gamma_url("events")).query(&query))
+                .await?;
+
+            if !response.status().is_success() {
+                return Err(PolyError::api(
+                    response.status().as_u16(),
+                    "Failed to fetch Gamma series events",
+                ));
+            }
+
+            let payload: Value = response
+                .json()
+                .await
+                .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+            let events: Vec<Value> = self.parse_gamma_list(payload, "Gamma series events")?;
+            let page_len = events.len();
+
+            for event in events {
+                let Some(raw_markets) = event.get("markets").cloned() else {
+                    continue;
+                };
+                let gamma_markets: Vec<GammaMarket> =
+                    serde_json::from_value(raw_markets).map_err(|e| {
+                        PolyError::parse(format!("Failed to parse series markets: {}", e), None)
+                    })?;
+                markets.extend(gamma_markets.into_iter().map(|gamma| {
+                    let mut market = Market::from(gamma);
+                    market.series_id = Some(series_id.to_string());
+                    market
+                }));
+            }
+
+            if page_len < GAMMA_MARKETS_LIMIT as usize {
+                break;
+            }
+            offset += GAMMA_MARKETS_LIMIT;
+        }
+
+        Ok(markets)
+    }
+
+    /// Fetch the event containing the market with `condition_id`.
+    ///
+    /// Same as [`GammaClient::get_event_for_market`].
     pub async fn get_event(&self, condition_id: &str) -> Result<GammaEvent> {
-        todo!()
+        self.get_event_for_market(condition_id).await
+    }
+
+    /// Fetch the parent event of the market with `condition_id`.
+    ///
+    /// Only an event whose embedded markets list the condition is returned.
+    /// When none does (Gamma left the markets out or ignored the filter), the
+    /// market's own event reference is followed instead. Markets that aren't
+    /// attached to any event come back as a 404 [`PolyError::Api`].
+    pub async fn get_event_for_market(&self, condition_id: &str) -> Result<GammaEvent> {
+        require("condition_id", condition_id)?;
+        let response = self
+            .send(
+                self.http_client
+                    .get(self.gamma_url("events"))
+                    .query(&[("condition_ids", condition_id)]),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to fetch Gamma event",
+            ));
+        }
+
+        let payload: Value = response
+            .json()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+
+        let mut events = self.parse_gamma_list::<GammaEvent>(payload, "Gamma events")?;
+        let lists_market = |event: &GammaEvent| {
+            event
+                .markets
+                .iter()
+                .any(|market| market.condition_id.eq_ignore_ascii_case(condition_id))
+        };
+        if let Some(index) = events.iter().position(lists_market) {
+            return Ok(events.swap_remove(index));
+        }
+
+        let not_found = || {
+            PolyError::api(
+                404,
+                format!("No Gamma event for condition {}", condition_id),
+            )
+        };
+        let event_id = self
+            .fetch_markets_by_condition_ids(&[condition_id.to_string()])
+            .await?
+            .into_iter()
+            .find(|market| market.condition_id.eq_ignore_ascii_case(condition_id))
+            .and_then(|market| market.events.into_iter().find_map(|event| event.id))
+            .ok_or_else(not_found)?;
+        self.get_event_by_id(&event_id).await
     }
 
     pub async fn get_events(&self, params: Option<&GammaListParams>) -> Result<Vec<GammaEvent>> {
@@ -252,10 +698,7 @@ impl GammaClient {
             request = request.query(&options.to_query_params());
         }
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+        let response = self.send(request).await?;
 
         if !response.status().is_success() {
             return Err(PolyError::api(
@@ -272,13 +715,56 @@ impl GammaClient {
         self.parse_gamma_list(payload, "Gamma events")
     }
 
+    /// Fetch the events with the given slugs through the comma-separated
+    /// `slug` filter, in the order requested. Slugs Gamma has no event for
+    /// are skipped.
+    pub async fn get_events_by_slugs(&self, slugs: &[String]) -> Result<Vec<GammaEvent>> {
+        for slug in slugs {
+            require("slug", slug)?;
+        }
+        let mut by_slug = HashMap::with_capacity(slugs.len());
+
+        for chunk in slugs.chunks(GAMMA_MARKETS_LIMIT as usize) {
+            let query = [
+                ("slug", chunk.join(",")),
+                ("limit", chunk.len().to_string()),
+            ];
+
+            let response = self
+                .send(self.http_client.get(self.gamma_url("events")).query(&query))
+                .await?;
+
+            if !response.status().is_success() {
+                return Err(PolyError::api(
+                    response.status().as_u16(),
+                    "Failed to fetch Gamma events",
+                ));
+            }
+
+            let payload: Value = response
+                .json()
+                .await
+                .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+
+            for event in self.parse_gamma_list::<GammaEvent>(payload, "Gamma events")? {
+                by_slug.insert(event.slug.clone(), event);
+            }
+        }
+
+        Ok(slugs
+            .iter()
+            .filter_map(|slug| by_slug.remove(slug))
+            .collect())
+    }
+
     pub async fn get_event_by_slug(&self, slug: &str) -> Result<GammaEvent> {
+        require("slug", slug)?;
         let response = self
-            .http_client
-            .get(self.gamma_url(&format!("events/slug/{}", slug)))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send(
+                self.http_client
+                    .get(self.gamma_url(&format!("events/slug/{}", slug))),
+            )
+            .await?;
 
         if !response.status().is_success() {
             return Err(PolyError::api(
@@ -294,12 +780,13 @@ impl GammaClient {
     }
 
     pub async fn get_event_by_id(&self, event_id: &str) -> Result<GammaEvent> {
+        require("event_id", event_id)?;
         let response = self
-            .http_client
-            .get(self.gamma_url(&format!("events/{}", event_id)))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send(
+                self.http_client
+                    .get(self.gamma_url(&format!("events/{}", event_id))),
+            )
+            .await?;
 
         if !response.status().is_success() {
             return Err(PolyError::api(
@@ -314,13 +801,94 @@ impl GammaClient {
             .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
     }
 
-    pub async fn get_tags(&self) -> Result<Vec<GammaTag>> {
+    /// Comment, holder, and watch counts for the Gamma market with numeric
+    /// `id`, as far as Gamma reports them.
+    pub async fn get_market_social(&self, id: u64) -> Result<SocialStats> {
+        let response = self
+            .send(
+                self.http_client
+                    .get(self.gamma_url(&format!("markets/{}", id))),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to fetch Gamma market",
+            ));
+        }
+
+        response
+            .json::<SocialStats>()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+    }
+
+    /// Search markets, events, and profiles matching `query`. `limit` caps
+    /// the results of each type. Sections missing from the response come
+    /// back empty.
+    pub async fn search(&self, query: &str, limit: Option<u32>) -> Result<SearchResults> {
+        require("query", query)?;
+        let query = query.trim();
+
+        let mut params = vec![("q", query.to_string())];
+        if let Some(limit) = limit {
+            params.push(("limit_per_type", limit.to_string()));
+        }
+
         let response = self
-            .http_client
-            .get(self.gamma_url("tags"))
-            .send()
+            .send(
+                self.http_client
+                    .get(self.gamma_url("public-search"))
+                    .query(&params),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to search Gamma",
+            ));
+        }
+
+        let mut payload: Value = response
+            .json()
             .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+
+        let mut section = |key: &str| match payload.get_mut(key).map(Value::take) {
+            Some(Value::Null) | None => Value::Array(Vec::new()),
+            Some(value) => value,
+        };
+        let markets = section("markets");
+        let events = section("events");
+        let profiles = match payload.get_mut("profiles").map(Value::take) {
+            Some(Value::Null) | None => None,
+            Some(value) => Some(self.parse_gamma_list(value, "Gamma search profiles")?),
+        };
+
+        Ok(SearchResults {
+            markets: self.parse_gamma_list(markets, "Gamma search markets")?,
+            events: self.parse_gamma_list(events, "Gamma search events")?,
+            profiles,
+        })
+    }
+
+    pub async fn get_tags(&self) -> Result<Vec<GammaTag>> {
+        if let Some(tags) = self.cache.as_ref().and_then(|c| c.tags.get(c.ttl)) {
+            return Ok(tags);
+        }
+        let tags = self.fetch_tags().await?;
+        if let Some(cache) = &self.cache {
+            cache.tags.put(&tags);
+        }
+        Ok(tags)
+    }
+
+    async fn fetch_tags(&self) -> Result<Vec<GammaTag>> {
+        let response = self
+            .send(self.http_client.get(self.gamma_url("tags")))
+            .await?;
 
         if !response.status().is_success() {
             return Err(PolyError::api(
@@ -338,12 +906,20 @@ impl GammaClient {
     }
 
     pub async fn get_sports(&self) -> Result<Vec<crate::types::Sport>> {
+        if let Some(sports) = self.cache.as_ref().and_then(|c| c.sports.get(c.ttl)) {
+            return Ok(sports);
+        }
+        let sports = self.fetch_sports().await?;
+        if let Some(cache) = &self.cache {
+            cache.sports.put(&sports);
+        }
+        Ok(sports)
+    }
+
+    async fn fetch_sports(&self) -> Result<Vec<crate::types::Sport>> {
         let response = self
-            .http_client
-            .get(self.gamma_url("sports"))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send(self.http_client.get(self.gamma_url("sports")))
+            .await?;
 
         if !response.status().is_success() {
             return Err(PolyError::api(
@@ -380,3 +956,1117 @@ impl Default for GammaClient {
         Self::new()
     }
 }
+
+/// Chain `streams` (e.g. one [`GammaClient::markets_stream`] per tag id)
+/// into one, skipping markets whose condition id was already yielded.
+///
+/// Streams are drained in the order given, so the output order is stable:
+/// every market appears where it first appeared in the earliest stream
+/// containing it. Errors are passed through and don't end the merge.
+pub fn merge_market_streams<S>(streams: Vec<S>) -> impl Stream<Item = Result<Market>>
+where
+    S: Stream<Item = Result<Market>>,
+{
+    let mut seen = HashSet::new();
+    futures::stream::iter(streams)
+        .flatten()
+        .filter(move |item| {
+            let keep = match item {
+                Ok(market) => seen.insert(market.condition_id.clone()),
+                Err(_) => true,
+            };
+            futures::future::ready(keep)
+        })
+}
+
+/// Reject a blank `value` for `field` instead of sending a request that can
+/// only 404 or match everything.
+fn require(field: &str, value: &str) -> Result<()> {
+    if value.trim().is_empty() {
+        return Err(PolyError::invalid_field(
+            field,
+            format!("{} must not be empty", field),
+        ));
+    }
+    Ok(())
+}
+
+/// Drop every key not listed in `fields` (or required to decode a market).
+fn project_fields(markets: &mut [Value], fields: &[String]) {
+    for market in markets {
+        if let Value::Object(map) = market {
+            map.retain(|key, _| {
+                GAMMA_MARKET_REQUIRED_FIELDS.contains(&key.as_str())
+                    || fields.iter().any(|field| field == key)
+            });
+        }
+    }
+}
+
+/// Query for `/markets`: `params` as [`GammaListParams::to_query_params`]
+/// would send it to any other list endpoint, plus these defaults:
+///
+/// - `limit` defaults to 50, the page size the cursor advances by;
+/// - `offset` falls back to the position encoded in `next_cursor`, then 0;
+/// - `liquidity_num_min` defaults to 10,000;
+/// - `end_date_max` defaults to three weeks from `now` and is never earlier
+///   than that.
+fn markets_params(
+    next_cursor: Option<&str>,
+    params: Option<&GammaListParams>,
+    now: DateTime<Utc>,
+) -> GammaListParams {
+    let mut options = params.cloned().unwrap_or_default();
+    let offset = options
+        .offset
+        .map(u64::from)
+        .or_else(|| next_cursor.and_then(cursor::decode))
+        .unwrap_or(0);
+    options.offset = Some(u32::try_from(offset).unwrap_or(u32::MAX));
+    options.limit.get_or_insert(GAMMA_MARKETS_LIMIT);
+    options
+        .liquidity_num_min
+        .get_or_insert_with(|| Decimal::from(10_000));
+
+    let min_end_date = now + Duration::weeks(3);
+    options.end_date_max = Some(
+        options
+            .end_date_max
+            .map_or(min_end_date, |end| end.max(min_end_date)),
+    );
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Keep-alive HTTP server answering every request with `[]`; returns its
+    /// URL and a count of accepted connections.
+    async fn counting_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+        let count = connections.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                count.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut buf = Vec::new();
+                    let mut chunk = [0u8; 1024];
+                    loop {
+                        let Ok(n) = socket.read(&mut chunk).await else {
+                            return;
+                        };
+                        if n == 0 {
+                            return;
+                        }
+                        buf.extend_from_slice(&chunk[..n]);
+                        while let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                            buf.drain(..end + 4);
+                            let response = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\n\r\n[]";
+                            if socket.write_all(response.as_bytes()).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                });
+            }
+        });
+        (url, connections)
+    }
+
+    #[tokio::test]
+    async fn test_pooled_client_reuses_connection() {
+        let (url, connections) = counting_server().await;
+        let client = GammaClient::new()
+            .with_pool_idle_timeout(std::time::Duration::from_secs(30))
+            .with_pool_max_idle_per_host(2)
+            .with_base_url(&url);
+
+        client.get_events(None).await.unwrap();
+        client.get_events(None).await.unwrap();
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cache_serves_tags_and_sports_until_cleared() {
+        let mut server = Server::new_async().await;
+        let tags = server
+            .mock("GET", "/tags")
+            .with_status(200)
+            .with_body(r#"[{"id": "1", "name": "Politics", "slug": "politics"}]"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let sports = server
+            .mock("GET", "/sports")
+            .with_status(200)
+            .with_body("[]")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new()
+            .with_base_url(&server.url())
+            .with_cache(std::time::Duration::from_secs(60));
+        let clone = client.clone();
+
+        assert_eq!(client.get_tags().await.unwrap().len(), 1);
+        assert_eq!(clone.get_tags().await.unwrap()[0].name, "Politics");
+        client.get_sports().await.unwrap();
+        clone.get_sports().await.unwrap();
+
+        clone.clear_cache();
+        client.get_tags().await.unwrap();
+
+        tags.assert_async().await;
+        sports.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_cache_refetches_after_ttl() {
+        let mut server = Server::new_async().await;
+        let tags = server
+            .mock("GET", "/tags")
+            .with_status(200)
+            .with_body("[]")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new()
+            .with_base_url(&server.url())
+            .with_cache(std::time::Duration::from_millis(20));
+        client.get_tags().await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(40)).await;
+        client.get_tags().await.unwrap();
+
+        tags.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_search_parses_mixed_results() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/public-search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("q".into(), "fed rates".into()),
+                Matcher::UrlEncoded("limit_per_type".into(), "5".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "events": [{"id": "42", "slug": "fed-june", "markets": []}],
+                    "markets": [{"conditionId": "0xabc", "slug": "fed-cut-june",
+                                 "active": "true", "closed": false}],
+                    "profiles": [{"name": "macro-trader", "proxyWallet": "0x1"}],
+                    "pagination": {"hasMore": false}
+                }"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let events_only = server
+            .mock("GET", "/public-search")
+            .match_query(Matcher::UrlEncoded("q".into(), "nba".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"events": [], "profiles": null}"#)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let results = client.search("fed rates", Some(5)).await.unwrap();
+        assert_eq!(results.events[0].slug, "fed-june");
+        assert_eq!(results.markets[0].condition_id, "0xabc");
+        assert_eq!(results.profiles.unwrap()[0]["name"], "macro-trader");
+
+        let results = client.search("nba", None).await.unwrap();
+        assert!(results.markets.is_empty() && results.events.is_empty());
+        assert!(results.profiles.is_none());
+
+        let err = client.search("  ", None).await.unwrap_err();
+        assert!(matches!(err, PolyError::Validation { .. }));
+
+        mock.assert_async().await;
+        events_only.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_blank_inputs_fail_validation_without_a_request() {
+        let mut server = Server::new_async().await;
+        let any = server
+            .mock("GET", Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+        let client = GammaClient::new().with_base_url(&server.url());
+
+        let field = |result: Result<()>| result.unwrap_err().validation_field().map(str::to_owned);
+        assert_eq!(
+            field(client.get_event_by_slug("").await.map(drop)).as_deref(),
+            Some("slug")
+        );
+        assert_eq!(
+            field(
+                client
+                    .get_events_by_slugs(&["fed-june".into(), " ".into()])
+                    .await
+                    .map(drop)
+            )
+            .as_deref(),
+            Some("slug")
+        );
+        assert_eq!(
+            field(client.get_event_by_id(" ").await.map(drop)).as_deref(),
+            Some("event_id")
+        );
+        assert_eq!(
+            field(client.get_event("").await.map(drop)).as_deref(),
+            Some("condition_id")
+        );
+        assert_eq!(
+            field(client.get_market_by_condition_id("").await.map(drop)).as_deref(),
+            Some("condition_id")
+        );
+        assert_eq!(
+            field(client.get_resolution_details("\t").await.map(drop)).as_deref(),
+            Some("condition_id")
+        );
+        assert_eq!(
+            field(
+                client
+                    .neg_risk_groups_for(&["0xabc".into(), String::new()])
+                    .await
+                    .map(drop)
+            )
+            .as_deref(),
+            Some("condition_id")
+        );
+        assert_eq!(
+            field(client.get_series_markets("").await.map(drop)).as_deref(),
+            Some("series_id")
+        );
+        assert_eq!(
+            field(client.search("", None).await.map(drop)).as_deref(),
+            Some("query")
+        );
+
+        any.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_order_book_only_drops_markets_without_a_book() {
+        let body = r#"[
+            {"conditionId": "0xclob", "slug": "clob", "active": true, "closed": false,
+             "enableOrderBook": true, "clobTokenIds": "[\"1\", \"2\"]"},
+            {"conditionId": "0xamm", "slug": "amm", "active": true, "closed": false,
+             "enableOrderBook": false, "clobTokenIds": "[\"3\", \"4\"]"},
+            {"conditionId": "0xunknown", "slug": "unknown", "active": true, "closed": false,
+             "clobTokenIds": "[\"5\", \"6\"]"}
+        ]"#;
+        let mut server = Server::new_async().await;
+        let by_token = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded(
+                "clob_token_ids".into(),
+                "1,2,3,5".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .expect(2)
+            .create_async()
+            .await;
+        let listed = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded("limit".into(), "50".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let tokens: Vec<String> = ["1", "2", "3", "5"].map(String::from).to_vec();
+        let ids = |markets: Vec<Market>| -> Vec<String> {
+            markets.into_iter().map(|m| m.condition_id).collect()
+        };
+
+        let all = client.get_markets_by_token(&tokens, false).await.unwrap();
+        assert_eq!(
+            all.iter().map(|m| m.has_order_book).collect::<Vec<_>>(),
+            [true, false, true]
+        );
+        assert_eq!(
+            ids(client.get_markets_by_token(&tokens, true).await.unwrap()),
+            ["0xclob", "0xunknown"]
+        );
+
+        let params = GammaListParams::builder().order_book_only(true);
+        let page = client.get_markets(None, Some(&params)).await.unwrap();
+        assert_eq!(ids(page.data), ["0xclob", "0xunknown"]);
+
+        by_token.assert_async().await;
+        listed.assert_async().await;
+    }
+
+    fn market(condition_id: &str) -> Market {
+        let gamma: GammaMarket = serde_json::from_value(serde_json::json!({
+            "conditionId": condition_id,
+            "slug": condition_id,
+            "active": true,
+            "closed": false
+        }))
+        .unwrap();
+        Market::from(gamma)
+    }
+
+    #[tokio::test]
+    async fn test_merge_market_streams_dedups_in_stable_order() {
+        let politics =
+            futures::stream::iter(["0xa", "0xb", "0xc"].map(|id| Ok::<_, PolyError>(market(id))));
+        let elections = futures::stream::iter(vec![
+            Ok(market("0xc")),
+            Err(PolyError::api(503, "page failed")),
+            Ok(market("0xd")),
+            Ok(market("0xa")),
+        ]);
+
+        let merged: Vec<_> = merge_market_streams(vec![politics.boxed(), elections.boxed()])
+            .collect()
+            .await;
+
+        assert_eq!(merged.len(), 5);
+        assert!(matches!(merged[3], Err(PolyError::Api { status: 503, .. })));
+        let ids: Vec<_> = merged
+            .into_iter()
+            .filter_map(|item| item.ok().map(|market| market.condition_id))
+            .collect();
+        assert_eq!(ids, ["0xa", "0xb", "0xc", "0xd"]);
+    }
+
+    fn full_params() -> GammaListParams {
+        let date = DateTime::from_timestamp(4_000_000_000, 0).unwrap();
+        GammaListParams {
+            limit: Some(5),
+            offset: Some(10),
+            closed: Some(true),
+            tag_id: Some("2".to_string()),
+            exclude_tag_id: Some("7".to_string()),
+            related_tags: Some("true".to_string()),
+            order: Some(crate::types::OrderBy::Volume),
+            ascending: Some(false),
+            liquidity_num_min: Some(Decimal::from(500)),
+            liquidity_num_max: Some(Decimal::from(9000)),
+            end_date_max: Some(date),
+            start_date_min: Some(date),
+            start_date_max: Some(date),
+            end_date_min: Some(date),
+            volume_num_min: Some(Decimal::from(1)),
+            volume_num_max: Some(Decimal::from(2)),
+            id: Some(vec![1, 2]),
+            slug: Some(vec!["s".to_string()]),
+            clob_token_ids: Some(vec!["t".to_string()]),
+            condition_ids: Some(vec!["0xc".to_string()]),
+            market_maker_address: Some(vec!["0xm".to_string()]),
+            cyom: Some(false),
+            uma_resolution_status: Some("resolved".to_string()),
+            game_id: Some("g".to_string()),
+            sports_market_types: Some(vec!["moneyline".to_string()]),
+            rewards_min_size: Some(Decimal::from(3)),
+            question_ids: Some(vec!["0xq".to_string()]),
+            include_tag: Some(true),
+            fields: Some(vec!["question".to_string()]),
+            order_book_only: Some(true),
+        }
+    }
+
+    #[test]
+    fn test_markets_query_matches_generic_query_when_fully_specified() {
+        let params = full_params();
+        let markets = markets_params(Some("ignored"), Some(&params), Utc::now());
+        assert_eq!(markets.to_query_params(), params.to_query_params());
+    }
+
+    #[test]
+    fn test_markets_query_injects_documented_defaults() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let floor = now + Duration::weeks(3);
+
+        let defaults = markets_params(Some(&cursor::encode(100)), None, now);
+        assert_eq!(
+            defaults,
+            GammaListParams {
+                limit: Some(GAMMA_MARKETS_LIMIT),
+                offset: Some(100),
+                liquidity_num_min: Some(Decimal::from(10_000)),
+                end_date_max: Some(floor),
+                ..GammaListParams::default()
+            }
+        );
+        assert!(
+            !defaults
+                .to_query_params()
+                .iter()
+                .any(|(key, _)| *key == "closed")
+        );
+
+        let early = GammaListParams::builder().end_date_max(now);
+        assert_eq!(
+            markets_params(None, Some(&early), now).end_date_max,
+            Some(floor)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_markets_and_events_send_the_same_query() {
+        let params = full_params();
+        let expected = Matcher::AllOf(
+            params
+                .to_query_params()
+                .into_iter()
+                .map(|(key, value)| Matcher::UrlEncoded(key.to_string(), value))
+                .collect(),
+        );
+        let mut server = Server::new_async().await;
+        let markets = server
+            .mock("GET", "/markets")
+            .match_query(expected.clone())
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+        let events = server
+            .mock("GET", "/events")
+            .match_query(expected)
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        client.get_markets(None, Some(&params)).await.unwrap();
+        client.get_events(Some(&params)).await.unwrap();
+
+        markets.assert_async().await;
+        events.assert_async().await;
+    }
+
+    fn fast_retry() -> RetryPolicy {
+        RetryPolicy {
+            base_delay: std::time::Duration::from_millis(1),
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_recovers_from_transient_failures() {
+        let mut server = Server::new_async().await;
+        let failing = server
+            .mock("GET", "/tags")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/tags")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id": "1", "name": "Politics", "slug": "politics"}]"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new()
+            .with_base_url(&server.url())
+            .with_retry(fast_retry());
+        let tags = client.get_tags().await.unwrap();
+
+        assert_eq!(tags.len(), 1);
+        failing.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_retry_skips_client_errors_and_stops_at_max_attempts() {
+        let mut server = Server::new_async().await;
+        let not_found = server
+            .mock("GET", "/events/slug/missing")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+        let unavailable = server
+            .mock("GET", "/sports")
+            .with_status(502)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new()
+            .with_base_url(&server.url())
+            .with_retry(fast_retry());
+
+        let err = client.get_event_by_slug("missing").await.unwrap_err();
+        assert!(matches!(err, PolyError::Api { status: 404, .. }));
+        let err = client.get_sports().await.unwrap_err();
+        assert!(matches!(err, PolyError::Api { status: 502, .. }));
+
+        not_found.assert_async().await;
+        unavailable.assert_async().await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_honors_retry_after() {
+        let mut server = Server::new_async().await;
+        let limited = server
+            .mock("GET", "/tags")
+            .with_status(429)
+            .with_header("retry-after", "1")
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/tags")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = GammaClient::new()
+            .with_base_url(&server.url())
+            .with_retry(fast_retry());
+        let started = tokio::time::Instant::now();
+        client.get_tags().await.unwrap();
+
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+        limited.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_after_is_capped_by_max_delay() {
+        let mut server = Server::new_async().await;
+        let limited = server
+            .mock("GET", "/tags")
+            .with_status(429)
+            .with_header("retry-after", "86400")
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/tags")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = GammaClient::new()
+            .with_base_url(&server.url())
+            .with_retry(RetryPolicy {
+                max_delay: std::time::Duration::from_secs(5),
+                ..fast_retry()
+            });
+        let started = tokio::time::Instant::now();
+        client.get_tags().await.unwrap();
+
+        let waited = started.elapsed();
+        assert!(waited >= std::time::Duration::from_secs(5));
+        assert!(waited < std::time::Duration::from_secs(60));
+        limited.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_event_by_condition_id() {
+        let mut server = Server::new_async().await;
+        let found = server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded("condition_ids".into(), "0xabc".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"data": [{"id": "42", "slug": "fed-june", "markets": [{"conditionId": "0xabc"}]}]}"#,
+            )
+            .create_async()
+            .await;
+        let missing = server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded("condition_ids".into(), "0xnone".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await;
+        let _no_market = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded("condition_ids".into(), "0xnone".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let event = client.get_event("0xabc").await.unwrap();
+        assert_eq!(event.id, "42");
+        assert_eq!(event.slug, "fed-june");
+
+        let err = client.get_event("0xnone").await.unwrap_err();
+        assert!(matches!(err, PolyError::Api { status: 404, .. }));
+
+        found.assert_async().await;
+        missing.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_event_for_market_picks_parent_event() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded("condition_ids".into(), "0xabc".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": "7", "slug": "other", "markets": [{"conditionId": "0xdef"}]},
+                    {"id": "42", "slug": "fed-june", "markets": [{"conditionId": "0xABC"}]}
+                ]"#,
+            )
+            .create_async()
+            .await;
+        let orphan = server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded(
+                "condition_ids".into(),
+                "0xorphan".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id": "7", "slug": "other", "markets": [{"conditionId": "0xdef"}]}]"#)
+            .create_async()
+            .await;
+        let orphan_market = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded(
+                "condition_ids".into(),
+                "0xorphan".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{"conditionId": "0xorphan", "slug": "o", "active": true, "closed": false, "events": []}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let event = client.get_event_for_market("0xabc").await.unwrap();
+        assert_eq!(event.id, "42");
+        assert_eq!(event.slug, "fed-june");
+
+        let err = client.get_event_for_market("0xorphan").await.unwrap_err();
+        assert!(matches!(err, PolyError::Api { status: 404, .. }));
+
+        mock.assert_async().await;
+        orphan.assert_async().await;
+        orphan_market.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_event_for_market_follows_market_ref_without_embedded_markets() {
+        let mut server = Server::new_async().await;
+        let events = server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded("condition_ids".into(), "0xabc".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id": "7", "slug": "unrelated"}, {"id": "42", "slug": "fed-june"}]"#)
+            .create_async()
+            .await;
+        let market = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded("condition_ids".into(), "0xabc".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{"conditionId": "0xabc", "slug": "m", "active": true, "closed": false,
+                     "events": [{"id": 42}]}]"#,
+            )
+            .create_async()
+            .await;
+        let parent = server
+            .mock("GET", "/events/42")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id": "42", "slug": "fed-june"}"#)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let event = client.get_event_for_market("0xabc").await.unwrap();
+        assert_eq!(event.id, "42");
+
+        events.assert_async().await;
+        market.assert_async().await;
+        parent.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_resolution_details_parses_text() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded("condition_ids".into(), "0xabc".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{
+                    "conditionId": "0xabc",
+                    "slug": "rain-in-nyc",
+                    "question": "Will it rain in NYC?",
+                    "description": "Resolves Yes if NOAA reports rain.",
+                    "resolutionSource": "https://www.weather.gov",
+                    "questionID": "0xq",
+                    "umaResolutionStatus": "resolved",
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "outcomePrices": "[\"1\", \"0\"]",
+                    "active": true,
+                    "closed": true
+                }]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let details = client.get_resolution_details("0xabc").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            details.resolution_criteria.as_deref(),
+            Some("Resolves Yes if NOAA reports rain.")
+        );
+        assert_eq!(
+            details.resolution_source.as_deref(),
+            Some("https://www.weather.gov")
+        );
+        assert_eq!(details.question_id.as_deref(), Some("0xq"));
+        assert_eq!(details.ancillary_data, None);
+        assert_eq!(details.resolved_outcome.as_deref(), Some("Yes"));
+    }
+
+    #[tokio::test]
+    async fn test_get_resolution_details_tolerates_missing_fields() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{"conditionId": "0xabc", "slug": "s", "resolutionSource": "", "active": true, "closed": false}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let details = client.get_resolution_details("0xabc").await.unwrap();
+        assert_eq!(details.condition_id, "0xabc");
+        assert_eq!(details.resolution_source, None);
+        assert_eq!(details.resolution_criteria, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_events_by_slugs_skips_missing_and_keeps_order() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/events")
+            .match_query(Matcher::UrlEncoded(
+                "slug".into(),
+                "fed-june,missing,election".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": "2", "slug": "election"},
+                    {"id": "1", "slug": "fed-june"}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let slugs = vec![
+            "fed-june".to_string(),
+            "missing".to_string(),
+            "election".to_string(),
+        ];
+        let events = client.get_events_by_slugs(&slugs).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].slug, "fed-june");
+        assert_eq!(events[1].slug, "election");
+    }
+
+    #[tokio::test]
+    async fn test_neg_risk_groups_for_mixed_markets() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded(
+                "condition_ids".into(),
+                "0xneg1,0xneg2,0xsolo".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"conditionId": "0xneg1", "slug": "a", "active": true, "closed": false,
+                     "negRisk": true, "negRiskMarketID": "0xgroup"},
+                    {"conditionId": "0xneg2", "slug": "b", "active": true, "closed": false,
+                     "negRisk": true, "negRiskMarketID": "0xgroup"},
+                    {"conditionId": "0xsolo", "slug": "c", "active": true, "closed": false,
+                     "negRisk": false}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let ids = vec![
+            "0xneg1".to_string(),
+            "0xneg2".to_string(),
+            "0xsolo".to_string(),
+        ];
+        let groups = client.neg_risk_groups_for(&ids).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups["0xneg1"].as_deref(), Some("0xgroup"));
+        assert_eq!(groups["0xneg2"].as_deref(), Some("0xgroup"));
+        assert_eq!(groups["0xsolo"], None);
+    }
+
+    #[tokio::test]
+    async fn test_get_series_markets_flattens_instances() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/events")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("series_id".into(), "42".into()),
+                Matcher::UrlEncoded("offset".into(), "0".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": "1", "slug": "week-1", "markets": [
+                        {"conditionId": "0xw1", "slug": "week-1", "active": false, "closed": true}
+                    ]},
+                    {"id": "2", "slug": "week-2", "markets": [
+                        {"conditionId": "0xw2", "slug": "week-2", "active": true, "closed": false}
+                    ]},
+                    {"id": "3", "slug": "week-3"}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let markets = client.get_series_markets("42").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(markets.len(), 2);
+        assert_eq!(markets[0].condition_id, "0xw1");
+        assert_eq!(markets[1].condition_id, "0xw2");
+        assert!(markets.iter().all(|m| m.series_id.as_deref() == Some("42")));
+    }
+
+    #[tokio::test]
+    async fn test_markets_stream_walks_every_page() {
+        use futures::StreamExt;
+
+        let market = |id: &str| {
+            format!(r#"{{"conditionId": "{id}", "slug": "{id}", "active": true, "closed": false}}"#)
+        };
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded("offset".into(), "0".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!("[{}, {}]", market("0x1"), market("0x2")))
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded("offset".into(), "2".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!("[{}]", market("0x3")))
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let params = GammaListParams {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let markets: Vec<Result<Market>> = client.markets_stream(Some(params)).collect().await;
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(markets.len(), 3);
+        let ids: Vec<_> = markets
+            .into_iter()
+            .map(|market| market.unwrap().condition_id)
+            .collect();
+        assert_eq!(ids, ["0x1", "0x2", "0x3"]);
+    }
+
+    #[tokio::test]
+    async fn test_markets_stream_yields_error_and_stops() {
+        use futures::StreamExt;
+
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::Any)
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let items: Vec<Result<Market>> = client.markets_stream(None).collect().await;
+
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_recently_resolved_filters_window_and_outcome() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("closed".into(), "true".into()),
+                Matcher::UrlEncoded("end_date_min".into(), "2025-01-10T00:00:00+00:00".into()),
+                Matcher::UrlEncoded("liquidity_num_min".into(), "0".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"conditionId": "0xwon", "slug": "a", "active": false, "closed": true,
+                     "outcomes": "[\"Yes\", \"No\"]", "outcomePrices": "[\"0\", \"1\"]",
+                     "clobTokenIds": "[\"1\", \"2\"]",
+                     "endDate": "2025-01-12T00:00:00Z", "closedTime": "2025-01-12 18:04:11+00"},
+                    {"conditionId": "0xold", "slug": "b", "active": false, "closed": true,
+                     "outcomes": "[\"Yes\", \"No\"]", "outcomePrices": "[\"1\", \"0\"]",
+                     "endDate": "2025-01-11T00:00:00Z", "closedTime": "2025-01-05 09:00:00+00"},
+                    {"conditionId": "0xpending", "slug": "c", "active": false, "closed": true,
+                     "outcomes": "[\"Yes\", \"No\"]", "outcomePrices": "[\"0.62\", \"0.38\"]",
+                     "endDate": "2025-01-13T00:00:00Z"}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let since = "2025-01-10T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let markets = client.get_recently_resolved(since, None).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(markets.len(), 1);
+        assert_eq!(markets[0].condition_id, "0xwon");
+        assert!(markets[0].closed);
+        assert_eq!(markets[0].resolved_outcome.as_deref(), Some("No"));
+    }
+
+    #[tokio::test]
+    async fn test_get_market_social_tolerates_missing_counts() {
+        let mut server = Server::new_async().await;
+        let busy = server
+            .mock("GET", "/markets/101")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"id": "101", "conditionId": "0xabc", "commentCount": 42,
+                    "holderCount": "310", "question": "Will it rain?"}"#,
+            )
+            .create_async()
+            .await;
+        let quiet = server
+            .mock("GET", "/markets/102")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id": "102", "commentCount": 0, "holderCount": 0, "watchCount": 0}"#)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let stats = client.get_market_social(101).await.unwrap();
+        assert_eq!(stats.comment_count, Some(42));
+        assert_eq!(stats.holder_count, Some(310));
+        assert_eq!(stats.watch_count, None);
+
+        let stats = client.get_market_social(102).await.unwrap();
+        assert_eq!(
+            stats,
+            SocialStats {
+                comment_count: Some(0),
+                holder_count: Some(0),
+                watch_count: Some(0),
+            }
+        );
+
+        busy.assert_async().await;
+        quiet.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_markets_projects_requested_fields() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded(
+                "fields".into(),
+                "question,volumeNum".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{"conditionId": "0xabc", "slug": "a", "active": true, "closed": false,
+                     "question": "Will it rain?", "volumeNum": 1200,
+                     "liquidityNum": 500, "description": "long text"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = GammaClient::new().with_base_url(&server.url());
+        let params = GammaListParams::builder()
+            .fields(vec!["question".to_string(), "volumeNum".to_string()]);
+        let response = client.get_markets(None, Some(&params)).await.unwrap();
+
+        mock.assert_async().await;
+        let market = &response.data[0];
+        assert_eq!(market.condition_id, "0xabc");
+        assert_eq!(market.question, "Will it rain?");
+        assert_eq!(market.volume_num, Some(Decimal::from(1200)));
+        assert_eq!(market.liquidity_num, None);
+        assert!(market.description.is_empty());
+    }
+}
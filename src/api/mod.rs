@@ -1,3 +1,6 @@
+pub mod cursor;
+pub mod data;
 pub mod gamma;
 
-pub use gamma::GammaClient;
+pub use data::DataClient;
+pub use gamma::{GammaClient, RetryPolicy, merge_market_streams};
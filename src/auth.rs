@@ -5,16 +5,17 @@
 
 use crate::errors::{PolyError, Result};
 use crate::types::ApiCredentials;
-use alloy_primitives::{Address, U256, hex::encode_prefixed};
+use alloy_primitives::{Address, Signature, U256, hex::encode_prefixed};
 use alloy_signer::SignerSync;
 use alloy_signer_local::PrivateKeySigner;
-use alloy_sol_types::{eip712_domain, sol};
+use alloy_sol_types::{SolStruct, eip712_domain, sol};
 use base64::engine::Engine;
 use base64::engine::general_purpose::{STANDARD, URL_SAFE, URL_SAFE_NO_PAD};
 use hmac::{Hmac, Mac};
 use serde::Serialize;
 use sha2::Sha256;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 // Header constants
@@ -127,6 +128,27 @@ pub fn sign_order_message(
     Ok(encode_prefixed(signature.as_bytes()))
 }
 
+/// Recover the address that produced an EIP-712 order signature
+pub fn recover_order_signer(
+    order: &Order,
+    signature: &str,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> Result<Address> {
+    let domain = eip712_domain!(
+        name: "Polymarket CTF Exchange",
+        version: "1",
+        chain_id: chain_id,
+        verifying_contract: verifying_contract,
+    );
+
+    let signature = Signature::from_str(signature)
+        .map_err(|e| PolyError::crypto(format!("Invalid order signature: {}", e)))?;
+    signature
+        .recover_address_from_prehash(&order.eip712_signing_hash(&domain))
+        .map_err(|e| PolyError::crypto(format!("Order signature recovery failed: {}", e)))
+}
+
 /// Build HMAC signature for L2 authentication
 pub fn build_hmac_signature<T>(
     secret: &str,
@@ -264,6 +286,44 @@ mod tests {
         assert_ne!(sig2, sig3);
     }
 
+    #[test]
+    fn test_order_signature_is_stable_for_known_key() {
+        let signer: PrivateKeySigner =
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+                .parse()
+                .unwrap();
+        let exchange = Address::from_str("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E").unwrap();
+        let order = |signature_type: u8| Order {
+            salt: U256::from(479_249_096_354u64),
+            maker: signer.address(),
+            signer: signer.address(),
+            taker: Address::ZERO,
+            tokenId: U256::from(1234u64),
+            makerAmount: U256::from(4_500_000u64),
+            takerAmount: U256::from(10_000_000u64),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::ZERO,
+            side: 0,
+            signatureType: signature_type,
+        };
+
+        let eoa = sign_order_message(&signer, order(0), 137, exchange).unwrap();
+        assert_eq!(
+            eoa,
+            "0x9590b62c663dd021cbd1002941d302e8f38590675f9865764aabc2494018fb0e\
+             068ecfeae77bebe7b3f1c629c30517b631234b3a1875f723230ee50cfb8584a91c"
+        );
+        assert_eq!(
+            recover_order_signer(&order(0), &eoa, 137, exchange).unwrap(),
+            signer.address()
+        );
+
+        // The signature type is part of the signed struct.
+        let proxy = sign_order_message(&signer, order(1), 137, exchange).unwrap();
+        assert_ne!(proxy, eoa);
+    }
+
     #[test]
     fn test_decode_api_secret_with_urlsafe_padding() {
         assert_eq!(decode_api_secret("cQ=="), b"q".to_vec());
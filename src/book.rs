@@ -311,6 +311,38 @@ impl OrderBook {
         }
     }
 
+    /// Replace both sides with a full snapshot
+    /// Used when the exchange sends the whole book (e.g. a WSS `book` event)
+    /// rather than a delta. Zero-size levels are skipped and the depth limit
+    /// still applies.
+    pub fn apply_snapshot(&mut self, snapshot: &crate::types::OrderBook) -> Result<()> {
+        if snapshot.token_id != self.token_id {
+            return Err(PolyError::validation("Token ID mismatch"));
+        }
+
+        let levels = |side: &[BookLevel]| -> Result<BTreeMap<Price, Qty>> {
+            let mut converted = BTreeMap::new();
+            for level in side.iter().filter(|level| !level.size.is_zero()) {
+                let price = decimal_to_price(level.price)
+                    .map_err(|e| PolyError::validation(format!("Invalid snapshot price: {}", e)))?;
+                let size = decimal_to_qty(level.size)
+                    .map_err(|e| PolyError::validation(format!("Invalid snapshot size: {}", e)))?;
+                converted.insert(price, size);
+            }
+            Ok(converted)
+        };
+        // Convert both sides before touching the book so a bad level leaves it intact
+        let bids = levels(&snapshot.bids)?;
+        let asks = levels(&snapshot.asks)?;
+
+        self.bids = bids;
+        self.asks = asks;
+        self.sequence = snapshot.sequence;
+        self.timestamp = snapshot.timestamp;
+        self.trim_depth();
+        Ok(())
+    }
+
     /// Apply a delta update to the book (LEGACY VERSION - for external API compatibility)
     /// A "delta" is an incremental change - like "add 100 tokens at $0.65" or "remove all at $0.70"
     ///
@@ -361,7 +393,7 @@ impl OrderBook {
             // }
 
             // AFTER (fast, ~2ns, pure integer):
-            if tick_size_ticks > 0 && delta.price % tick_size_ticks != 0 {
+            if tick_size_ticks > 0 && !delta.price.is_multiple_of(tick_size_ticks) {
                 // Price is not aligned to tick size - reject the update
                 warn!(
                     "Rejecting misaligned price: {} not divisible by tick size {}",
@@ -627,6 +659,16 @@ impl OrderBook {
         qty_to_decimal(total_size_units)
     }
 
+    /// Order-book imbalance over the top `levels` price levels per side:
+    /// `(bid_vol - ask_vol) / (bid_vol + ask_vol)`, from -1 (all asks) to 1
+    /// (all bids). `None` when those levels are empty.
+    pub fn imbalance(&self, levels: usize) -> Option<Decimal> {
+        // Sum in fixed-point and convert once
+        let bid_units: Qty = self.bids.values().rev().take(levels).sum();
+        let ask_units: Qty = self.asks.values().take(levels).sum();
+        math::book_imbalance(qty_to_decimal(bid_units), qty_to_decimal(ask_units))
+    }
+
     /// Validate that prices are properly ordered
     /// A healthy book should have best bid < best ask (otherwise there's an arbitrage opportunity)
     pub fn is_valid(&self) -> bool {
@@ -711,6 +753,39 @@ impl OrderBookManager {
         book.apply_delta(delta)
     }
 
+    /// Replace a book with a full snapshot
+    /// Creates the book if this is the first we've heard of the token
+    pub fn apply_snapshot(&self, snapshot: &crate::types::OrderBook) -> Result<()> {
+        let mut books = self
+            .books
+            .write()
+            .map_err(|_| PolyError::internal_simple("Failed to acquire book lock"))?;
+
+        books
+            .entry(snapshot.token_id.clone())
+            .or_insert_with(|| OrderBook::new(snapshot.token_id.clone(), self.max_depth))
+            .apply_snapshot(snapshot)
+    }
+
+    /// Order-book imbalance of a tracked book
+    /// See [`OrderBook::imbalance`]; errors if the token has no book yet
+    pub fn imbalance(&self, token_id: &str, levels: usize) -> Result<Option<Decimal>> {
+        let books = self
+            .books
+            .read()
+            .map_err(|_| PolyError::internal_simple("Failed to acquire book lock"))?;
+
+        books
+            .get(token_id)
+            .map(|book| book.imbalance(levels))
+            .ok_or_else(|| {
+                PolyError::market_data(
+                    format!("No book found for token: {}", token_id),
+                    crate::errors::MarketDataErrorKind::TokenNotFound,
+                )
+            })
+    }
+
     /// Get a book snapshot
     /// Returns a copy of the current book state that won't change
     pub fn get_book(&self, token_id: &str) -> Result<crate::types::OrderBook> {
@@ -1108,6 +1183,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_apply_snapshot_replaces_levels() {
+        let mut book = OrderBook::new("test_token".to_string(), 2);
+        book.apply_bid_delta(dec!(0.10), dec!(1));
+
+        let level = |price, size| BookLevel { price, size };
+        let snapshot = crate::types::OrderBook {
+            token_id: "test_token".to_string(),
+            timestamp: Utc::now(),
+            bids: vec![
+                level(dec!(0.40), dec!(10)),
+                level(dec!(0.45), dec!(5)),
+                level(dec!(0.30), dec!(7)),
+                level(dec!(0.44), dec!(0)),
+            ],
+            asks: vec![level(dec!(0.55), dec!(8))],
+            sequence: 7,
+        };
+        book.apply_snapshot(&snapshot).unwrap();
+
+        // The old bid is gone and the depth limit drops the 0.30 bid
+        let prices: Vec<_> = book.bids(None).iter().map(|l| l.price).collect();
+        assert_eq!(prices, [dec!(0.45), dec!(0.40)]);
+        assert_eq!(book.best_ask().unwrap().size, dec!(8));
+        assert_eq!(book.sequence, 7);
+
+        let other = crate::types::OrderBook {
+            token_id: "other".to_string(),
+            ..snapshot
+        };
+        assert!(book.apply_snapshot(&other).is_err());
+    }
+
+    #[test]
+    fn test_imbalance_over_top_levels() {
+        let mut book = OrderBook::new("test_token".to_string(), 10);
+        assert_eq!(book.imbalance(1), None);
+
+        book.apply_bid_delta(dec!(0.50), dec!(300));
+        book.apply_bid_delta(dec!(0.49), dec!(100));
+        book.apply_bid_delta(dec!(0.48), dec!(100));
+        book.apply_ask_delta(dec!(0.52), dec!(100));
+        book.apply_ask_delta(dec!(0.53), dec!(200));
+        book.apply_ask_delta(dec!(0.54), dec!(300));
+        // Outside the top three levels
+        book.apply_ask_delta(dec!(0.60), dec!(1000));
+
+        // (300 - 100) / 400
+        assert_eq!(book.imbalance(1), Some(dec!(0.5)));
+        // (500 - 600) / 1100
+        assert_eq!(book.imbalance(3), Some(dec!(-100) / dec!(1100)));
+        assert_eq!(book.imbalance(0), None);
+    }
+
     #[test]
     fn test_fast_operations() {
         let mut book = OrderBook::new("test_token".to_string(), 10);
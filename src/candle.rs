@@ -0,0 +1,290 @@
+//! OHLC candles built from last-trade events.
+//!
+//! Trades are bucketed by their exchange timestamp. Intervals without trades
+//! still produce a flat candle at the previous close, so charts have no
+//! gaps. Because idle intervals can only be closed by the passage of time,
+//! the aggregator keeps an estimate of exchange time anchored on the latest
+//! trade and advanced by the local monotonic clock.
+
+use crate::errors::{PolyError, Result};
+use crate::wss::LastTradeMessage;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::debug;
+
+const DEFAULT_CANDLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A closed OHLC candle for one asset over `[start, end)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candle {
+    pub asset_id: String,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+struct OpenCandle {
+    candle: Candle,
+    traded: bool,
+}
+
+/// Folds [`LastTradeMessage`]s into fixed-interval candles per asset.
+#[derive(Debug, Clone)]
+pub struct CandleAggregator {
+    interval: Duration,
+    open: HashMap<String, OpenCandle>,
+    clock: Option<(i64, Instant)>,
+}
+
+impl Default for CandleAggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CandleAggregator {
+    /// One-minute candles.
+    pub fn new() -> Self {
+        Self::with_interval(DEFAULT_CANDLE_INTERVAL)
+    }
+
+    /// Candles of the given length, which must be at least one millisecond.
+    pub fn with_interval(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+            open: HashMap::new(),
+            clock: None,
+        }
+    }
+
+    /// Add a trade, returning any candles it closes for that asset.
+    ///
+    /// Trades older than the asset's current candle are dropped.
+    pub fn ingest(&mut self, trade: &LastTradeMessage) -> Result<Vec<Candle>> {
+        let ts = trade.timestamp.trim().parse::<i64>().map_err(|err| {
+            PolyError::parse(
+                format!("Invalid trade timestamp '{}': {}", trade.timestamp, err),
+                Some(Box::new(err)),
+            )
+        })?;
+        if self.clock.is_none_or(|(anchor, _)| ts >= anchor) {
+            self.clock = Some((ts, Instant::now()));
+        }
+
+        let interval = self.interval_ms();
+        let bucket = ts.div_euclid(interval) * interval;
+        let mut closed = Vec::new();
+
+        let mut entry = match self.open.remove(&trade.asset_id) {
+            Some(current) => {
+                let current_start = current.candle.start.timestamp_millis();
+                if bucket < current_start {
+                    debug!(
+                        "Dropping late trade for {} at {}",
+                        trade.asset_id, trade.timestamp
+                    );
+                    self.open.insert(trade.asset_id.clone(), current);
+                    return Ok(closed);
+                }
+                roll_forward(current, bucket, interval, &mut closed)
+            }
+            None => OpenCandle {
+                candle: flat_candle(&trade.asset_id, trade.price, bucket, interval),
+                traded: false,
+            },
+        };
+
+        let candle = &mut entry.candle;
+        if entry.traded {
+            candle.high = candle.high.max(trade.price);
+            candle.low = candle.low.min(trade.price);
+        } else {
+            candle.open = trade.price;
+            candle.high = trade.price;
+            candle.low = trade.price;
+            entry.traded = true;
+        }
+        candle.close = trade.price;
+        candle.volume += trade.size;
+        self.open.insert(trade.asset_id.clone(), entry);
+
+        Ok(closed)
+    }
+
+    /// Close every candle whose interval has ended according to the
+    /// estimated exchange clock, including flat candles for idle intervals.
+    ///
+    /// Call this on a timer (see [`crate::feed::with_ticks`]).
+    pub fn close_elapsed(&mut self) -> Vec<Candle> {
+        let Some(now) = self.exchange_now_ms() else {
+            return Vec::new();
+        };
+        let interval = self.interval_ms();
+        let bucket = now.div_euclid(interval) * interval;
+
+        let mut closed = Vec::new();
+        let assets: Vec<String> = self.open.keys().cloned().collect();
+        for asset_id in assets {
+            if let Some(current) = self.open.remove(&asset_id) {
+                let next = roll_forward(current, bucket, interval, &mut closed);
+                self.open.insert(asset_id, next);
+            }
+        }
+        closed.sort_by(|a, b| (a.start, &a.asset_id).cmp(&(b.start, &b.asset_id)));
+        closed
+    }
+
+    fn interval_ms(&self) -> i64 {
+        self.interval.as_millis() as i64
+    }
+
+    fn exchange_now_ms(&self) -> Option<i64> {
+        self.clock
+            .map(|(anchor, seen_at)| anchor + seen_at.elapsed().as_millis() as i64)
+    }
+}
+
+/// Close `current` and any idle intervals before `bucket`, returning the
+/// candle that is open for `bucket`.
+fn roll_forward(
+    mut current: OpenCandle,
+    bucket: i64,
+    interval: i64,
+    closed: &mut Vec<Candle>,
+) -> OpenCandle {
+    let mut start = current.candle.start.timestamp_millis();
+    while start < bucket {
+        let close = current.candle.close;
+        let asset_id = current.candle.asset_id.clone();
+        closed.push(current.candle);
+        start += interval;
+        current = OpenCandle {
+            candle: flat_candle(&asset_id, close, start, interval),
+            traded: false,
+        };
+    }
+    current
+}
+
+fn flat_candle(asset_id: &str, price: Decimal, start_ms: i64, interval_ms: i64) -> Candle {
+    Candle {
+        asset_id: asset_id.to_string(),
+        open: price,
+        high: price,
+        low: price,
+        close: price,
+        volume: Decimal::ZERO,
+        start: millis_to_datetime(start_ms),
+        end: millis_to_datetime(start_ms + interval_ms),
+    }
+}
+
+fn millis_to_datetime(ms: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(ms).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    // 2023-11-14T22:13:00Z, on a minute boundary.
+    const MINUTE_0: i64 = 1_700_000_000_000 - 20_000;
+
+    fn trade(offset_ms: i64, price: Decimal, size: Decimal) -> LastTradeMessage {
+        serde_json::from_value(serde_json::json!({
+            "event_type": "last_trade_price",
+            "asset_id": "123",
+            "market": "0xabc",
+            "price": price.to_string(),
+            "side": "BUY",
+            "size": size.to_string(),
+            "fee_rate_bps": "0",
+            "timestamp": (MINUTE_0 + offset_ms).to_string()
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_candles_across_two_minutes() {
+        let mut agg = CandleAggregator::new();
+
+        assert!(
+            agg.ingest(&trade(5_000, dec!(0.50), dec!(10)))
+                .unwrap()
+                .is_empty()
+        );
+        tokio::time::advance(Duration::from_secs(20)).await;
+        assert!(
+            agg.ingest(&trade(25_000, dec!(0.55), dec!(5)))
+                .unwrap()
+                .is_empty()
+        );
+        tokio::time::advance(Duration::from_secs(20)).await;
+        assert!(
+            agg.ingest(&trade(45_000, dec!(0.48), dec!(2)))
+                .unwrap()
+                .is_empty()
+        );
+
+        // The first trade of minute two closes minute one.
+        tokio::time::advance(Duration::from_secs(25)).await;
+        let closed = agg.ingest(&trade(70_000, dec!(0.52), dec!(4))).unwrap();
+        assert_eq!(
+            closed,
+            vec![Candle {
+                asset_id: "123".to_string(),
+                open: dec!(0.50),
+                high: dec!(0.55),
+                low: dec!(0.48),
+                close: dec!(0.48),
+                volume: dec!(17),
+                start: millis_to_datetime(MINUTE_0),
+                end: millis_to_datetime(MINUTE_0 + 60_000),
+            }]
+        );
+
+        // Minute two closes on the clock alone, then minute three is idle.
+        tokio::time::advance(Duration::from_secs(45)).await;
+        assert!(agg.close_elapsed().is_empty());
+        tokio::time::advance(Duration::from_secs(80)).await;
+        let closed = agg.close_elapsed();
+        assert_eq!(closed.len(), 2);
+
+        let minute_two = &closed[0];
+        assert_eq!(minute_two.start, millis_to_datetime(MINUTE_0 + 60_000));
+        assert_eq!(minute_two.open, dec!(0.52));
+        assert_eq!(minute_two.close, dec!(0.52));
+        assert_eq!(minute_two.volume, dec!(4));
+
+        let idle = &closed[1];
+        assert_eq!(idle.start, millis_to_datetime(MINUTE_0 + 120_000));
+        assert_eq!(
+            (idle.open, idle.high, idle.low, idle.close),
+            (dec!(0.52), dec!(0.52), dec!(0.52), dec!(0.52))
+        );
+        assert_eq!(idle.volume, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_late_trade_is_dropped() {
+        let mut agg = CandleAggregator::with_interval(Duration::from_secs(60));
+        agg.ingest(&trade(65_000, dec!(0.50), dec!(1))).unwrap();
+        assert!(
+            agg.ingest(&trade(5_000, dec!(0.90), dec!(1)))
+                .unwrap()
+                .is_empty()
+        );
+        let current = &agg.open["123"].candle;
+        assert_eq!(current.high, dec!(0.50));
+        assert_eq!(current.volume, dec!(1));
+    }
+}
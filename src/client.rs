@@ -5,14 +5,14 @@
 
 use crate::api::gamma::GammaClient;
 use crate::auth::{create_l1_headers, create_l2_headers};
+use crate::config::ChainEnv;
 use crate::errors::{PolyError, Result};
 use crate::types::{OrderOptions, PostOrder, SignedOrderRequest};
+use crate::utils::rate_limit::TokenBucket;
 use alloy_primitives::{Address, U256};
 use alloy_signer_local::PrivateKeySigner;
 use async_trait::async_trait;
-use base64::Engine;
-use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use reqwest::Client;
 use reqwest::header::HeaderName;
 use reqwest::{Method, RequestBuilder};
@@ -21,18 +21,138 @@ use rust_decimal::prelude::FromPrimitive;
 use serde_json::{self, Value};
 use std::env;
 use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tracing::warn;
 
-const DEFAULT_CLOB_BASE: &str = "https://clob.polymarket.com";
+fn parse_cancel_response(response: Value) -> Result<CancelResponse> {
+    serde_json::from_value(response)
+        .map_err(|e| PolyError::parse(format!("Failed to parse cancel response: {}", e), None))
+}
+
+/// Typed error for an order rejection the exchange explains, e.g.
+/// "not enough balance / allowance".
+fn order_rejection(message: &str) -> Option<PolyError> {
+    let lower = message.to_ascii_lowercase();
+    if lower.contains("not enough balance") || lower.contains("allowance") {
+        return Some(PolyError::order(
+            format!("Account is not funded for this order: {}", message),
+            crate::errors::OrderErrorKind::InsufficientBalance,
+        ));
+    }
+    None
+}
+
+/// Order options for `order_args` on `market`: the token must belong to the
+/// market and the price must pass [`Market::validate_order_price`]; the tick
+/// is the one in effect at that price.
+fn market_order_options(market: &Market, order_args: &OrderArgs) -> Result<OrderOptions> {
+    if !market
+        .tokens
+        .iter()
+        .any(|token| token.token_id == order_args.token_id)
+    {
+        return Err(PolyError::invalid_field(
+            "token_id",
+            format!(
+                "token {} is not part of market {}",
+                order_args.token_id, market.condition_id
+            ),
+        ));
+    }
+    market.validate_order_price(order_args.price)?;
+    Ok(OrderOptions {
+        tick_size: Some(market.tick_size_at(order_args.price)),
+        neg_risk: None,
+        fee_rate_bps: None,
+    })
+}
+
+/// The exchange rejects GTD orders expiring less than a minute out.
+const GTD_MIN_EXPIRATION_BUFFER: Duration = Duration::from_secs(60);
+
+/// Decode a JSON response body, reporting Cloudflare edge failures (HTML error
+/// pages with a 52x status) as [`PolyError::Infrastructure`] instead of a parse error.
+async fn decode_json<T: serde::de::DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+    let status = response.status().as_u16();
+    if PolyError::is_cloudflare_status(status) {
+        return Err(PolyError::infrastructure(
+            status,
+            format!("origin unreachable for {}", response.url().path()),
+        ));
+    }
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+}
+
+/// Decimal at `key` in a price endpoint response. Empty books come back with
+/// the field missing, null, or an empty string; those map to `None`.
+fn optional_decimal_field(payload: &Value, key: &str) -> Result<Option<Decimal>> {
+    let raw = match payload.get(key) {
+        None | Some(Value::Null) => return Ok(None),
+        Some(Value::String(raw)) if raw.trim().is_empty() => return Ok(None),
+        Some(Value::String(raw)) => raw.trim().to_string(),
+        Some(Value::Number(raw)) => raw.to_string(),
+        Some(other) => {
+            return Err(PolyError::parse(
+                format!("Unexpected {} value: {}", key, other),
+                None,
+            ));
+        }
+    };
+    Decimal::from_str(&raw)
+        .map(Some)
+        .map_err(|e| PolyError::parse(format!("Invalid {} value {}: {}", key, raw, e), None))
+}
+
+/// Decode a `{token_id: "decimal"}` batch payload. Tokens whose value is null
+/// or empty (no book on one side) are left out.
+fn decimal_map(payload: &Value) -> Result<std::collections::HashMap<String, Decimal>> {
+    let entries = payload.as_object().ok_or_else(|| {
+        PolyError::parse(
+            format!("Expected an object keyed by token ID, got {}", payload),
+            None,
+        )
+    })?;
+    let mut values = std::collections::HashMap::with_capacity(entries.len());
+    for token_id in entries.keys() {
+        if let Some(value) = optional_decimal_field(payload, token_id)? {
+            values.insert(token_id.clone(), value);
+        }
+    }
+    Ok(values)
+}
+
+/// Pair each key with its entry in `map`, in the order of `keys`; keys the
+/// server left out map to `None`.
+fn in_input_order<K, V>(keys: &[K], map: &std::collections::HashMap<K, V>) -> Vec<(K, Option<V>)>
+where
+    K: Eq + std::hash::Hash + Clone,
+    V: Clone,
+{
+    keys.iter()
+        .map(|key| (key.clone(), map.get(key).cloned()))
+        .collect()
+}
+
+pub(crate) const DEFAULT_CLOB_BASE: &str = "https://clob.polymarket.com";
 const DEFAULT_GAMMA_BASE: &str = "https://gamma-api.polymarket.com";
 const DEFAULT_WS_BASE: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/";
 const DEFAULT_RTDS_BASE: &str = "wss://ws-live-data.polymarket.com";
-const DEFAULT_DATA_API_BASE: &str = "https://data-api.polymarket.com";
-const GAMMA_MARKETS_LIMIT: u32 = 50;
+const ORDER_POLL_INITIAL_INTERVAL: Duration = Duration::from_millis(250);
+const ORDER_POLL_MAX_INTERVAL: Duration = Duration::from_secs(2);
 
 // Re-export types for compatibility
 pub use crate::types::{ApiCredentials as ApiCreds, OrderType, Side};
 
+/// L2 credentials shared between clients; see [`ClobClient::with_shared_credentials`].
+pub type SharedCredentials = Arc<RwLock<ApiCreds>>;
+
+/// Body argument for [`ClobClient::send_l2`] requests without one.
+const NO_BODY: Option<&()> = None;
+
 // Compatibility types
 #[derive(Debug, Clone)]
 pub struct OrderArgs {
@@ -40,6 +160,9 @@ pub struct OrderArgs {
     pub price: Decimal,
     pub size: Decimal,
     pub side: Side,
+    /// Relative GTD expiration, resolved against server time when the order
+    /// is built. Ignored when an explicit expiration is passed.
+    pub expires_in: Option<Duration>,
 }
 
 impl OrderArgs {
@@ -49,8 +172,26 @@ impl OrderArgs {
             price,
             size,
             side,
+            expires_in: None,
         }
     }
+
+    /// Size the order to spend about `usdc` at `price`. The share size is
+    /// rounded down to the CLOB's 0.01 lot, so the notional never exceeds
+    /// `usdc`; a zero price gives a zero size.
+    pub fn with_usdc_notional(mut self, usdc: Decimal, price: Decimal) -> Self {
+        self.price = price;
+        self.size = crate::utils::math::usdc_to_shares(usdc, price)
+            .round_dp_with_strategy(2, rust_decimal::RoundingStrategy::ToZero);
+        self
+    }
+
+    /// Expire the order `offset` after it is built. The offset must exceed
+    /// the exchange's one-minute GTD buffer.
+    pub fn expires_in(mut self, offset: Duration) -> Self {
+        self.expires_in = Some(offset);
+        self
+    }
 }
 
 impl Default for OrderArgs {
@@ -60,46 +201,32 @@ impl Default for OrderArgs {
             price: Decimal::ZERO,
             size: Decimal::ZERO,
             side: Side::BUY,
+            expires_in: None,
         }
     }
 }
 
 /// Client for Polymarket's public data API.
 ///
-/// This client is intentionally light-weight and only serves the
-/// public `/value` and `/positions` endpoints, which do not require
-/// authentication.
-#[derive(Debug, Clone)]
+/// Kept for compatibility; it delegates to [`crate::api::DataClient`], which
+/// also serves `/trades`.
+#[derive(Debug, Clone, Default)]
 pub struct DataApiClient {
-    http_client: Client,
-    base_url: String,
+    data: crate::api::DataClient,
 }
 
 impl DataApiClient {
     /// Create a data API client using the default base URL.
     pub fn new() -> Self {
-        Self {
-            http_client: Client::new(),
-            base_url: DEFAULT_DATA_API_BASE.to_string(),
-        }
+        Self::default()
     }
 
     /// Override the base URL (useful for testing or staging).
     pub fn with_base_url(mut self, url: &str) -> Self {
-        self.base_url = url.to_string();
+        self.data = self.data.with_base_url(url);
         self
     }
 
-    fn data_api_url(&self, path: &str) -> String {
-        let base = self.base_url.trim_end_matches('/');
-        let path = path.trim_start_matches('/');
-        if path.is_empty() {
-            base.to_string()
-        } else {
-            format!("{}/{}", base, path)
-        }
-    }
-
     /// Fetch the total positions value for a single user wallet.
     ///
     /// Returns the same structure as the `GET /value` public endpoint.
@@ -107,61 +234,72 @@ impl DataApiClient {
         &self,
         user: &str,
     ) -> Result<Vec<crate::types::DataPositionValue>> {
-        let response = self
-            .http_client
-            .get(self.data_api_url("value"))
-            .query(&[("user", user)])
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch total positions value",
-            ));
-        }
-
-        response
-            .json::<Vec<crate::types::DataPositionValue>>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        self.data.get_total_positions_value(user).await
     }
 
     /// Retrieve the current open positions for a wallet.
     ///
-    /// This wraps the `GET /positions` endpoint and automatically applies
-    /// sane defaults when the optional `params` argument is omitted.
+    /// This wraps the `GET /positions` endpoint; fields left unset in
+    /// `params` fall back to the server's defaults.
     pub async fn get_positions(
         &self,
         user: &str,
         params: Option<crate::types::DataApiPositionsParams>,
     ) -> Result<Vec<crate::types::DataPosition>> {
-        let params = params.unwrap_or_default();
-        let mut query = params.to_query_params();
-        query.push(("user", user.to_string()));
+        self.data
+            .get_positions_with_params(user, &params.unwrap_or_default())
+            .await
+    }
+}
 
-        let response = self
-            .http_client
-            .get(self.data_api_url("positions"))
-            .query(&query)
-            .send()
-            .await?;
+/// Check that `key` is 32 bytes of hex, with or without `0x`, and build a
+/// signer from it. Errors never echo the key itself.
+fn parse_private_key(key: &str) -> Result<PrivateKeySigner> {
+    let hex = key.trim();
+    let hex = hex
+        .strip_prefix("0x")
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(PolyError::validation(
+            "invalid private key: contains non-hex characters",
+        ));
+    }
+    if hex.len() != 64 {
+        return Err(PolyError::validation(format!(
+            "invalid private key: expected 64 hex characters (32 bytes), got {}",
+            hex.len()
+        )));
+    }
+    hex.parse::<PrivateKeySigner>()
+        .map_err(|err| PolyError::validation(format!("invalid private key: {}", err)))
+}
 
-        if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch positions",
-            ));
-        }
+/// `RequestBuilder::send`, after taking a token from the client's rate
+/// limiter when one is configured.
+trait SendLimited {
+    async fn send_limited(
+        self,
+        limiter: &Option<TokenBucket>,
+    ) -> reqwest::Result<reqwest::Response>;
+}
 
-        response
-            .json::<Vec<crate::types::DataPosition>>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+impl SendLimited for RequestBuilder {
+    async fn send_limited(
+        self,
+        limiter: &Option<TokenBucket>,
+    ) -> reqwest::Result<reqwest::Response> {
+        if let Some(limiter) = limiter {
+            limiter.acquire().await;
+        }
+        self.send().await
     }
 }
 
 /// Main client for interacting with Polymarket API
+///
+/// Clones share credentials and the rate limiter.
+#[derive(Clone)]
 pub struct ClobClient {
     http_client: Client,
     base_url: String,
@@ -170,9 +308,10 @@ pub struct ClobClient {
     rtds_base_url: String,
     chain_id: u64,
     signer: Option<PrivateKeySigner>,
-    api_creds: Option<ApiCreds>,
+    api_creds: Option<SharedCredentials>,
     order_builder: Option<crate::orders::OrderBuilder>,
     gamma_client: GammaClient,
+    rate_limiter: Option<TokenBucket>,
 }
 
 impl ClobClient {
@@ -189,6 +328,16 @@ impl ClobClient {
             api_creds: None,
             order_builder: None,
             gamma_client: GammaClient::new(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Unauthenticated client for the CLOB on `chain_id`, e.g. 80002 for
+    /// Polymarket's Amoy staging CLOB; see [`ChainEnv::from_chain_id`].
+    pub fn for_chain(chain_id: u64) -> Self {
+        Self {
+            chain_id,
+            ..Self::new(&ChainEnv::from_chain_id(chain_id).clob_url)
         }
     }
 
@@ -196,9 +345,10 @@ impl ClobClient {
     ///
     /// This performs L1 auth to create/derive API creds, then constructs the L2 client.
     pub async fn new_with_auth(private_key: &str, funder: Option<&str>) -> Result<Self> {
-        let l1_client = ClobClient::with_l1_headers(DEFAULT_CLOB_BASE, private_key, 137);
+        let l1_client = ClobClient::try_with_l1_headers(DEFAULT_CLOB_BASE, private_key, 137)?;
         let creds = l1_client.create_or_derive_api_key(None).await?;
-        let mut client = ClobClient::with_l2_headers(DEFAULT_CLOB_BASE, private_key, 137, creds);
+        let mut client =
+            ClobClient::try_with_l2_headers(DEFAULT_CLOB_BASE, private_key, 137, creds)?;
 
         if let Some(funder) = funder {
             client.set_funder(funder)?;
@@ -207,18 +357,6 @@ impl ClobClient {
         Ok(client)
     }
 
-    fn encode_cursor(cursor: u64) -> String {
-        BASE64_ENGINE.encode(cursor.to_string())
-    }
-
-    fn decode_cursor(cursor: &str) -> Option<u64> {
-        BASE64_ENGINE
-            .decode(cursor)
-            .ok()
-            .and_then(|bytes| String::from_utf8(bytes).ok())
-            .and_then(|s| s.parse::<u64>().ok())
-    }
-
     fn build_url(base: &str, path: &str) -> String {
         let base = base.trim_end_matches('/');
         let path = path.trim_start_matches('/');
@@ -238,14 +376,24 @@ impl ClobClient {
     }
 
     /// Create a client with L1 headers (for authentication)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `private_key` is invalid; see
+    /// [`ClobClient::try_with_l1_headers`] for a fallible version.
     pub fn with_l1_headers(host: &str, private_key: &str, chain_id: u64) -> Self {
-        let signer = private_key
-            .parse::<PrivateKeySigner>()
-            .expect("Invalid private key");
+        Self::try_with_l1_headers(host, private_key, chain_id)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`ClobClient::with_l1_headers`], returning a validation error
+    /// when `private_key` isn't 32 bytes of hex.
+    pub fn try_with_l1_headers(host: &str, private_key: &str, chain_id: u64) -> Result<Self> {
+        let signer = parse_private_key(private_key)?;
 
         let order_builder = crate::orders::OrderBuilder::new(signer.clone(), None, None);
 
-        Self {
+        Ok(Self {
             http_client: Client::new(),
             base_url: host.to_string(),
             gamma_base_url: DEFAULT_GAMMA_BASE.to_string(),
@@ -256,23 +404,39 @@ impl ClobClient {
             api_creds: None,
             order_builder: Some(order_builder),
             gamma_client: GammaClient::new(),
-        }
+            rate_limiter: None,
+        })
     }
 
     /// Create a client with L2 headers (for API key authentication)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `private_key` is invalid; see
+    /// [`ClobClient::try_with_l2_headers`] for a fallible version.
     pub fn with_l2_headers(
         host: &str,
         private_key: &str,
         chain_id: u64,
         api_creds: ApiCreds,
     ) -> Self {
-        let signer = private_key
-            .parse::<PrivateKeySigner>()
-            .expect("Invalid private key");
+        Self::try_with_l2_headers(host, private_key, chain_id, api_creds)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`ClobClient::with_l2_headers`], returning a validation error
+    /// when `private_key` isn't 32 bytes of hex.
+    pub fn try_with_l2_headers(
+        host: &str,
+        private_key: &str,
+        chain_id: u64,
+        api_creds: ApiCreds,
+    ) -> Result<Self> {
+        let signer = parse_private_key(private_key)?;
 
         let order_builder = crate::orders::OrderBuilder::new(signer.clone(), None, None);
 
-        Self {
+        Ok(Self {
             http_client: Client::new(),
             base_url: host.to_string(),
             gamma_base_url: DEFAULT_GAMMA_BASE.to_string(),
@@ -280,16 +444,62 @@ impl ClobClient {
             rtds_base_url: DEFAULT_RTDS_BASE.to_string(),
             chain_id,
             signer: Some(signer),
-            api_creds: Some(api_creds),
+            api_creds: Some(Arc::new(RwLock::new(api_creds))),
             order_builder: Some(order_builder),
             gamma_client: GammaClient::new(),
+            rate_limiter: None,
         }
-        .with_env_funder()
+        .with_env_funder())
+    }
+
+    /// Address of the signing key, if the client has one.
+    pub fn address(&self) -> Option<Address> {
+        self.signer.as_ref().map(|signer| signer.address())
     }
 
-    /// Set API credentials
+    /// Set API credentials. If the client already shares a credential cell,
+    /// the new credentials are written into it, so every holder sees them.
     pub fn set_api_creds(&mut self, api_creds: ApiCreds) {
-        self.api_creds = Some(api_creds);
+        match &self.api_creds {
+            Some(cell) => *cell.write().unwrap_or_else(|e| e.into_inner()) = api_creds,
+            None => self.api_creds = Some(Arc::new(RwLock::new(api_creds))),
+        }
+    }
+
+    /// Handle to this client's L2 credentials, for sharing with other
+    /// clients via [`ClobClient::with_shared_credentials`].
+    pub fn shared_credentials(&self) -> Option<SharedCredentials> {
+        self.api_creds.clone()
+    }
+
+    /// Use a credential cell shared with other holders. Whichever holder
+    /// updates it (e.g. through [`ClobClient::refresh_credentials`]), all
+    /// of them sign subsequent requests with the new credentials.
+    pub fn with_shared_credentials(mut self, credentials: SharedCredentials) -> Self {
+        self.api_creds = Some(credentials);
+        self
+    }
+
+    /// Create or derive the API key with the L1 signer and store it in the
+    /// shared credential cell, so all holders see the refreshed credentials.
+    ///
+    /// L2 requests call this on their own, once, when the server rejects the
+    /// current credentials with a 401/403.
+    pub async fn refresh_credentials(&self) -> Result<ApiCreds> {
+        let cell = self
+            .api_creds
+            .as_ref()
+            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
+        let fresh = self.create_or_derive_api_key(None).await?;
+        *cell.write().unwrap_or_else(|e| e.into_inner()) = fresh.clone();
+        Ok(fresh)
+    }
+
+    /// Snapshot of the current L2 credentials.
+    fn current_api_creds(&self) -> Option<ApiCreds> {
+        self.api_creds
+            .as_ref()
+            .map(|cell| cell.read().unwrap_or_else(|e| e.into_inner()).clone())
     }
 
     /// Override the funder/maker address used when creating signed orders.
@@ -306,15 +516,44 @@ impl ClobClient {
         Ok(())
     }
 
+    /// Override the exchange contracts used when signing orders, e.g. for a
+    /// testnet deployment.
+    pub fn set_contract_config(&mut self, contracts: crate::config::ContractConfig) -> Result<()> {
+        let order_builder = self
+            .order_builder
+            .as_mut()
+            .ok_or_else(|| PolyError::config("Order builder not initialized"))?;
+
+        order_builder.set_contract_config(contracts);
+        Ok(())
+    }
+
     fn with_env_funder(mut self) -> Self {
-        if let Ok(funder) = env::var("POLY_FUNDER") {
-            if let Err(err) = self.set_funder(&funder) {
-                warn!("Failed to set funder from POLY_FUNDER: {}", err);
-            }
+        if let Ok(funder) = env::var("POLY_FUNDER")
+            && let Err(err) = self.set_funder(&funder)
+        {
+            warn!("Failed to set funder from POLY_FUNDER: {}", err);
         }
         self
     }
 
+    /// Throttle outbound requests to `requests_per_sec`, allowing bursts of
+    /// up to `burst`. Requests made while the bucket is empty wait for a
+    /// token instead of failing. Shared by every clone of this client.
+    pub fn with_rate_limit(mut self, requests_per_sec: u32, burst: u32) -> Self {
+        self.rate_limiter = Some(TokenBucket::new(
+            burst.max(1) as usize,
+            requests_per_sec.max(1) as usize,
+        ));
+        self
+    }
+
+    /// Requests that can be sent right now without waiting, or `None` when
+    /// no rate limit is configured.
+    pub fn available_permits(&self) -> Option<usize> {
+        self.rate_limiter.as_ref().map(TokenBucket::available)
+    }
+
     /// Override the Gamma API base URL
     pub fn with_gamma_base(mut self, url: &str) -> Self {
         self.gamma_base_url = url.to_string();
@@ -336,7 +575,12 @@ impl ClobClient {
 
     /// Test basic connectivity
     pub async fn get_ok(&self) -> bool {
-        match self.http_client.get(self.clob_url("ok")).send().await {
+        match self
+            .http_client
+            .get(self.clob_url("ok"))
+            .send_limited(&self.rate_limiter)
+            .await
+        {
             Ok(response) => response.status().is_success(),
             Err(_) => false,
         }
@@ -344,7 +588,11 @@ impl ClobClient {
 
     /// Get server time
     pub async fn get_server_time(&self) -> Result<u64> {
-        let response = self.http_client.get(self.clob_url("time")).send().await?;
+        let response = self
+            .http_client
+            .get(self.clob_url("time"))
+            .send_limited(&self.rate_limiter)
+            .await?;
 
         if !response.status().is_success() {
             return Err(PolyError::api(
@@ -368,7 +616,7 @@ impl ClobClient {
             .http_client
             .get(self.clob_url("book"))
             .query(&[("token_id", token_id)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -382,13 +630,76 @@ impl ClobClient {
         Ok(order_book)
     }
 
+    /// Order book for a token as a WSS-style [`crate::wss::MarketBook`], so REST
+    /// snapshots and `book` events can be handled by the same code.
+    ///
+    /// An unknown token, which `/book` answers with a 404, comes back as an
+    /// empty book rather than an error.
+    pub async fn get_market_book(&self, token_id: &str) -> Result<crate::wss::MarketBook> {
+        let mut book = match self.get_order_book(token_id).await {
+            Ok(book) => book,
+            Err(err) if err.status_code() == Some(404) => OrderBookSummary::default(),
+            Err(err) => return Err(err),
+        };
+        if book.asset_id.is_empty() {
+            book.asset_id = token_id.to_string();
+        }
+        Ok(book.into())
+    }
+
+    /// Batch form of [`ClobClient::get_market_book`], via `/books`.
+    pub async fn get_market_books(
+        &self,
+        token_ids: &[String],
+    ) -> Result<Vec<crate::wss::MarketBook>> {
+        let books = self.get_order_books(token_ids).await?;
+        Ok(books.into_iter().map(Into::into).collect())
+    }
+
+    /// Get the top `depth` levels on each side of a token's order book.
+    ///
+    /// The depth is passed to the server as a `depth` query parameter so busy
+    /// books come back smaller. If the server ignores it, the book is truncated
+    /// client-side; either way bids are returned best (highest) first and asks
+    /// best (lowest) first.
+    pub async fn get_order_book_depth(
+        &self,
+        token_id: &str,
+        depth: u32,
+    ) -> Result<OrderBookSummary> {
+        let response = self
+            .http_client
+            .get(self.clob_url("book"))
+            .query(&[("token_id", token_id), ("depth", &depth.to_string())])
+            .send_limited(&self.rate_limiter)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to get order book",
+            ));
+        }
+
+        let mut order_book: OrderBookSummary = response.json().await?;
+        order_book
+            .bids
+            .sort_by_key(|level| std::cmp::Reverse(level.price));
+        order_book.asks.sort_by_key(|level| level.price);
+        order_book.bids.truncate(depth as usize);
+        order_book.asks.truncate(depth as usize);
+        Ok(order_book)
+    }
+
     /// Get midpoint for a token
-    pub async fn get_midpoint(&self, token_id: &str) -> Result<MidpointResponse> {
+    ///
+    /// Returns `Ok(None)` when the book is empty and there is no midpoint.
+    pub async fn get_midpoint(&self, token_id: &str) -> Result<Option<Decimal>> {
         let response = self
             .http_client
             .get(self.clob_url("midpoint"))
             .query(&[("token_id", token_id)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -398,17 +709,19 @@ impl ClobClient {
             ));
         }
 
-        let midpoint: MidpointResponse = response.json().await?;
-        Ok(midpoint)
+        let payload: Value = response.json().await?;
+        optional_decimal_field(&payload, "mid")
     }
 
     /// Get spread for a token
-    pub async fn get_spread(&self, token_id: &str) -> Result<SpreadResponse> {
+    ///
+    /// Returns `Ok(None)` when the book is empty and there is no spread.
+    pub async fn get_spread(&self, token_id: &str) -> Result<Option<Decimal>> {
         let response = self
             .http_client
             .get(self.clob_url("spread"))
             .query(&[("token_id", token_id)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -418,8 +731,8 @@ impl ClobClient {
             ));
         }
 
-        let spread: SpreadResponse = response.json().await?;
-        Ok(spread)
+        let payload: Value = response.json().await?;
+        optional_decimal_field(&payload, "spread")
     }
 
     /// Get spreads for multiple tokens (batch)
@@ -440,7 +753,7 @@ impl ClobClient {
             .http_client
             .post(self.clob_url("spreads"))
             .json(&request_data)
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -450,10 +763,18 @@ impl ClobClient {
             ));
         }
 
-        response
-            .json::<std::collections::HashMap<String, Decimal>>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        let payload: Value = response.json().await?;
+        decimal_map(&payload)
+    }
+
+    /// Like [`ClobClient::get_spreads`], but in `token_ids` order, with `None`
+    /// for tokens missing from the response.
+    pub async fn get_spreads_ordered(
+        &self,
+        token_ids: &[String],
+    ) -> Result<Vec<(String, Option<Decimal>)>> {
+        let spreads = self.get_spreads(token_ids).await?;
+        Ok(in_input_order(token_ids, &spreads))
     }
 
     /// Get price for a token and side
@@ -462,7 +783,7 @@ impl ClobClient {
             .http_client
             .get(self.clob_url("price"))
             .query(&[("token_id", token_id), ("side", side.as_str())])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -476,13 +797,85 @@ impl ClobClient {
         Ok(price)
     }
 
+    /// Price history for a token over a lookback `interval`.
+    ///
+    /// `fidelity` is the sample spacing in minutes; the server picks one
+    /// when it's `None`. A token with no trades returns an empty `Vec`.
+    pub async fn get_price_history(
+        &self,
+        token_id: &str,
+        interval: PriceInterval,
+        fidelity: Option<u32>,
+    ) -> Result<Vec<PricePoint>> {
+        let mut query = vec![
+            ("market", token_id.to_string()),
+            ("interval", interval.as_str().to_string()),
+        ];
+        if let Some(fidelity) = fidelity {
+            query.push(("fidelity", fidelity.to_string()));
+        }
+        self.fetch_price_history(&query).await
+    }
+
+    /// Like [`ClobClient::get_price_history`], over an explicit time range.
+    pub async fn get_price_history_range(
+        &self,
+        token_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        fidelity: Option<u32>,
+    ) -> Result<Vec<PricePoint>> {
+        if end < start {
+            return Err(PolyError::validation(
+                "Price history range ends before it starts",
+            ));
+        }
+        let mut query = vec![
+            ("market", token_id.to_string()),
+            ("startTs", start.timestamp().to_string()),
+            ("endTs", end.timestamp().to_string()),
+        ];
+        if let Some(fidelity) = fidelity {
+            query.push(("fidelity", fidelity.to_string()));
+        }
+        self.fetch_price_history(&query).await
+    }
+
+    async fn fetch_price_history(&self, query: &[(&str, String)]) -> Result<Vec<PricePoint>> {
+        #[derive(serde::Deserialize)]
+        struct PriceHistory {
+            #[serde(default)]
+            history: Option<Vec<PricePoint>>,
+        }
+
+        let response = self
+            .http_client
+            .get(self.clob_url("prices-history"))
+            .query(query)
+            .send_limited(&self.rate_limiter)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(PolyError::api(
+                response.status().as_u16(),
+                "Failed to get price history",
+            ));
+        }
+
+        let history: PriceHistory = response
+            .json()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse price history: {}", e), None))?;
+        Ok(history.history.unwrap_or_default())
+    }
+
     /// Get tick size for a token
     pub async fn get_tick_size(&self, token_id: &str) -> Result<Decimal> {
         let response = self
             .http_client
             .get(self.clob_url("tick-size"))
             .query(&[("token_id", token_id)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -517,7 +910,7 @@ impl ClobClient {
         let req =
             self.create_request_with_headers(Method::POST, "/auth/api-key", headers.into_iter());
 
-        let response = req.send().await?;
+        let response = req.send_limited(&self.rate_limiter).await?;
         if !response.status().is_success() {
             let status = response.status().as_u16();
             let body = response
@@ -547,7 +940,7 @@ impl ClobClient {
             headers.into_iter(),
         );
 
-        let response = req.send().await?;
+        let response = req.send_limited(&self.rate_limiter).await?;
         if !response.status().is_success() {
             let status = response.status().as_u16();
             let body = response
@@ -573,74 +966,96 @@ impl ClobClient {
 
     /// Get all API keys for the authenticated user
     pub async fn get_api_keys(&self) -> Result<Vec<String>> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::config("Signer not configured"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
-
         let method = Method::GET;
         let endpoint = "/auth/api-keys";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
-        let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+        let response = self.send_l2(method, endpoint, NO_BODY, |req| req).await?;
 
-        let api_keys_response: crate::types::ApiKeysResponse = response
-            .json()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+        let api_keys_response: crate::types::ApiKeysResponse = decode_json(response).await?;
 
         Ok(api_keys_response.api_keys)
     }
 
     /// Delete the current API key
     pub async fn delete_api_key(&self) -> Result<String> {
+        let method = Method::DELETE;
+        let endpoint = "/auth/api-key";
+
+        let response = self.send_l2(method, endpoint, NO_BODY, |req| req).await?;
+
+        response
+            .text()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+    }
+
+    /// Send an L2-authenticated request that `build` signs with the given
+    /// credentials.
+    ///
+    /// A 401/403 usually means the credentials went stale: they are then
+    /// refreshed (see [`ClobClient::refresh_credentials`]) and the request is
+    /// rebuilt and sent once more. If another holder of the shared
+    /// credentials already replaced them, those are used without refreshing
+    /// again. When the refresh fails, the original response is returned.
+    async fn send_l2_with<F>(&self, build: F) -> Result<reqwest::Response>
+    where
+        F: Fn(&PrivateKeySigner, &ApiCreds) -> Result<RequestBuilder>,
+    {
         let signer = self
             .signer
             .as_ref()
-            .ok_or_else(|| PolyError::config("Signer not configured"))?;
+            .ok_or_else(|| PolyError::auth("Signer not set"))?;
         let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
-
-        let method = Method::DELETE;
-        let endpoint = "/auth/api-key";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
+            .current_api_creds()
+            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
 
-        let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .send()
+        let response = build(signer, &api_creds)?
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+        if !matches!(response.status().as_u16(), 401 | 403) {
+            return Ok(response);
+        }
 
-        response
-            .text()
+        let fresh = match self.current_api_creds() {
+            Some(current) if current.api_key != api_creds.api_key => current,
+            _ => match self.refresh_credentials().await {
+                Ok(fresh) => fresh,
+                Err(err) => {
+                    warn!("API credential refresh failed: {}", err);
+                    return Ok(response);
+                }
+            },
+        };
+        build(signer, &fresh)?
+            .send_limited(&self.rate_limiter)
             .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))
+    }
+
+    /// [`ClobClient::send_l2_with`] for a request to `endpoint`, signed over
+    /// `method`, `endpoint`, and the JSON `body` it carries, if any.
+    /// `decorate` adds anything unsigned, such as query parameters.
+    async fn send_l2<B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<&B>,
+        decorate: impl Fn(RequestBuilder) -> RequestBuilder,
+    ) -> Result<reqwest::Response>
+    where
+        B: serde::Serialize + ?Sized,
+    {
+        self.send_l2_with(|signer, api_creds| {
+            let headers = create_l2_headers(signer, api_creds, method.as_str(), endpoint, body)?;
+            let mut req =
+                self.create_request_with_headers(method.clone(), endpoint, headers.into_iter());
+            if let Some(body) = body {
+                req = req.json(body);
+            }
+            Ok(decorate(req))
+        })
+        .await
     }
 
     /// Helper to create request with headers
@@ -660,7 +1075,7 @@ impl ClobClient {
             .http_client
             .get(self.clob_url("neg-risk"))
             .query(&[("token_id", token_id)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -725,9 +1140,28 @@ impl ClobClient {
         })
     }
 
-    /// Check if price is in valid range
-    fn is_price_in_range(&self, price: Decimal, tick_size: Decimal) -> bool {
-        let min_price = tick_size;
+    /// Absolute GTD expiration `offset` from now, by the server's clock when
+    /// it answers and the local clock otherwise.
+    async fn expiration_after(&self, offset: Duration) -> Result<u64> {
+        if offset <= GTD_MIN_EXPIRATION_BUFFER {
+            return Err(PolyError::validation(format!(
+                "Expiration offset {:?} must exceed the {:?} GTD buffer",
+                offset, GTD_MIN_EXPIRATION_BUFFER
+            )));
+        }
+        let now = match self.get_server_time().await {
+            Ok(now) => now,
+            Err(err) => {
+                warn!("server time unavailable, using local clock: {}", err);
+                Utc::now().timestamp().max(0) as u64
+            }
+        };
+        Ok(now + offset.as_secs())
+    }
+
+    /// Check if price is in valid range
+    fn is_price_in_range(&self, price: Decimal, tick_size: Decimal) -> bool {
+        let min_price = tick_size;
         let max_price = Decimal::ONE - tick_size;
         price >= min_price && price <= max_price
     }
@@ -749,7 +1183,11 @@ impl ClobClient {
             .get_filled_order_options(&order_args.token_id, options)
             .await?;
 
-        let expiration = expiration.unwrap_or(0);
+        let expiration = match (expiration, order_args.expires_in) {
+            (Some(expiration), _) => expiration,
+            (None, Some(offset)) => self.expiration_after(offset).await?,
+            (None, None) => 0,
+        };
         let extras = extras.unwrap_or_default();
 
         if !self.is_price_in_range(
@@ -768,6 +1206,40 @@ impl ClobClient {
         )
     }
 
+    /// [`ClobClient::create_order`] for an order on a known `market`. The
+    /// price is checked with [`Market::validate_order_price`] and signed at
+    /// the tick in effect at that price; the CLOB's current tick for the
+    /// token still has the final say.
+    pub async fn create_order_for_market(
+        &self,
+        market: &Market,
+        order_args: &OrderArgs,
+        expiration: Option<u64>,
+        extras: Option<crate::types::ExtraOrderArgs>,
+    ) -> Result<SignedOrderRequest> {
+        let options = market_order_options(market, order_args)?;
+        self.create_order(order_args, expiration, extras, Some(&options))
+            .await
+    }
+
+    /// Verify locally that `signed` was signed by this client's key for the
+    /// exchange matching `neg_risk`, without touching the network.
+    ///
+    /// Returns `Ok(false)` if the signature recovers to another address or
+    /// the order names a different signer or funder.
+    pub fn verify_order_signature(
+        &self,
+        signed: &SignedOrderRequest,
+        neg_risk: bool,
+    ) -> Result<bool> {
+        let order_builder = self
+            .order_builder
+            .as_ref()
+            .ok_or_else(|| PolyError::auth("Order builder not initialized"))?;
+
+        order_builder.verify_signed_order(self.chain_id, signed, neg_risk)
+    }
+
     /// Calculate market price from order book
     async fn calculate_market_price(
         &self,
@@ -781,8 +1253,8 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::auth("Order builder not initialized"))?;
 
-        // Convert OrderSummary to BookLevel
-        let levels: Vec<crate::types::BookLevel> = match side {
+        // Convert OrderSummary to BookLevel, best price first
+        let mut levels: Vec<crate::types::BookLevel> = match side {
             Side::BUY => book
                 .asks
                 .into_iter()
@@ -800,6 +1272,10 @@ impl ClobClient {
                 })
                 .collect(),
         };
+        match side {
+            Side::BUY => levels.sort_by_key(|level| level.price),
+            Side::SELL => levels.sort_by_key(|level| std::cmp::Reverse(level.price)),
+        }
 
         order_builder.calculate_market_price(&levels, amount)
     }
@@ -847,29 +1323,26 @@ impl ClobClient {
         order: SignedOrderRequest,
         order_type: OrderType,
     ) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
-        let body = PostOrder::new(order, api_creds.api_key.clone(), order_type);
-
-        let headers = create_l2_headers(signer, api_creds, "POST", "/order", Some(&body))?;
-        if env::var("POLY_LOG_REQUEST").is_ok() {
-            if let Ok(body_text) = serde_json::to_string(&body) {
-                println!("rust request url    : {}", self.clob_url("/order"));
-                println!("rust request method : POST");
-                println!("rust request headers: {:?}", headers);
-                println!("rust request body   : {}", body_text);
-            }
-        }
-        let req = self.create_request_with_headers(Method::POST, "/order", headers.into_iter());
+        // The body names the API key as owner, so it's rebuilt with the
+        // credentials of each attempt.
+        let response = self
+            .send_l2_with(|signer, api_creds| {
+                let body = PostOrder::new(order.clone(), api_creds.api_key.clone(), order_type);
 
-        let response = req.json(&body).send().await?;
+                let headers = create_l2_headers(signer, api_creds, "POST", "/order", Some(&body))?;
+                if env::var("POLY_LOG_REQUEST").is_ok()
+                    && let Ok(body_text) = serde_json::to_string(&body)
+                {
+                    println!("rust request url    : {}", self.clob_url("/order"));
+                    println!("rust request method : POST");
+                    println!("rust request headers: {:?}", headers);
+                    println!("rust request body   : {}", body_text);
+                }
+                Ok(self
+                    .create_request_with_headers(Method::POST, "/order", headers.into_iter())
+                    .json(&body))
+            })
+            .await?;
         if !response.status().is_success() {
             return Err(PolyError::api(
                 response.status().as_u16(),
@@ -892,34 +1365,32 @@ impl ClobClient {
         orders: Vec<SignedOrderRequest>,
         order_type: OrderType,
     ) -> Result<Vec<BatchOrderResponse>> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
-        let batch: Vec<PostOrder> = orders
-            .into_iter()
-            .map(|order| PostOrder::new(order, api_creds.api_key.clone(), order_type.clone()))
-            .collect();
-
-        let headers = create_l2_headers(signer, api_creds, "POST", "/orders", Some(&batch))?;
-
-        if env::var("POLY_LOG_REQUEST").is_ok() {
-            if let Ok(body_text) = serde_json::to_string(&batch) {
-                println!("rust request url    : {}", self.clob_url("/orders"));
-                println!("rust request method : POST");
-                println!("rust request headers: {:?}", headers);
-                println!("rust request body   : {}", body_text);
-            }
-        }
-
-        let req = self.create_request_with_headers(Method::POST, "/orders", headers.into_iter());
+        let response = self
+            .send_l2_with(|signer, api_creds| {
+                let batch: Vec<PostOrder> = orders
+                    .iter()
+                    .map(|order| {
+                        PostOrder::new(order.clone(), api_creds.api_key.clone(), order_type)
+                    })
+                    .collect();
+
+                let headers =
+                    create_l2_headers(signer, api_creds, "POST", "/orders", Some(&batch))?;
+
+                if env::var("POLY_LOG_REQUEST").is_ok()
+                    && let Ok(body_text) = serde_json::to_string(&batch)
+                {
+                    println!("rust request url    : {}", self.clob_url("/orders"));
+                    println!("rust request method : POST");
+                    println!("rust request headers: {:?}", headers);
+                    println!("rust request body   : {}", body_text);
+                }
 
-        let response = req.json(&batch).send().await?;
+                Ok(self
+                    .create_request_with_headers(Method::POST, "/orders", headers.into_iter())
+                    .json(&batch))
+            })
+            .await?;
         if !response.status().is_success() {
             return Err(PolyError::api(
                 response.status().as_u16(),
@@ -948,6 +1419,125 @@ impl ClobClient {
         Ok(batch_results)
     }
 
+    /// Build, sign, and post a limit order, returning the exchange's order
+    /// ID and status.
+    ///
+    /// The price is rounded to the token's tick size and the order is signed
+    /// with the signature type the client was configured with (EOA or
+    /// proxy). GTD orders need [`OrderArgs::expires_in`]. A rejection for
+    /// missing funds or allowance comes back as
+    /// `OrderErrorKind::InsufficientBalance`.
+    pub async fn place_order(
+        &self,
+        order_args: &OrderArgs,
+        order_type: OrderType,
+    ) -> Result<crate::types::PlacedOrder> {
+        self.place_order_with(order_args, order_type, None).await
+    }
+
+    /// [`ClobClient::place_order`] for an order on a known `market`, with the
+    /// price validated against its tick bands; see
+    /// [`ClobClient::create_order_for_market`].
+    pub async fn place_order_for_market(
+        &self,
+        market: &Market,
+        order_args: &OrderArgs,
+        order_type: OrderType,
+    ) -> Result<crate::types::PlacedOrder> {
+        let options = market_order_options(market, order_args)?;
+        self.place_order_with(order_args, order_type, Some(&options))
+            .await
+    }
+
+    async fn place_order_with(
+        &self,
+        order_args: &OrderArgs,
+        order_type: OrderType,
+        options: Option<&OrderOptions>,
+    ) -> Result<crate::types::PlacedOrder> {
+        if order_type == OrderType::GTD && order_args.expires_in.is_none() {
+            return Err(PolyError::validation(
+                "GTD orders need an expiration; set OrderArgs::expires_in",
+            ));
+        }
+
+        let order = self.create_order(order_args, None, None, options).await?;
+        self.post_placed_order(order, order_type).await
+    }
+
+    /// Post a signed order and decode the exchange's verdict.
+    async fn post_placed_order(
+        &self,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+    ) -> Result<crate::types::PlacedOrder> {
+        let response = self
+            .post_order(order, order_type)
+            .await
+            .map_err(|err| match &err {
+                PolyError::Api { message, .. } => order_rejection(message).unwrap_or(err),
+                _ => err,
+            })?;
+        let response: BatchOrderResponse = serde_json::from_value(response).map_err(|e| {
+            PolyError::parse(format!("Failed to parse order response: {}", e), None)
+        })?;
+
+        if response.has_error() {
+            let message = response
+                .error_msg
+                .unwrap_or_else(|| "order rejected".into());
+            return Err(order_rejection(&message).unwrap_or_else(|| {
+                PolyError::order(message, crate::errors::OrderErrorKind::ExecutionFailed)
+            }));
+        }
+
+        let order_id = response
+            .order_id
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| PolyError::parse("Order response has no order ID", None))?;
+        Ok(crate::types::PlacedOrder {
+            order_id,
+            status: response.status,
+        })
+    }
+
+    /// Submit a FOK market buy spending `usdc` on `token_id`.
+    ///
+    /// The order is built by [`ClobClient::create_market_order`], so the
+    /// signed maker amount is exactly `usdc` and the price is the deepest ask
+    /// the budget reaches. Fails with `OrderErrorKind::SizeConstraint` before
+    /// signing when the asks can't absorb the whole budget.
+    pub async fn place_market_buy_usdc(
+        &self,
+        token_id: &str,
+        usdc: Decimal,
+    ) -> Result<crate::types::PlacedOrder> {
+        if usdc <= Decimal::ZERO {
+            return Err(PolyError::validation("USDC amount must be positive"));
+        }
+
+        let book = self.get_market_book(token_id).await?;
+        let ask_depth: Decimal = book.asks.iter().map(|level| level.size).sum();
+        let fill = book.simulate_fill(Side::BUY, ask_depth);
+        let depth_usdc = fill.avg_price.unwrap_or_default() * fill.filled;
+        if depth_usdc < usdc {
+            return Err(PolyError::order(
+                format!(
+                    "Book for {} is too thin: {} of {} USDC available",
+                    token_id, depth_usdc, usdc
+                ),
+                crate::errors::OrderErrorKind::SizeConstraint,
+            ));
+        }
+
+        let order_args = crate::types::MarketOrderArgs {
+            token_id: token_id.to_string(),
+            amount: usdc,
+        };
+        let order = self.create_market_order(&order_args, None, None).await?;
+        self.post_placed_order(order, OrderType::FOK).await
+    }
+
     /// Create and post an order in one call
     pub async fn create_and_post_order(&self, order_args: &OrderArgs) -> Result<Value> {
         let order = self.create_order(order_args, None, None, None).await?;
@@ -956,21 +1546,11 @@ impl ClobClient {
 
     /// Cancel an order
     pub async fn cancel(&self, order_id: &str) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
         let body = std::collections::HashMap::from([("orderID", order_id)]);
 
-        let headers = create_l2_headers(signer, api_creds, "DELETE", "/order", Some(&body))?;
-        let req = self.create_request_with_headers(Method::DELETE, "/order", headers.into_iter());
-
-        let response = req.json(&body).send().await?;
+        let response = self
+            .send_l2(Method::DELETE, "/order", Some(&body), |req| req)
+            .await?;
         if !response.status().is_success() {
             return Err(PolyError::api(
                 response.status().as_u16(),
@@ -981,21 +1561,15 @@ impl ClobClient {
         Ok(response.json::<Value>().await?)
     }
 
-    /// Cancel multiple orders
-    pub async fn cancel_orders(&self, order_ids: &[String]) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
-        let headers = create_l2_headers(signer, api_creds, "DELETE", "/orders", Some(order_ids))?;
-        let req = self.create_request_with_headers(Method::DELETE, "/orders", headers.into_iter());
-
-        let response = req.json(order_ids).send().await?;
+    /// Cancel several orders in one request.
+    ///
+    /// Orders the exchange refuses to cancel don't fail the call; they show up
+    /// in [`CancelResponse::not_canceled`] with the reason. Requested IDs the
+    /// exchange doesn't mention at all are reported there too.
+    pub async fn cancel_orders(&self, order_ids: &[String]) -> Result<CancelResponse> {
+        let response = self
+            .send_l2(Method::DELETE, "/orders", Some(order_ids), |req| req)
+            .await?;
         if !response.status().is_success() {
             return Err(PolyError::api(
                 response.status().as_u16(),
@@ -1003,25 +1577,43 @@ impl ClobClient {
             ));
         }
 
-        Ok(response.json::<Value>().await?)
+        let mut result = parse_cancel_response(response.json::<Value>().await?)?;
+        for order_id in order_ids {
+            if !result.is_canceled(order_id) && !result.not_canceled.contains_key(order_id) {
+                result
+                    .not_canceled
+                    .insert(order_id.clone(), "not reported by exchange".to_string());
+            }
+        }
+        Ok(result)
     }
 
-    /// Cancel all orders
-    pub async fn cancel_all(&self) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
+    /// Cancel every open order created more than `age` ago.
+    ///
+    /// Returns an empty response without hitting the cancel endpoint when no
+    /// order is old enough.
+    pub async fn cancel_orders_older_than(&self, age: Duration) -> Result<CancelResponse> {
+        let cutoff = (Utc::now().timestamp().max(0) as u64).saturating_sub(age.as_secs());
+        let stale: Vec<String> = self
+            .get_orders(None, None)
+            .await?
+            .into_iter()
+            .filter(|order| order.created_at < cutoff)
+            .map(|order| order.id)
+            .collect();
 
-        let headers = create_l2_headers::<Value>(signer, api_creds, "DELETE", "/cancel-all", None)?;
-        let req =
-            self.create_request_with_headers(Method::DELETE, "/cancel-all", headers.into_iter());
+        if stale.is_empty() {
+            return Ok(CancelResponse::default());
+        }
 
-        let response = req.send().await?;
+        self.cancel_orders(&stale).await
+    }
+
+    /// Cancel every open order on the account.
+    pub async fn cancel_all(&self) -> Result<CancelResponse> {
+        let response = self
+            .send_l2(Method::DELETE, "/cancel-all", NO_BODY, |req| req)
+            .await?;
         if !response.status().is_success() {
             return Err(PolyError::api(
                 response.status().as_u16(),
@@ -1029,7 +1621,24 @@ impl ClobClient {
             ));
         }
 
-        Ok(response.json::<Value>().await?)
+        parse_cancel_response(response.json::<Value>().await?)
+    }
+
+    /// Cancel every open order on the market with `condition_id`.
+    pub async fn cancel_market(&self, condition_id: &str) -> Result<CancelResponse> {
+        let response = self.cancel_market_orders(Some(condition_id), None).await?;
+        parse_cancel_response(response)
+    }
+
+    /// Every order still resting on the book for the authenticated user,
+    /// following the cursor across pages. Use this to reconcile local state
+    /// after a restart.
+    pub async fn get_open_orders(
+        &self,
+        filter: Option<crate::types::OpenOrderFilter>,
+    ) -> Result<Vec<crate::types::OpenOrder>> {
+        let params = filter.map(crate::types::OpenOrderParams::from);
+        self.get_orders(params.as_ref(), None).await
     }
 
     /// Get open orders with optional filtering
@@ -1045,19 +1654,7 @@ impl ClobClient {
         params: Option<&crate::types::OpenOrderParams>,
         next_cursor: Option<&str>,
     ) -> Result<Vec<crate::types::OpenOrder>> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
-        let method = Method::GET;
         let endpoint = "/data/orders";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
         let query_params = match params {
             None => Vec::new(),
@@ -1069,24 +1666,13 @@ impl ClobClient {
 
         while next_cursor != "LTE=" {
             // END_CURSOR
-            let req = self
-                .http_client
-                .request(method.clone(), self.clob_url(endpoint))
-                .query(&query_params)
-                .query(&[("next_cursor", &next_cursor)]);
-
-            let r = headers
-                .clone()
-                .into_iter()
-                .fold(req, |r, (k, v)| r.header(HeaderName::from_static(k), v));
-
-            let resp = r
-                .send()
-                .await
-                .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?
-                .json::<Value>()
-                .await
-                .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+            let response = self
+                .send_l2(Method::GET, endpoint, NO_BODY, |req| {
+                    req.query(&query_params)
+                        .query(&[("next_cursor", &next_cursor)])
+                })
+                .await?;
+            let resp = decode_json::<Value>(response).await?;
 
             let new_cursor = resp["next_cursor"]
                 .as_str()
@@ -1124,19 +1710,7 @@ impl ClobClient {
         trade_params: Option<&crate::types::TradeParams>,
         next_cursor: Option<&str>,
     ) -> Result<Vec<Value>> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
-        let method = Method::GET;
         let endpoint = "/data/trades";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
         let query_params = match trade_params {
             None => Vec::new(),
@@ -1148,24 +1722,13 @@ impl ClobClient {
 
         while next_cursor != "LTE=" {
             // END_CURSOR
-            let req = self
-                .http_client
-                .request(method.clone(), self.clob_url(endpoint))
-                .query(&query_params)
-                .query(&[("next_cursor", &next_cursor)]);
-
-            let r = headers
-                .clone()
-                .into_iter()
-                .fold(req, |r, (k, v)| r.header(HeaderName::from_static(k), v));
-
-            let resp = r
-                .send()
-                .await
-                .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?
-                .json::<Value>()
-                .await
-                .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+            let response = self
+                .send_l2(Method::GET, endpoint, NO_BODY, |req| {
+                    req.query(&query_params)
+                        .query(&[("next_cursor", &next_cursor)])
+                })
+                .await?;
+            let resp = decode_json::<Value>(response).await?;
 
             let new_cursor = resp["next_cursor"]
                 .as_str()
@@ -1181,6 +1744,59 @@ impl ClobClient {
         Ok(output)
     }
 
+    /// Liquidity reward earnings per market for `date` (UTC), defaulting to
+    /// today. A day without earnings returns an empty list.
+    pub async fn get_rewards_earnings(
+        &self,
+        date: Option<NaiveDate>,
+    ) -> Result<Vec<RewardEarning>> {
+        let endpoint = "/rewards/user";
+
+        let date = date
+            .unwrap_or_else(|| Utc::now().date_naive())
+            .format("%Y-%m-%d")
+            .to_string();
+        let signature_type = self
+            .order_builder
+            .as_ref()
+            .map(|builder| builder.get_sig_type().to_string());
+
+        let mut next_cursor = "MA==".to_string(); // INITIAL_CURSOR
+        let mut output = Vec::new();
+
+        while next_cursor != "LTE=" {
+            // END_CURSOR
+            let response = self
+                .send_l2(Method::GET, endpoint, NO_BODY, |req| {
+                    let req = req.query(&[("date", &date), ("next_cursor", &next_cursor)]);
+                    match &signature_type {
+                        Some(signature_type) => req.query(&[("signature_type", signature_type)]),
+                        None => req,
+                    }
+                })
+                .await?;
+            let resp = decode_json::<Value>(response).await?;
+
+            let page = match &resp {
+                Value::Array(_) => resp.clone(),
+                _ => resp.get("data").cloned().unwrap_or(Value::Null),
+            };
+            if !page.is_null() {
+                let earnings: Vec<RewardEarning> = serde_json::from_value(page).map_err(|e| {
+                    PolyError::parse(format!("Invalid rewards response: {}", e), None)
+                })?;
+                output.extend(earnings);
+            }
+
+            next_cursor = match resp.get("next_cursor").and_then(Value::as_str) {
+                Some(cursor) if !cursor.is_empty() => cursor.to_owned(),
+                _ => break,
+            };
+        }
+
+        Ok(output)
+    }
+
     /// Get balance and allowance information for all assets
     ///
     /// This returns the current balance and allowance for each asset in your account.
@@ -1192,15 +1808,6 @@ impl ClobClient {
         &self,
         params: Option<crate::types::BalanceAllowanceParams>,
     ) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
         let mut params = params.unwrap_or_default();
         if params.signature_type.is_none() {
             params.set_signature_type(
@@ -1215,27 +1822,12 @@ impl ClobClient {
 
         let method = Method::GET;
         let endpoint = "/balance-allowance";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&query_params)
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2(method, endpoint, NO_BODY, |req| req.query(&query_params))
+            .await?;
 
-        response
-            .json::<Value>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Value>(response).await
     }
 
     /// Set up notifications for order fills and other events
@@ -1247,46 +1839,24 @@ impl ClobClient {
     ///
     /// The signature proves you own the account and want to receive notifications.
     pub async fn get_notifications(&self) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
         let method = Method::GET;
         let endpoint = "/notifications";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&[(
-                "signature_type",
-                &self
-                    .order_builder
-                    .as_ref()
-                    .expect("OrderBuilder not set")
-                    .get_sig_type()
-                    .to_string(),
-            )])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2(method, endpoint, NO_BODY, |req| {
+                req.query(&[(
+                    "signature_type",
+                    &self
+                        .order_builder
+                        .as_ref()
+                        .expect("OrderBuilder not set")
+                        .get_sig_type()
+                        .to_string(),
+                )])
+            })
+            .await?;
 
-        response
-            .json::<Value>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Value>(response).await
     }
 
     /// Get midpoints for multiple tokens in a single request
@@ -1313,7 +1883,7 @@ impl ClobClient {
             .http_client
             .post(self.clob_url("midpoints"))
             .json(&request_data)
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -1323,22 +1893,72 @@ impl ClobClient {
             ));
         }
 
-        let midpoints: std::collections::HashMap<String, Decimal> = response.json().await?;
-        Ok(midpoints)
+        let payload: Value = response.json().await?;
+        decimal_map(&payload)
     }
 
-    /// Get bid/ask/mid prices for multiple tokens in a single request
-    ///
-    /// This gives you the full price picture for multiple tokens at once.
-    /// Much more efficient than individual calls, especially when you're tracking
-    /// a portfolio or comparing multiple markets.
+    /// Like [`ClobClient::get_midpoints`], but in `token_ids` order, with
+    /// `None` for tokens that have no midpoint.
+    pub async fn get_midpoints_ordered(
+        &self,
+        token_ids: &[String],
+    ) -> Result<Vec<(String, Option<Decimal>)>> {
+        let midpoints = self.get_midpoints(token_ids).await?;
+        Ok(in_input_order(token_ids, &midpoints))
+    }
+
+    /// Get best prices for many `(token_id, side)` pairs in a single request
     ///
-    /// Returns bid (best buy price), ask (best sell price), and mid (average) for each token.
-    pub async fn get_prices(
+    /// POSTs the pairs to the CLOB `/prices` batch endpoint and returns a map
+    /// keyed by the same pair. Pairs the server leaves out of its response
+    /// (e.g. tokens with an empty side) are simply absent from the map. An
+    /// empty input returns an empty map without a network call.
+    pub async fn get_prices_by_pair(
         &self,
-        book_params: &[crate::types::BookParams],
-    ) -> Result<std::collections::HashMap<String, std::collections::HashMap<Side, Decimal>>> {
-        let request_data: Vec<std::collections::HashMap<&str, String>> = book_params
+        requests: &[(String, Side)],
+    ) -> Result<std::collections::HashMap<(String, Side), Decimal>> {
+        if requests.is_empty() {
+            return Ok(std::collections::HashMap::new());
+        }
+
+        let book_params: Vec<crate::types::BookParams> = requests
+            .iter()
+            .map(|(token_id, side)| crate::types::BookParams {
+                token_id: token_id.clone(),
+                side: *side,
+            })
+            .collect();
+        let mut by_token = self.get_prices(&book_params).await?;
+
+        let mut prices = std::collections::HashMap::with_capacity(requests.len());
+        for (token_id, side) in requests {
+            if let Some(price) = by_token
+                .get_mut(token_id)
+                .and_then(|sides| sides.remove(side))
+            {
+                prices.insert((token_id.clone(), *side), price);
+            }
+        }
+        Ok(prices)
+    }
+
+    /// Like [`ClobClient::get_prices_by_pair`], but in `requests` order, with
+    /// `None` for pairs the server left out.
+    pub async fn get_prices_ordered(
+        &self,
+        requests: &[(String, Side)],
+    ) -> Result<Vec<((String, Side), Option<Decimal>)>> {
+        let prices = self.get_prices_by_pair(requests).await?;
+        Ok(in_input_order(requests, &prices))
+    }
+
+    /// Get bid/ask prices for multiple tokens in a single request, keyed by
+    /// token then side as returned by the `/prices` endpoint
+    pub async fn get_prices(
+        &self,
+        book_params: &[crate::types::BookParams],
+    ) -> Result<std::collections::HashMap<String, std::collections::HashMap<Side, Decimal>>> {
+        let request_data: Vec<std::collections::HashMap<&str, String>> = book_params
             .iter()
             .map(|params| {
                 let mut map = std::collections::HashMap::new();
@@ -1352,7 +1972,7 @@ impl ClobClient {
             .http_client
             .post(self.clob_url("prices"))
             .json(&request_data)
-            .send()
+            .send_limited(&self.rate_limiter)
             .await?;
 
         if !response.status().is_success() {
@@ -1382,49 +2002,54 @@ impl ClobClient {
             .http_client
             .post(self.clob_url("books"))
             .json(&request_data)
-            .send()
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
-        response
-            .json::<Vec<OrderBookSummary>>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Vec<OrderBookSummary>>(response).await
     }
 
     /// Get single order by ID
     pub async fn get_order(&self, order_id: &str) -> Result<crate::types::OpenOrder> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::config("Signer not configured"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
-
         let method = Method::GET;
         let endpoint = &format!("/data/order/{}", order_id);
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
-        let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+        let response = self.send_l2(method, endpoint, NO_BODY, |req| req).await?;
 
-        response
-            .json::<crate::types::OpenOrder>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<crate::types::OpenOrder>(response).await
+    }
+
+    /// Poll `get_order` until the order reaches a terminal status (matched,
+    /// unmatched, cancelled, ...) and return it.
+    ///
+    /// Polling starts at 250ms and doubles up to 2s between requests. Gives
+    /// up with a `Timeout` error once `timeout` has elapsed.
+    pub async fn wait_for_order_terminal(
+        &self,
+        order_id: &str,
+        timeout: Duration,
+    ) -> Result<crate::types::OrderStatus> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut interval = ORDER_POLL_INITIAL_INTERVAL;
+        loop {
+            let order = self.get_order(order_id).await?;
+            let status = crate::types::OrderStatus::from_api(&order.status).ok_or_else(|| {
+                PolyError::parse(format!("Unknown order status: {}", order.status), None)
+            })?;
+            if status.is_terminal() {
+                return Ok(status);
+            }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(PolyError::timeout(
+                    timeout,
+                    format!("waiting for order {} to leave {:?}", order_id, status),
+                ));
+            }
+            tokio::time::sleep(interval.min(deadline - now)).await;
+            interval = (interval * 2).min(ORDER_POLL_MAX_INTERVAL);
+        }
     }
 
     /// Get last trade price for a token
@@ -1433,14 +2058,11 @@ impl ClobClient {
             .http_client
             .get(self.clob_url("last-trade-price"))
             .query(&[("token_id", token_id)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
-        response
-            .json::<Value>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Value>(response).await
     }
 
     /// Get last trade prices for multiple tokens
@@ -1458,14 +2080,11 @@ impl ClobClient {
             .http_client
             .post(self.clob_url("last-trades-prices"))
             .json(&request_data)
-            .send()
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
-        response
-            .json::<Value>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Value>(response).await
     }
 
     /// Cancel market orders with optional filters
@@ -1474,15 +2093,6 @@ impl ClobClient {
         market: Option<&str>,
         asset_id: Option<&str>,
     ) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::config("Signer not configured"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
-
         let method = Method::DELETE;
         let endpoint = "/cancel-market-orders";
         let body = std::collections::HashMap::from([
@@ -1490,62 +2100,25 @@ impl ClobClient {
             ("asset_id", asset_id.unwrap_or("")),
         ]);
 
-        let headers = create_l2_headers(signer, api_creds, method.as_str(), endpoint, Some(&body))?;
-
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2(method, endpoint, Some(&body), |req| req)
+            .await?;
 
-        response
-            .json::<Value>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Value>(response).await
     }
 
     /// Drop (delete) notifications by IDs
     pub async fn drop_notifications(&self, ids: &[String]) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::config("Signer not configured"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
-
         let method = Method::DELETE;
         let endpoint = "/notifications";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&[("ids", ids.join(","))])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2(method, endpoint, NO_BODY, |req| {
+                req.query(&[("ids", ids.join(","))])
+            })
+            .await?;
 
-        response
-            .json::<Value>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Value>(response).await
     }
 
     /// Update balance allowance
@@ -1553,15 +2126,6 @@ impl ClobClient {
         &self,
         params: Option<crate::types::BalanceAllowanceParams>,
     ) -> Result<Value> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::config("Signer not configured"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
-
         let mut params = params.unwrap_or_default();
         if params.signature_type.is_none() {
             params.set_signature_type(
@@ -1576,63 +2140,26 @@ impl ClobClient {
 
         let method = Method::GET;
         let endpoint = "/balance-allowance/update";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&query_params)
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2(method, endpoint, NO_BODY, |req| req.query(&query_params))
+            .await?;
 
-        response
-            .json::<Value>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Value>(response).await
     }
 
     /// Check if an order is scoring
     pub async fn is_order_scoring(&self, order_id: &str) -> Result<bool> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::config("Signer not configured"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
-
         let method = Method::GET;
         let endpoint = "/order-scoring";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
 
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&[("order_id", order_id)])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2(method, endpoint, NO_BODY, |req| {
+                req.query(&[("order_id", order_id)])
+            })
+            .await?;
 
-        let result: Value = response
-            .json()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+        let result: Value = decode_json(response).await?;
 
         Ok(result["scoring"].as_bool().unwrap_or(false))
     }
@@ -1642,43 +2169,14 @@ impl ClobClient {
         &self,
         order_ids: &[&str],
     ) -> Result<std::collections::HashMap<String, bool>> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::config("Signer not configured"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::config("API credentials not configured"))?;
-
         let method = Method::POST;
         let endpoint = "/orders-scoring";
-        let headers = create_l2_headers(
-            signer,
-            api_creds,
-            method.as_str(),
-            endpoint,
-            Some(order_ids),
-        )?;
 
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .json(order_ids)
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2(method, endpoint, Some(order_ids), |req| req)
+            .await?;
 
-        response
-            .json::<std::collections::HashMap<String, bool>>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<std::collections::HashMap<String, bool>>(response).await
     }
 
     /// Get sampling markets with pagination
@@ -1692,14 +2190,11 @@ impl ClobClient {
             .http_client
             .get(self.gamma_url("sampling-markets"))
             .query(&[("next_cursor", next_cursor)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
-        response
-            .json::<crate::types::MarketsResponse>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<crate::types::MarketsResponse>(response).await
     }
 
     /// Get sampling simplified markets with pagination
@@ -1713,14 +2208,11 @@ impl ClobClient {
             .http_client
             .get(self.gamma_url("sampling-simplified-markets"))
             .query(&[("next_cursor", next_cursor)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
-        response
-            .json::<crate::types::SimplifiedMarketsResponse>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<crate::types::SimplifiedMarketsResponse>(response).await
     }
 
     /// Get markets with pagination
@@ -1743,14 +2235,11 @@ impl ClobClient {
             .http_client
             .get(self.gamma_url("simplified-markets"))
             .query(&[("next_cursor", next_cursor)])
-            .send()
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
-        response
-            .json::<crate::types::SimplifiedMarketsResponse>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<crate::types::SimplifiedMarketsResponse>(response).await
     }
 
     /// Get single market by condition ID
@@ -1758,7 +2247,7 @@ impl ClobClient {
         let response = self
             .http_client
             .get(self.gamma_url(&format!("markets/{}", market_id)))
-            .send()
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
@@ -1785,19 +2274,31 @@ impl ClobClient {
         Ok(gamma_market.into())
     }
 
+    /// Get a Gamma market by its condition ID
+    pub async fn get_market_by_condition_id(
+        &self,
+        condition_id: &str,
+    ) -> Result<crate::types::Market> {
+        self.gamma_client
+            .get_market_by_condition_id(condition_id)
+            .await
+    }
+
+    /// Get every market instance in a recurring Gamma series
+    pub async fn get_series_markets(&self, series_id: &str) -> Result<Vec<crate::types::Market>> {
+        self.gamma_client.get_series_markets(series_id).await
+    }
+
     /// Get market trades events
     pub async fn get_market_trades_events(&self, condition_id: &str) -> Result<Value> {
         let response = self
             .http_client
             .get(self.clob_url(&format!("live-activity/events/{}", condition_id)))
-            .send()
+            .send_limited(&self.rate_limiter)
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
-        response
-            .json::<Value>()
-            .await
-            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+        decode_json::<Value>(response).await
     }
 
     /// Fetch Gamma events with optional filtering
@@ -1841,10 +2342,11 @@ impl ClobClient {
 
 // Re-export types from the canonical location in types.rs
 pub use crate::types::{
-    BatchOrderResponse, DataApiPositionsParams, DataApiSortBy, DataApiSortDirection, DataPosition,
-    DataPositionValue, ExtraOrderArgs, GammaEvent, GammaListParams, Market, MarketOrderArgs,
-    MarketsResponse, MidpointResponse, NegRiskResponse, OrderBookSummary, OrderSummary,
-    PriceResponse, Rewards, Sport, SpreadResponse, Tag, TickSizeResponse, Token,
+    BatchOrderResponse, CancelResponse, DataApiPositionsParams, DataApiSortBy,
+    DataApiSortDirection, DataPosition, DataPositionValue, ExtraOrderArgs, GammaEvent,
+    GammaListParams, Market, MarketOrderArgs, MarketsResponse, MidpointResponse, NegRiskResponse,
+    OrderBookSummary, OrderSummary, PriceInterval, PricePoint, PriceResponse, RewardEarning,
+    Rewards, Sport, SpreadResponse, Tag, TickSizeResponse, Token,
 };
 
 // Compatibility types that need to stay in client.rs
@@ -1958,108 +2460,778 @@ mod tests {
         .with_gamma_base(base_url)
     }
 
-    #[tokio::test]
-    async fn test_client_creation() {
-        let client = create_test_client("https://test.example.com");
-        assert_eq!(client.base_url, "https://test.example.com");
-        assert!(client.signer.is_none());
-        assert!(client.api_creds.is_none());
+    fn sign_test_order(client: &ClobClient) -> SignedOrderRequest {
+        let args = OrderArgs::new(
+            "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+            Decimal::from_str("0.45").unwrap(),
+            Decimal::from(10),
+            Side::BUY,
+        );
+        let options = OrderOptions {
+            tick_size: Some(Decimal::from_str("0.01").unwrap()),
+            neg_risk: Some(false),
+            fee_rate_bps: None,
+        };
+        client
+            .order_builder
+            .as_ref()
+            .unwrap()
+            .create_order(137, &args, 0, &Default::default(), &options)
+            .unwrap()
     }
 
     #[tokio::test]
-    async fn test_client_with_l1_headers() {
-        let client = create_test_client_with_auth("https://test.example.com");
-        assert_eq!(client.base_url, "https://test.example.com");
-        assert!(client.signer.is_some());
-        assert_eq!(client.chain_id, 137);
-    }
+    async fn test_expires_in_uses_server_time() {
+        let mut server = Server::new_async().await;
+        let time = server
+            .mock("GET", "/time")
+            .with_status(200)
+            .with_body("1700000000")
+            .create_async()
+            .await;
+        let _tick = server
+            .mock("GET", "/tick-size")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"minimum_tick_size": "0.01"}"#)
+            .create_async()
+            .await;
 
-    #[tokio::test]
-    async fn test_client_with_l2_headers() {
-        let api_creds = ApiCredentials {
-            api_key: "test_key".to_string(),
-            secret: "test_secret".to_string(),
-            passphrase: "test_passphrase".to_string(),
+        let client = create_test_client_with_auth(&server.url());
+        let args = OrderArgs::new(
+            "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+            Decimal::from_str("0.45").unwrap(),
+            Decimal::from(10),
+            Side::BUY,
+        )
+        .expires_in(Duration::from_secs(300));
+        let options = OrderOptions {
+            tick_size: Some(Decimal::from_str("0.01").unwrap()),
+            neg_risk: Some(false),
+            fee_rate_bps: None,
         };
 
-        let client = ClobClient::with_l2_headers(
-            "https://test.example.com",
-            "0x1234567890123456789012345678901234567890123456789012345678901234",
-            137,
-            api_creds.clone(),
-        );
+        let signed = client
+            .create_order(&args, None, None, Some(&options))
+            .await
+            .unwrap();
+        time.assert_async().await;
+        assert_eq!(signed.expiration, "1700000300");
 
-        assert_eq!(client.base_url, "https://test.example.com");
-        assert!(client.signer.is_some());
-        assert!(client.api_creds.is_some());
-        assert_eq!(client.chain_id, 137);
+        // An explicit expiration wins over the relative one.
+        let signed = client
+            .create_order(&args, Some(1800000000), None, Some(&options))
+            .await
+            .unwrap();
+        assert_eq!(signed.expiration, "1800000000");
     }
 
-    #[tokio::test]
-    async fn test_set_api_creds() {
-        let mut client = create_test_client("https://test.example.com");
-        assert!(client.api_creds.is_none());
-
-        let api_creds = ApiCredentials {
-            api_key: "test_key".to_string(),
-            secret: "test_secret".to_string(),
-            passphrase: "test_passphrase".to_string(),
-        };
+    async fn mock_order_setup(server: &mut mockito::ServerGuard) -> Vec<mockito::Mock> {
+        vec![
+            server
+                .mock("GET", "/tick-size")
+                .match_query(Matcher::Any)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(r#"{"minimum_tick_size": "0.01"}"#)
+                .create_async()
+                .await,
+            server
+                .mock("GET", "/neg-risk")
+                .match_query(Matcher::Any)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(r#"{"neg_risk": false}"#)
+                .create_async()
+                .await,
+        ]
+    }
 
-        client.set_api_creds(api_creds.clone());
-        assert!(client.api_creds.is_some());
-        assert_eq!(client.api_creds.unwrap().api_key, "test_key");
+    fn place_order_args() -> OrderArgs {
+        OrderArgs::new(
+            "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+            Decimal::from_str("0.45").unwrap(),
+            Decimal::from(10),
+            Side::BUY,
+        )
     }
 
     #[tokio::test]
-    async fn test_get_sampling_markets_success() {
+    async fn test_place_order_returns_id_and_status() {
         let mut server = Server::new_async().await;
-        let mock_response = r#"{
-            "limit": "10",
-            "count": "2", 
-            "next_cursor": null,
-            "data": [
-                {
-                    "condition_id": "0x123",
-                    "tokens": [
-                        {"token_id": "0x456", "outcome": "Yes"},
-                        {"token_id": "0x789", "outcome": "No"}
-                    ],
-                    "rewards": {
-                        "rates": null,
-                        "min_size": "1.0",
-                        "max_spread": "0.1",
-                        "event_start_date": null,
-                        "event_end_date": null,
-                        "in_game_multiplier": null,
-                        "reward_epoch": null
-                    },
-                    "min_incentive_size": null,
-                    "max_incentive_spread": null,
-                    "active": true,
-                    "closed": false,
-                    "question_id": "0x123",
-                    "minimum_order_size": "1.0",
-                    "minimum_tick_size": "0.01",
-                    "description": "Test market",
-                    "category": "test",
-                    "end_date_iso": null,
-                    "game_start_time": null,
-                    "question": "Will this test pass?",
-                    "market_slug": "test-market",
-                    "seconds_delay": "0",
-                    "icon": "",
-                    "fpmm": ""
-                }
-            ]
-        }"#;
-
-        let mock = server
-            .mock("GET", "/sampling-markets")
-            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+        let _setup = mock_order_setup(&mut server).await;
+        let post = server
+            .mock("POST", "/order")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "orderType": "GTC",
+                "order": {"side": "BUY", "signatureType": 0}
+            })))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_response)
+            .with_body(
+                r#"{"success": true, "errorMsg": "", "orderID": "0xorder", "status": "live"}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let placed = client
+            .place_order(&place_order_args(), OrderType::GTC)
+            .await
+            .unwrap();
+
+        post.assert_async().await;
+        assert_eq!(placed.order_id, "0xorder");
+        assert_eq!(placed.status.as_deref(), Some("live"));
+    }
+
+    fn market_for(token_id: &str) -> Market {
+        let gamma: crate::types::GammaMarket = serde_json::from_value(serde_json::json!({
+            "conditionId": "0xabc",
+            "slug": "m",
+            "active": true,
+            "closed": false,
+            "orderPriceMinTickSize": "0.01",
+            "clobTokenIds": serde_json::to_string(&[token_id, "222"]).unwrap()
+        }))
+        .unwrap();
+        gamma.into()
+    }
+
+    #[tokio::test]
+    async fn test_place_order_for_market_validates_against_tick_bands() {
+        let mut server = Server::new_async().await;
+        let _setup = mock_order_setup(&mut server).await;
+        let client = create_test_client_with_l2(&server.url());
+        let args = place_order_args();
+        let market = market_for(&args.token_id);
+
+        // Off-tick prices and foreign tokens never reach the exchange.
+        let unposted = server.mock("POST", "/order").expect(0).create_async().await;
+        let off_tick = OrderArgs::new(
+            &args.token_id,
+            Decimal::from_str("0.455").unwrap(),
+            args.size,
+            Side::BUY,
+        );
+        let err = client
+            .place_order_for_market(&market, &off_tick, OrderType::GTC)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("not aligned to tick_size 0.01"),
+            "{}",
+            err
+        );
+        let foreign = OrderArgs::new("999", args.price, args.size, Side::BUY);
+        let err = client
+            .place_order_for_market(&market, &foreign, OrderType::GTC)
+            .await
+            .unwrap_err();
+        assert_eq!(err.validation_field(), Some("token_id"));
+        unposted.assert_async().await;
+
+        // A tail price is signed at the finer tick, which the CLOB must allow.
+        let tail = OrderArgs::new(
+            &args.token_id,
+            Decimal::from_str("0.975").unwrap(),
+            args.size,
+            Side::BUY,
+        );
+        let err = client
+            .create_order_for_market(&market, &tail, None, None)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("smaller than min_tick_size"),
+            "{}",
+            err
+        );
+
+        let placed = server
+            .mock("POST", "/order")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"success": true, "errorMsg": "", "orderID": "0xorder", "status": "live"}"#,
+            )
+            .create_async()
+            .await;
+        let order = client
+            .place_order_for_market(&market, &args, OrderType::GTC)
+            .await
+            .unwrap();
+        placed.assert_async().await;
+        assert_eq!(order.order_id, "0xorder");
+    }
+
+    #[tokio::test]
+    async fn test_place_market_buy_usdc_commits_the_budget() {
+        let token_id = place_order_args().token_id;
+        let mut server = Server::new_async().await;
+        let _setup = mock_order_setup(&mut server).await;
+        let book = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), token_id.clone()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "market": "0xabc",
+                    "asset_id": token_id,
+                    "hash": "h",
+                    "timestamp": "1",
+                    "bids": [{"price": "0.40", "size": "100"}],
+                    "asks": [
+                        {"price": "0.60", "size": "100"},
+                        {"price": "0.50", "size": "100"}
+                    ]
+                })
+                .to_string(),
+            )
+            .expect(3)
+            .create_async()
+            .await;
+        // 100 shares at 0.50 cost 50, so an 80 USDC budget reaches 0.60:
+        // the maker amount is the budget, the taker amount 80 / 0.60.
+        let post = server
+            .mock("POST", "/order")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "orderType": "FOK",
+                "order": {"side": "BUY", "makerAmount": "80000000", "takerAmount": "133333300"}
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"success": true, "errorMsg": "", "orderID": "0xfok", "status": "matched"}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let thin = client
+            .place_market_buy_usdc(&token_id, Decimal::from(111))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            thin,
+            PolyError::Order {
+                kind: crate::errors::OrderErrorKind::SizeConstraint,
+                ..
+            }
+        ));
+
+        let placed = client
+            .place_market_buy_usdc(&token_id, Decimal::from(80))
+            .await
+            .unwrap();
+        assert_eq!(placed.order_id, "0xfok");
+        book.assert_async().await;
+        post.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_place_order_unfunded_account_is_typed() {
+        let mut server = Server::new_async().await;
+        let _setup = mock_order_setup(&mut server).await;
+        let _post = server
+            .mock("POST", "/order")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error": "not enough balance / allowance"}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let err = client
+            .place_order(&place_order_args(), OrderType::GTC)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            PolyError::Order {
+                kind: crate::errors::OrderErrorKind::InsufficientBalance,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_place_gtd_order_requires_expiration() {
+        let client = create_test_client_with_l2("http://127.0.0.1:9");
+        let err = client
+            .place_order(&place_order_args(), OrderType::GTD)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Validation { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_expires_in_rejects_offsets_inside_buffer() {
+        let client = create_test_client_with_auth("http://127.0.0.1:9");
+        for offset in [
+            Duration::ZERO,
+            Duration::from_secs(30),
+            Duration::from_secs(60),
+        ] {
+            let err = client.expiration_after(offset).await.unwrap_err();
+            assert!(matches!(err, PolyError::Validation { .. }));
+        }
+    }
+
+    #[test]
+    fn test_order_args_with_usdc_notional() {
+        let args = OrderArgs::new("123", Decimal::ZERO, Decimal::ZERO, Side::BUY)
+            .with_usdc_notional(Decimal::from(100), Decimal::from_str("0.30").unwrap());
+        assert_eq!(args.price, Decimal::from_str("0.30").unwrap());
+        assert_eq!(args.size, Decimal::from_str("333.33").unwrap());
+        assert!(args.size * args.price <= Decimal::from(100));
+
+        let zero = OrderArgs::default().with_usdc_notional(Decimal::from(100), Decimal::ZERO);
+        assert_eq!(zero.size, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_verify_order_signature_accepts_own_signature() {
+        let client = create_test_client_with_auth("http://localhost");
+        let signed = sign_test_order(&client);
+
+        assert!(client.verify_order_signature(&signed, false).unwrap());
+        // Signed for the standard exchange, so the neg-risk domain differs.
+        assert!(!client.verify_order_signature(&signed, true).unwrap());
+    }
+
+    #[test]
+    fn test_verify_order_signature_rejects_tampered_order() {
+        let client = create_test_client_with_auth("http://localhost");
+        let mut signed = sign_test_order(&client);
+        signed.maker_amount = "1".to_string();
+
+        assert!(!client.verify_order_signature(&signed, false).unwrap());
+    }
+
+    fn create_test_client_with_l2(base_url: &str) -> ClobClient {
+        ClobClient::with_l2_headers(
+            base_url,
+            "0x1234567890123456789012345678901234567890123456789012345678901234",
+            137,
+            ApiCreds {
+                api_key: "test-key".to_string(),
+                secret: "c2VjcmV0".to_string(),
+                passphrase: "test-passphrase".to_string(),
+            },
+        )
+        .with_gamma_base(base_url)
+    }
+
+    fn open_order_json(id: &str, created_at: i64) -> Value {
+        serde_json::json!({
+            "associate_trades": [],
+            "id": id,
+            "status": "LIVE",
+            "market": "0xmarket",
+            "original_size": "10",
+            "outcome": "Yes",
+            "maker_address": "0xmaker",
+            "owner": "owner",
+            "price": "0.5",
+            "side": "BUY",
+            "size_matched": "0",
+            "asset_id": "123",
+            "expiration": "0",
+            "type": "GTC",
+            "created_at": created_at
+        })
+    }
+
+    #[tokio::test]
+    async fn test_private_key_is_validated_up_front() {
+        let key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let expected = Address::from_str("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266").unwrap();
+        for key in [key.to_string(), format!("0x{}", key)] {
+            let client = ClobClient::try_with_l1_headers("http://localhost", &key, 137).unwrap();
+            assert_eq!(client.address(), Some(expected));
+        }
+        assert_eq!(ClobClient::new("http://localhost").address(), None);
+
+        for (bad, reason) in [
+            ("0x1234", "expected 64 hex characters"),
+            (&"zz".repeat(32)[..], "non-hex"),
+        ] {
+            match ClobClient::try_with_l1_headers("http://localhost", bad, 137) {
+                Err(PolyError::Validation { message, .. }) => {
+                    assert!(message.starts_with("invalid private key"), "{}", message);
+                    assert!(message.contains(reason), "{}", message);
+                }
+                Err(other) => panic!("unexpected error: {:?}", other),
+                Ok(_) => panic!("accepted {}", bad),
+            }
+        }
+
+        // Rejected before any request is made.
+        let result = ClobClient::new_with_auth("0x1234", None).await;
+        assert!(matches!(result, Err(PolyError::Validation { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_spaces_requests_and_is_shared_by_clones() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/time")
+            .with_status(200)
+            .with_body("1700000000")
+            .expect(6)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url()).with_rate_limit(20, 2);
+        let clone = client.clone();
+        assert_eq!(create_test_client(&server.url()).available_permits(), None);
+        assert_eq!(client.available_permits(), Some(2));
+
+        let started = std::time::Instant::now();
+        for _ in 0..3 {
+            client.get_server_time().await.unwrap();
+            clone.get_server_time().await.unwrap();
+        }
+        let elapsed = started.elapsed();
+
+        // Two requests ride the burst; the other four wait 50ms each.
+        assert!(elapsed >= Duration::from_millis(190), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(2), "{:?}", elapsed);
+        assert_eq!(clone.available_permits(), Some(0));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_rewards_earnings_for_day() {
+        let mut server = Server::new_async().await;
+        let earnings = server
+            .mock("GET", "/rewards/user")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("date".into(), "2024-05-01".into()),
+                Matcher::UrlEncoded("next_cursor".into(), "MA==".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"next_cursor": "LTE=", "data": [
+                    {"date": "2024-05-01", "condition_id": "0xmarket", "asset_address": "0xusdc",
+                     "maker_address": "0xmaker", "earnings": 1.25, "asset_rate": "1"}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+        let quiet = server
+            .mock("GET", "/rewards/user")
+            .match_query(Matcher::UrlEncoded("date".into(), "2024-05-02".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"next_cursor": "LTE=", "data": []}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let day = |d| NaiveDate::from_ymd_opt(2024, 5, d);
+        let result = client.get_rewards_earnings(day(1)).await.unwrap();
+
+        earnings.assert_async().await;
+        assert_eq!(
+            result,
+            vec![RewardEarning {
+                market: "0xmarket".to_string(),
+                asset_id: "0xusdc".to_string(),
+                earnings: Decimal::from_str("1.25").unwrap(),
+                asset_rate: Decimal::ONE,
+            }]
+        );
+
+        assert!(
+            client
+                .get_rewards_earnings(day(2))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        quiet.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_order_terminal_polls_until_matched() {
+        let mut server = Server::new_async().await;
+        let with_status = |status: &str| {
+            let mut order = open_order_json("0xorder", 1);
+            order["status"] = Value::from(status);
+            order.to_string()
+        };
+        let delayed = server
+            .mock("GET", "/data/order/0xorder")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(with_status("delayed"))
+            .expect(2)
+            .create_async()
+            .await;
+        let matched = server
+            .mock("GET", "/data/order/0xorder")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(with_status("matched"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let status = client
+            .wait_for_order_terminal("0xorder", Duration::from_secs(10))
+            .await
+            .unwrap();
+
+        assert_eq!(status, crate::types::OrderStatus::Matched);
+        delayed.assert_async().await;
+        matched.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_order_terminal_times_out() {
+        let mut server = Server::new_async().await;
+        let _live = server
+            .mock("GET", "/data/order/0xorder")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(open_order_json("0xorder", 1).to_string())
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let err = client
+            .wait_for_order_terminal("0xorder", Duration::from_millis(300))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Timeout { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_credentials_is_visible_to_all_holders() {
+        let mut server = Server::new_async().await;
+        let derive = server
+            .mock("GET", "/auth/derive-api-key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"apiKey": "fresh-key", "secret": "c2VjcmV0", "passphrase": "fresh-pass"}"#,
+            )
+            .create_async()
+            .await;
+        let keys = server
+            .mock("GET", "/auth/api-keys")
+            .match_header("poly_api_key", "fresh-key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"apiKeys": ["fresh-key"]}"#)
+            .create_async()
+            .await;
+
+        let trading = create_test_client_with_l2(&server.url());
+        let shared = trading.shared_credentials().unwrap();
+        let refresher = create_test_client_with_auth(&server.url()).with_shared_credentials(shared);
+
+        let fresh = refresher.refresh_credentials().await.unwrap();
+        assert_eq!(fresh.api_key, "fresh-key");
+        derive.assert_async().await;
+
+        // The other holder signs with the refreshed key.
+        assert_eq!(
+            trading.current_api_creds().unwrap().passphrase,
+            "fresh-pass"
+        );
+        trading.get_api_keys().await.unwrap();
+        keys.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_stale_credentials_are_refreshed_and_retried_once() {
+        let mut server = Server::new_async().await;
+        let create = server
+            .mock("POST", "/auth/api-key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"apiKey": "fresh-key", "secret": "c2VjcmV0", "passphrase": "fresh-pass"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let stale = server
+            .mock("GET", "/auth/api-keys")
+            .match_header("poly_api_key", "test-key")
+            .with_status(401)
+            .expect(1)
+            .create_async()
+            .await;
+        let fresh = server
+            .mock("GET", "/auth/api-keys")
+            .match_header("poly_api_key", "fresh-key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"apiKeys": ["fresh-key"]}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        assert_eq!(client.get_api_keys().await.unwrap(), ["fresh-key"]);
+        assert_eq!(client.current_api_creds().unwrap().api_key, "fresh-key");
+
+        create.assert_async().await;
+        stale.assert_async().await;
+        fresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_credential_refresh_does_not_loop() {
+        let mut server = Server::new_async().await;
+        let create = server
+            .mock("POST", "/auth/api-key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"apiKey": "fresh-key", "secret": "c2VjcmV0", "passphrase": "fresh-pass"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let rejected = server
+            .mock("DELETE", "/cancel-all")
+            .with_status(403)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let err = client.cancel_all().await.unwrap_err();
+        assert_eq!(err.status_code(), Some(403));
+
+        create.assert_async().await;
+        rejected.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_client_creation() {
+        let client = create_test_client("https://test.example.com");
+        assert_eq!(client.base_url, "https://test.example.com");
+        assert!(client.signer.is_none());
+        assert!(client.api_creds.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_client_with_l1_headers() {
+        let client = create_test_client_with_auth("https://test.example.com");
+        assert_eq!(client.base_url, "https://test.example.com");
+        assert!(client.signer.is_some());
+        assert_eq!(client.chain_id, 137);
+    }
+
+    #[test]
+    fn test_for_chain_targets_the_chain_clob() {
+        let public = ClobClient::for_chain(80002);
+        assert_eq!(public.base_url, "https://clob-staging.polymarket.com");
+        assert_eq!(public.chain_id, 80002);
+        assert_eq!(public.gamma_base_url, DEFAULT_GAMMA_BASE);
+        assert_eq!(public.ws_base_url, DEFAULT_WS_BASE);
+
+        let mainnet = ClobClient::for_chain(137);
+        assert_eq!(mainnet.base_url, DEFAULT_CLOB_BASE);
+    }
+
+    #[tokio::test]
+    async fn test_client_with_l2_headers() {
+        let api_creds = ApiCredentials {
+            api_key: "test_key".to_string(),
+            secret: "test_secret".to_string(),
+            passphrase: "test_passphrase".to_string(),
+        };
+
+        let client = ClobClient::with_l2_headers(
+            "https://test.example.com",
+            "0x1234567890123456789012345678901234567890123456789012345678901234",
+            137,
+            api_creds.clone(),
+        );
+
+        assert_eq!(client.base_url, "https://test.example.com");
+        assert!(client.signer.is_some());
+        assert!(client.api_creds.is_some());
+        assert_eq!(client.chain_id, 137);
+    }
+
+    #[tokio::test]
+    async fn test_set_api_creds() {
+        let mut client = create_test_client("https://test.example.com");
+        assert!(client.api_creds.is_none());
+
+        let api_creds = ApiCredentials {
+            api_key: "test_key".to_string(),
+            secret: "test_secret".to_string(),
+            passphrase: "test_passphrase".to_string(),
+        };
+
+        client.set_api_creds(api_creds.clone());
+        assert!(client.api_creds.is_some());
+        assert_eq!(client.current_api_creds().unwrap().api_key, "test_key");
+    }
+
+    #[tokio::test]
+    async fn test_get_sampling_markets_success() {
+        let mut server = Server::new_async().await;
+        let mock_response = r#"{
+            "limit": "10",
+            "count": "2", 
+            "next_cursor": null,
+            "data": [
+                {
+                    "condition_id": "0x123",
+                    "tokens": [
+                        {"token_id": "0x456", "outcome": "Yes"},
+                        {"token_id": "0x789", "outcome": "No"}
+                    ],
+                    "rewards": {
+                        "rates": null,
+                        "min_size": "1.0",
+                        "max_spread": "0.1",
+                        "event_start_date": null,
+                        "event_end_date": null,
+                        "in_game_multiplier": null,
+                        "reward_epoch": null
+                    },
+                    "min_incentive_size": null,
+                    "max_incentive_spread": null,
+                    "active": true,
+                    "closed": false,
+                    "question_id": "0x123",
+                    "minimum_order_size": "1.0",
+                    "minimum_tick_size": "0.01",
+                    "description": "Test market",
+                    "category": "test",
+                    "end_date_iso": null,
+                    "game_start_time": null,
+                    "question": "Will this test pass?",
+                    "market_slug": "test-market",
+                    "seconds_delay": "0",
+                    "icon": "",
+                    "fpmm": ""
+                }
+            ]
+        }"#;
+
+        let mock = server
+            .mock("GET", "/sampling-markets")
+            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response)
             .create_async()
             .await;
 
@@ -2130,14 +3302,97 @@ mod tests {
             .await;
 
         let client = create_test_client(&server.url());
-        let result = client.get_order_book("0x123").await;
+        let result = client.get_order_book("0x123").await;
+
+        mock.assert_async().await;
+        assert!(result.is_ok());
+        let book = result.unwrap();
+        assert_eq!(book.market, "0x123");
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.asks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_market_book_parses_levels_as_decimals() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "market": "0xmarket",
+                    "asset_id": "0x123",
+                    "hash": "0xabc",
+                    "timestamp": "1700000000000",
+                    "bids": [{"price": "0.48", "size": "1250.5"}],
+                    "asks": [{"price": "0.52", "size": "30"}]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let book = client.get_market_book("0x123").await.unwrap();
+
+        assert_eq!(book.event_type, "book");
+        assert_eq!(book.timestamp, "1700000000000");
+        assert_eq!(book.bids[0].price, Decimal::from_str("0.48").unwrap());
+        assert_eq!(book.bids[0].size, Decimal::from_str("1250.5").unwrap());
+        assert_eq!(book.asks[0].price, Decimal::from_str("0.52").unwrap());
+        assert_eq!(book.asks[0].size, Decimal::from(30));
+    }
+
+    #[tokio::test]
+    async fn test_get_market_book_unknown_token_is_empty() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "unknown".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error": "No orderbook exists for the requested token id"}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let book = client.get_market_book("unknown").await.unwrap();
+
+        assert_eq!(book.asset_id, "unknown");
+        assert!(book.bids.is_empty() && book.asks.is_empty());
+        assert_eq!(book.timestamp, "0");
+        assert!(client.get_order_book("unknown").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_market_books_handles_unknown_token() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/books")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"market": "0xm", "asset_id": "known", "hash": "h", "timestamp": "1",
+                     "bids": [{"price": "0.10", "size": "5"}], "asks": []},
+                    {"asset_id": "unknown", "bids": [], "asks": []}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let books = client
+            .get_market_books(&["known".to_string(), "unknown".to_string()])
+            .await
+            .unwrap();
 
-        mock.assert_async().await;
-        assert!(result.is_ok());
-        let book = result.unwrap();
-        assert_eq!(book.market, "0x123");
-        assert_eq!(book.bids.len(), 1);
-        assert_eq!(book.asks.len(), 1);
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].bids[0].price, Decimal::from_str("0.10").unwrap());
+        assert_eq!(books[1].asset_id, "unknown");
+        assert!(books[1].bids.is_empty() && books[1].asks.is_empty());
+        assert_eq!(books[1].timestamp, "0");
     }
 
     #[tokio::test]
@@ -2161,8 +3416,49 @@ mod tests {
 
         mock.assert_async().await;
         assert!(result.is_ok());
-        let response = result.unwrap();
-        assert_eq!(response.mid, Decimal::from_str("0.755").unwrap());
+        let mid = result.unwrap();
+        assert_eq!(mid, Some(Decimal::from_str("0.755").unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoint_and_spread_empty_book() {
+        let mut server = Server::new_async().await;
+        let midpoint = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "0xempty".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"mid": ""}"#)
+            .create_async()
+            .await;
+        let spread = server
+            .mock("GET", "/spread")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "0xempty".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        assert_eq!(client.get_midpoint("0xempty").await.unwrap(), None);
+        assert_eq!(client.get_spread("0xempty").await.unwrap(), None);
+        midpoint.assert_async().await;
+        spread.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoint_failure_is_error() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::Any)
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        assert!(client.get_midpoint("0x123").await.is_err());
     }
 
     #[tokio::test]
@@ -2186,8 +3482,8 @@ mod tests {
 
         mock.assert_async().await;
         assert!(result.is_ok());
-        let response = result.unwrap();
-        assert_eq!(response.spread, Decimal::from_str("0.01").unwrap());
+        let spread = result.unwrap();
+        assert_eq!(spread, Some(Decimal::from_str("0.01").unwrap()));
     }
 
     #[tokio::test]
@@ -2297,6 +3593,42 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_cloudflare_522_is_retryable_infrastructure_error() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/last-trade-price")
+            .match_query(Matcher::Any)
+            .with_status(522)
+            .with_header("content-type", "text/html")
+            .with_body("<!DOCTYPE html><html><body>Connection timed out</body></html>")
+            .create_async()
+            .await;
+        let book_mock = server
+            .mock("GET", "/book")
+            .match_query(Matcher::Any)
+            .with_status(522)
+            .with_header("content-type", "text/html")
+            .with_body("<!DOCTYPE html><html><body>Connection timed out</body></html>")
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+
+        // Unchecked JSON path: previously surfaced as a parse error on the HTML body.
+        let err = client.get_last_trade_price("0x123").await.unwrap_err();
+        assert!(matches!(err, PolyError::Infrastructure { status: 522, .. }));
+        assert!(err.is_retryable());
+
+        // Status-checked path goes through PolyError::api.
+        let err = client.get_order_book("0x123").await.unwrap_err();
+        assert!(matches!(err, PolyError::Infrastructure { status: 522, .. }));
+        assert!(err.is_retryable());
+
+        mock.assert_async().await;
+        book_mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_network_error_handling() {
         // Test with invalid URL to simulate network error
@@ -2352,6 +3684,131 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_price_history_parses_points() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/prices-history")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("market".into(), "0x123".into()),
+                Matcher::UrlEncoded("interval".into(), "1d".into()),
+                Matcher::UrlEncoded("fidelity".into(), "60".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"history": [{"t": 1700000000, "p": 0.51}, {"t": 1700003600, "p": 0.535}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let history = client
+            .get_price_history("0x123", PriceInterval::OneDay, Some(60))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].timestamp.timestamp(), 1_700_000_000);
+        assert_eq!(history[0].price, Decimal::from_str("0.51").unwrap());
+        assert_eq!(history[1].price, Decimal::from_str("0.535").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_price_history_range_empty_is_ok() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/prices-history")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("startTs".into(), "1700000000".into()),
+                Matcher::UrlEncoded("endTs".into(), "1700086400".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"history": []}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let _missing = server
+            .mock("GET", "/prices-history")
+            .match_query(Matcher::UrlEncoded("interval".into(), "max".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let end = DateTime::from_timestamp(1_700_086_400, 0).unwrap();
+        let history = client
+            .get_price_history_range("0x123", start, end, None)
+            .await
+            .unwrap();
+        assert!(history.is_empty());
+        mock.assert_async().await;
+
+        let history = client
+            .get_price_history("0x123", PriceInterval::Max, None)
+            .await
+            .unwrap();
+        assert!(history.is_empty());
+
+        let err = client
+            .get_price_history_range("0x123", end, start, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Validation { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_get_spreads_batch_skips_empty_books() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/spreads")
+            .match_body(Matcher::Json(serde_json::json!([
+                {"token_id": "0x123"},
+                {"token_id": "0x456"},
+                {"token_id": "0x789"}
+            ])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"0x123": "0.02", "0x456": "", "0x789": null}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let token_ids = vec![
+            "0x123".to_string(),
+            "0x456".to_string(),
+            "0x789".to_string(),
+        ];
+        let spreads = client.get_spreads(&token_ids).await.unwrap();
+
+        assert_eq!(spreads.len(), 1);
+        assert_eq!(spreads["0x123"], Decimal::from_str("0.02").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoints_batch_rejects_malformed_value() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/midpoints")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"0x123": "not-a-price"}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let err = client
+            .get_midpoints(&["0x123".to_string()])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Parse { .. }));
+    }
+
     #[tokio::test]
     async fn test_get_gamma_events_success() {
         let mut server = Server::new_async().await;
@@ -2464,4 +3921,337 @@ mod tests {
         assert!(auth_client.signer.is_some());
         assert_eq!(auth_client.chain_id, 137);
     }
+
+    #[tokio::test]
+    async fn test_cancel_orders_older_than_only_cancels_stale() {
+        let mut server = Server::new_async().await;
+        let now = Utc::now().timestamp();
+        let orders_body = serde_json::json!({
+            "next_cursor": "LTE=",
+            "data": [
+                open_order_json("old-1", now - 7200),
+                open_order_json("fresh", now - 60),
+                open_order_json("old-2", now - 3601 - 60),
+            ]
+        });
+
+        let orders_mock = server
+            .mock("GET", "/data/orders")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(orders_body.to_string())
+            .create_async()
+            .await;
+        let cancel_mock = server
+            .mock("DELETE", "/orders")
+            .match_body(Matcher::Json(serde_json::json!(["old-1", "old-2"])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"canceled": ["old-1", "old-2"], "not_canceled": {}}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let result = client
+            .cancel_orders_older_than(Duration::from_secs(3600))
+            .await
+            .unwrap();
+
+        orders_mock.assert_async().await;
+        cancel_mock.assert_async().await;
+        assert_eq!(result.canceled, vec!["old-1", "old-2"]);
+        assert!(result.not_canceled.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_open_orders_follows_cursor_and_applies_filter() {
+        let mut server = Server::new_async().await;
+        let mut sell = open_order_json("order-2", 1_700_000_100);
+        sell["side"] = "SELL".into();
+        sell["price"] = "0.62".into();
+        sell["original_size"] = "25".into();
+        sell["size_matched"] = "10.5".into();
+        sell["status"] = "MATCHED".into();
+        let first_page = serde_json::json!({
+            "next_cursor": "MTAw",
+            "data": [open_order_json("order-1", 1_700_000_000), sell]
+        });
+        let second_page = serde_json::json!({
+            "next_cursor": "LTE=",
+            "data": [open_order_json("order-3", 1_700_000_200)]
+        });
+
+        let filter = Matcher::AllOf(vec![
+            Matcher::UrlEncoded("market".into(), "0xmarket".into()),
+            Matcher::UrlEncoded("asset_id".into(), "123".into()),
+        ]);
+        let first = server
+            .mock("GET", "/data/orders")
+            .match_query(Matcher::AllOf(vec![
+                filter.clone(),
+                Matcher::UrlEncoded("next_cursor".into(), "MA==".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(first_page.to_string())
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/data/orders")
+            .match_query(Matcher::AllOf(vec![
+                filter,
+                Matcher::UrlEncoded("next_cursor".into(), "MTAw".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(second_page.to_string())
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let orders = client
+            .get_open_orders(Some(
+                crate::types::OpenOrderFilter::builder()
+                    .market("0xmarket")
+                    .asset_id("123"),
+            ))
+            .await
+            .unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        let ids: Vec<_> = orders.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(ids, ["order-1", "order-2", "order-3"]);
+
+        let sell = &orders[1];
+        assert_eq!(sell.asset_id, "123");
+        assert_eq!(sell.side, Side::SELL);
+        assert_eq!(sell.price, Decimal::from_str("0.62").unwrap());
+        assert_eq!(sell.original_size, Decimal::from(25));
+        assert_eq!(sell.size_matched, Decimal::from_str("10.5").unwrap());
+        assert_eq!(sell.remaining_size(), Decimal::from_str("14.5").unwrap());
+        assert_eq!(sell.status, "MATCHED");
+        assert_eq!(orders[0].side, Side::BUY);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_reports_per_id_results() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/orders")
+            .match_body(Matcher::Json(serde_json::json!(["a", "b", "c"])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"canceled": ["a"], "not_canceled": {"b": "order already matched"}}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let ids = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let results = client.cancel_orders(&ids).await.unwrap().results();
+
+        mock.assert_async().await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results["a"], Ok(()));
+        assert_eq!(results["b"], Err("order already matched".to_string()));
+        assert!(results["c"].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_market_and_cancel_all() {
+        let mut server = Server::new_async().await;
+        let market_mock = server
+            .mock("DELETE", "/cancel-market-orders")
+            .match_body(Matcher::PartialJson(
+                serde_json::json!({"market": "0xcondition"}),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"canceled": ["m1"], "not_canceled": {"m2": "not found"}}"#)
+            .create_async()
+            .await;
+        let all_mock = server
+            .mock("DELETE", "/cancel-all")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"canceled": ["x", "y"], "not_canceled": {}}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2(&server.url());
+        let market = client.cancel_market("0xcondition").await.unwrap();
+        assert!(market.is_canceled("m1"));
+        assert_eq!(market.not_canceled["m2"], "not found");
+
+        let all = client.cancel_all().await.unwrap();
+        assert_eq!(all.canceled, vec!["x", "y"]);
+
+        market_mock.assert_async().await;
+        all_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book_depth_sends_param_and_truncates() {
+        let mut server = Server::new_async().await;
+        // Server ignores the depth hint and returns the whole book, worst first.
+        let mock = server
+            .mock("GET", "/book")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("token_id".into(), "0x123".into()),
+                Matcher::UrlEncoded("depth".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                "market": "0x123",
+                "asset_id": "0x123",
+                "hash": "0xabc123",
+                "timestamp": "1234567890",
+                "bids": [
+                    {"price": "0.70", "size": "10"},
+                    {"price": "0.72", "size": "10"},
+                    {"price": "0.74", "size": "10"}
+                ],
+                "asks": [
+                    {"price": "0.80", "size": "10"},
+                    {"price": "0.78", "size": "10"},
+                    {"price": "0.76", "size": "10"}
+                ]
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let book = client.get_order_book_depth("0x123", 2).await.unwrap();
+
+        mock.assert_async().await;
+        let bids: Vec<_> = book.bids.iter().map(|level| level.price).collect();
+        let asks: Vec<_> = book.asks.iter().map(|level| level.price).collect();
+        assert_eq!(
+            bids,
+            vec![
+                Decimal::from_str("0.74").unwrap(),
+                Decimal::from_str("0.72").unwrap()
+            ]
+        );
+        assert_eq!(
+            asks,
+            vec![
+                Decimal::from_str("0.76").unwrap(),
+                Decimal::from_str("0.78").unwrap()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoints_ordered_keeps_input_order() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/midpoints")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"0x456": "0.623", "0x123": "0.755"}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let token_ids = vec![
+            "0x456".to_string(),
+            "0x999".to_string(),
+            "0x123".to_string(),
+        ];
+        let midpoints = client.get_midpoints_ordered(&token_ids).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            midpoints,
+            vec![
+                (
+                    "0x456".to_string(),
+                    Some(Decimal::from_str("0.623").unwrap())
+                ),
+                ("0x999".to_string(), None),
+                (
+                    "0x123".to_string(),
+                    Some(Decimal::from_str("0.755").unwrap())
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_prices_ordered_keeps_input_order() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/prices")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"111": {"BUY": "0.45"}, "222": {"SELL": "0.60"}}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let requests = vec![
+            ("222".to_string(), Side::SELL),
+            ("111".to_string(), Side::SELL),
+            ("111".to_string(), Side::BUY),
+        ];
+        let prices = client.get_prices_ordered(&requests).await.unwrap();
+
+        mock.assert_async().await;
+        let keys: Vec<_> = prices.iter().map(|(key, _)| key.clone()).collect();
+        assert_eq!(keys, requests);
+        assert_eq!(prices[0].1, Some(Decimal::new(60, 2)));
+        assert_eq!(prices[1].1, None);
+        assert_eq!(prices[2].1, Some(Decimal::new(45, 2)));
+    }
+
+    #[tokio::test]
+    async fn test_get_prices_by_pair_empty_input_skips_request() {
+        let server = Server::new_async().await;
+        let client = create_test_client(&server.url());
+
+        let prices = client.get_prices_by_pair(&[]).await.unwrap();
+        assert!(prices.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_prices_by_pair_multiple_pairs() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/prices")
+            .match_body(Matcher::Json(serde_json::json!([
+                {"token_id": "111", "side": "BUY"},
+                {"token_id": "111", "side": "SELL"},
+                {"token_id": "222", "side": "BUY"},
+                {"token_id": "333", "side": "SELL"}
+            ])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"111": {"BUY": "0.45", "SELL": "0.47"}, "222": {"BUY": "0.60"}}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let requests = vec![
+            ("111".to_string(), Side::BUY),
+            ("111".to_string(), Side::SELL),
+            ("222".to_string(), Side::BUY),
+            ("333".to_string(), Side::SELL),
+        ];
+        let prices = client.get_prices_by_pair(&requests).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(prices.len(), 3);
+        assert_eq!(prices[&("111".to_string(), Side::BUY)], Decimal::new(45, 2));
+        assert_eq!(
+            prices[&("111".to_string(), Side::SELL)],
+            Decimal::new(47, 2)
+        );
+        assert_eq!(prices[&("222".to_string(), Side::BUY)], Decimal::new(60, 2));
+        assert!(!prices.contains_key(&("333".to_string(), Side::SELL)));
+    }
 }
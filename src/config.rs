@@ -5,50 +5,119 @@
 
 use std::collections::HashMap;
 
+const POLYGON_MAINNET: u64 = 137;
+const POLYGON_AMOY: u64 = 80002;
+
 /// Contract configuration for a specific network
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ContractConfig {
     pub exchange: String,
+    /// Exchange used for neg-risk markets, which settle through a separate
+    /// adapter.
+    pub neg_risk_exchange: String,
     pub collateral: String,
     pub conditional_tokens: String,
 }
 
+impl ContractConfig {
+    /// Known addresses for `chain_id` (Polygon mainnet and Amoy).
+    ///
+    /// Unknown chains start out empty; fill them in with the `with_*`
+    /// builders before signing orders.
+    pub fn for_chain(chain_id: u64) -> Self {
+        match chain_id {
+            POLYGON_MAINNET => Self {
+                exchange: "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_owned(),
+                neg_risk_exchange: "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_owned(),
+                collateral: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".to_owned(),
+                conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_owned(),
+            },
+            POLYGON_AMOY => Self {
+                exchange: "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40".to_owned(),
+                neg_risk_exchange: "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296".to_owned(),
+                collateral: "0x9c4e1703476e875070ee25b56a58b008cfb8fa78".to_owned(),
+                conditional_tokens: "0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB".to_owned(),
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// Override the standard exchange address
+    pub fn with_exchange(mut self, address: &str) -> Self {
+        self.exchange = address.to_string();
+        self
+    }
+
+    /// Override the neg-risk exchange address
+    pub fn with_neg_risk_exchange(mut self, address: &str) -> Self {
+        self.neg_risk_exchange = address.to_string();
+        self
+    }
+
+    /// Override the collateral token address
+    pub fn with_collateral(mut self, address: &str) -> Self {
+        self.collateral = address.to_string();
+        self
+    }
+
+    /// Override the conditional tokens address
+    pub fn with_conditional_tokens(mut self, address: &str) -> Self {
+        self.conditional_tokens = address.to_string();
+        self
+    }
+
+    /// Exchange that signed orders must target, or `None` if it is not
+    /// configured for this chain.
+    pub fn exchange_for(&self, neg_risk: bool) -> Option<&str> {
+        let exchange = if neg_risk {
+            &self.neg_risk_exchange
+        } else {
+            &self.exchange
+        };
+        (!exchange.is_empty()).then_some(exchange.as_str())
+    }
+}
+
 /// Get contract configuration for a specific chain and risk setting
+///
+/// The returned `exchange` is already the one matching `neg_risk`.
 pub fn get_contract_config(chain_id: u64, neg_risk: bool) -> Option<ContractConfig> {
-    match neg_risk {
-        true => {
-            if chain_id == 137 {
-                return Some(ContractConfig {
-                    exchange: "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_owned(),
-                    collateral: "0x2791bca1f2de4661ed88a30c99a7a9449aa84174".to_owned(),
-                    conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_owned(),
-                });
-            } else if chain_id == 80002 {
-                return Some(ContractConfig {
-                    exchange: "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296".to_owned(),
-                    collateral: "0x9c4e1703476e875070ee25b56a58b008cfb8fa78".to_owned(),
-                    conditional_tokens: "0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB".to_owned(),
-                });
-            }
-            None
-        }
-        false => {
-            if chain_id == 137 {
-                return Some(ContractConfig {
-                    exchange: "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_owned(),
-                    collateral: "0x2791Bca1f2de4661ED88A30C99a7a9449Aa84174".to_owned(),
-                    conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_owned(),
-                });
-            } else if chain_id == 80002 {
-                return Some(ContractConfig {
-                    exchange: "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40".to_owned(),
-                    collateral: "0x9c4e1703476e875070ee25b56a58b008cfb8fa78".to_owned(),
-                    conditional_tokens: "0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB".to_owned(),
-                });
-            }
-            None
+    let config = ContractConfig::for_chain(chain_id);
+    let exchange = config.exchange_for(neg_risk)?.to_string();
+    Some(ContractConfig { exchange, ..config })
+}
+
+/// CLOB host and contracts of the Polymarket deployment on one chain.
+///
+/// Only the CLOB has a known staging host. Gamma, the data API, and the
+/// WebSocket feeds have no published Amoy counterpart, so clients keep their
+/// production defaults for those; override them explicitly if needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainEnv {
+    pub chain_id: u64,
+    pub clob_url: String,
+    pub contracts: ContractConfig,
+}
+
+impl ChainEnv {
+    /// Deployment for `chain_id`. Amoy targets the staging CLOB; any other
+    /// chain gets production, with contracts from
+    /// [`ContractConfig::for_chain`] (empty for unknown chains).
+    pub fn from_chain_id(chain_id: u64) -> Self {
+        let clob_url = match chain_id {
+            POLYGON_AMOY => "https://clob-staging.polymarket.com",
+            _ => "https://clob.polymarket.com",
+        };
+        Self {
+            chain_id,
+            clob_url: clob_url.to_owned(),
+            contracts: ContractConfig::for_chain(chain_id),
         }
     }
+
+    pub fn is_testnet(&self) -> bool {
+        self.chain_id == POLYGON_AMOY
+    }
 }
 
 /// Network configuration
@@ -162,6 +231,62 @@ mod tests {
         assert!(!config.conditional_tokens.is_empty());
     }
 
+    #[test]
+    fn test_contract_config_for_mainnet() {
+        let config = ContractConfig::for_chain(137);
+        assert_eq!(
+            config.exchange_for(false),
+            Some("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E")
+        );
+        assert_eq!(
+            config.exchange_for(true),
+            Some("0xC5d563A36AE78145C45a50134d48A1215220f80a")
+        );
+        assert_eq!(
+            config.collateral,
+            "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174"
+        );
+        assert_eq!(
+            get_contract_config(137, true).unwrap().exchange,
+            config.neg_risk_exchange
+        );
+    }
+
+    #[test]
+    fn test_contract_config_overrides() {
+        let config = ContractConfig::for_chain(31337)
+            .with_exchange("0x1111111111111111111111111111111111111111")
+            .with_collateral("0x2222222222222222222222222222222222222222");
+
+        assert_eq!(
+            config.exchange_for(false),
+            Some("0x1111111111111111111111111111111111111111")
+        );
+        // Nothing configured for neg-risk on this chain yet.
+        assert_eq!(config.exchange_for(true), None);
+        assert_eq!(
+            config.collateral,
+            "0x2222222222222222222222222222222222222222"
+        );
+        assert!(get_contract_config(31337, false).is_none());
+    }
+
+    #[test]
+    fn test_chain_env_selects_deployment() {
+        let mainnet = ChainEnv::from_chain_id(137);
+        assert!(!mainnet.is_testnet());
+        assert_eq!(mainnet.clob_url, "https://clob.polymarket.com");
+        assert_eq!(mainnet.contracts, ContractConfig::for_chain(137));
+
+        let amoy = ChainEnv::from_chain_id(80002);
+        assert!(amoy.is_testnet());
+        assert_eq!(amoy.clob_url, "https://clob-staging.polymarket.com");
+        assert_eq!(
+            amoy.contracts.exchange,
+            "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40"
+        );
+    }
+
     #[test]
     fn test_network_config() {
         let polygon = NetworkConfig::polygon_mainnet();
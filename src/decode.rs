@@ -82,6 +82,105 @@ pub mod deserializers {
         }
     }
 
+    /// Deserialize a string field that the API may also send as a bare number
+    pub fn string_from_string_or_number<'de, D>(
+        deserializer: D,
+    ) -> std::result::Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value {
+            serde_json::Value::String(s) => Ok(s),
+            serde_json::Value::Number(n) => Ok(n.to_string()),
+            _ => Err(serde::de::Error::custom("Expected string or number")),
+        }
+    }
+
+    /// Deserialize an optional string that may arrive as a number, null, or
+    /// an empty string (the latter two become `None`)
+    pub fn optional_string_from_string_or_number<'de, D>(
+        deserializer: D,
+    ) -> std::result::Result<Option<String>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value {
+            serde_json::Value::Null => Ok(None),
+            serde_json::Value::String(s) if s.is_empty() => Ok(None),
+            serde_json::Value::String(s) => Ok(Some(s)),
+            serde_json::Value::Number(n) => Ok(Some(n.to_string())),
+            _ => Err(serde::de::Error::custom("Expected string, number, or null")),
+        }
+    }
+
+    /// Deserialize a list that may be null or a single object; entries that
+    /// fail to decode are skipped instead of failing the whole payload
+    pub fn lenient_vec<'de, T, D>(deserializer: D) -> std::result::Result<Vec<T>, D::Error>
+    where
+        T: serde::de::DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let items = match value {
+            serde_json::Value::Null => Vec::new(),
+            serde_json::Value::Array(items) => items,
+            other => vec![other],
+        };
+        Ok(items
+            .into_iter()
+            .filter_map(|item| serde_json::from_value(item).ok())
+            .collect())
+    }
+
+    /// Deserialize an optional object that may arrive bare, wrapped in a
+    /// list (the first decodable entry wins), or as null
+    pub fn lenient_first<'de, T, D>(deserializer: D) -> std::result::Result<Option<T>, D::Error>
+    where
+        T: serde::de::DeserializeOwned,
+        D: Deserializer<'de>,
+    {
+        Ok(lenient_vec::<T, D>(deserializer)?.into_iter().next())
+    }
+
+    /// Deserialize a bool sent as `true`/`false`, `"true"`/`"false"`, or `0`/`1`
+    pub fn de_bool_flexible<'de, D>(deserializer: D) -> std::result::Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de_optional_bool_flexible(deserializer)?
+            .ok_or_else(|| serde::de::Error::custom("Expected bool, got null"))
+    }
+
+    /// Like [`de_bool_flexible`], with null or an empty string as `None`
+    pub fn de_optional_bool_flexible<'de, D>(
+        deserializer: D,
+    ) -> std::result::Result<Option<bool>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value {
+            serde_json::Value::Null => Ok(None),
+            serde_json::Value::Bool(b) => Ok(Some(b)),
+            serde_json::Value::String(s) => match s.trim().to_ascii_lowercase().as_str() {
+                "" => Ok(None),
+                "true" | "1" => Ok(Some(true)),
+                "false" | "0" => Ok(Some(false)),
+                _ => Err(serde::de::Error::custom(format!("Invalid bool: {s}"))),
+            },
+            serde_json::Value::Number(n) => match n.as_u64() {
+                Some(1) => Ok(Some(true)),
+                Some(0) => Ok(Some(false)),
+                _ => Err(serde::de::Error::custom(format!("Invalid bool: {n}"))),
+            },
+            _ => Err(serde::de::Error::custom(
+                "Expected bool, string, number, or null",
+            )),
+        }
+    }
+
     /// Deserialize DateTime from Unix timestamp
     pub fn datetime_from_timestamp<'de, D>(
         deserializer: D,
@@ -232,19 +331,9 @@ impl Decoder<OrderBook> for RawOrderBookResponse {
 
 impl Decoder<Order> for RawOrderResponse {
     fn decode(&self) -> Result<Order> {
-        let status = match self.status.as_str() {
-            "LIVE" => OrderStatus::Live,
-            "CANCELLED" => OrderStatus::Cancelled,
-            "FILLED" => OrderStatus::Filled,
-            "PARTIAL" => OrderStatus::Partial,
-            "EXPIRED" => OrderStatus::Expired,
-            _ => {
-                return Err(PolyError::parse(
-                    format!("Unknown order status: {}", self.status),
-                    None,
-                ));
-            }
-        };
+        let status = OrderStatus::from_api(&self.status).ok_or_else(|| {
+            PolyError::parse(format!("Unknown order status: {}", self.status), None)
+        })?;
 
         let created_at = chrono::DateTime::from_timestamp(self.created_at as i64, 0)
             .ok_or_else(|| PolyError::parse("Invalid created_at timestamp".to_string(), None))?;
@@ -309,10 +398,12 @@ impl Decoder<Market> for RawMarketResponse {
             Token {
                 token_id: self.tokens[0].token_id.clone(),
                 outcome: self.tokens[0].outcome.clone(),
+                price: None,
             },
             Token {
                 token_id: self.tokens[1].token_id.clone(),
                 outcome: self.tokens[1].outcome.clone(),
+                price: None,
             },
         ];
 
@@ -369,6 +460,13 @@ impl Decoder<Market> for RawMarketResponse {
             volume_1yr_clob: None,
             volume_amm: None,
             volume_clob: None,
+            series_id: None,
+            resolved_outcome: None,
+            comment_count: None,
+            tick_bands: crate::types::TickBand::tail_bands(self.minimum_tick_size),
+            sports: None,
+            resolution_source: None,
+            has_order_book: true,
         })
     }
 }
@@ -0,0 +1,260 @@
+//! Callback-style consumption of the market channel.
+//!
+//! [`MarketEventDispatcher`] owns a [`WssMarketClient`] and routes each event
+//! to the handlers registered for its type, so consumers don't have to write
+//! the `match` over [`WssMarketEvent`] themselves. [`MarketEventDispatcher::run`]
+//! loops until the stream fails or a [`StopHandle`] is triggered.
+
+use crate::errors::Result;
+use crate::wss::{
+    AggBookMessage, LastTradeMessage, MarketBook, PriceChangeMessage, TickSizeChangeMessage,
+    WssMarketClient, WssMarketEvent,
+};
+use std::sync::Arc;
+use tokio::sync::watch;
+
+type Handler = Box<dyn Fn(&WssMarketEvent) + Send + Sync>;
+
+/// Identifies a registered handler for [`MarketEventDispatcher::remove_handler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandlerId(u64);
+
+/// Ends a running [`MarketEventDispatcher::run`] loop. Cheap to clone; safe to
+/// trigger from a handler or another task.
+#[derive(Debug, Clone)]
+pub struct StopHandle {
+    tx: Arc<watch::Sender<bool>>,
+}
+
+impl StopHandle {
+    /// Make `run` return `Ok(())` before dispatching another event. A stop
+    /// requested while `run` isn't active ends the next call immediately.
+    pub fn stop(&self) {
+        self.tx.send_replace(true);
+    }
+}
+
+/// Routes market events to typed handlers.
+pub struct MarketEventDispatcher {
+    client: WssMarketClient,
+    handlers: Vec<(HandlerId, Handler)>,
+    next_id: u64,
+    stop: Arc<watch::Sender<bool>>,
+}
+
+impl MarketEventDispatcher {
+    pub fn new(client: WssMarketClient) -> Self {
+        Self {
+            client,
+            handlers: Vec::new(),
+            next_id: 0,
+            stop: Arc::new(watch::channel(false).0),
+        }
+    }
+
+    /// The wrapped client, e.g. to change subscriptions between runs.
+    pub fn client_mut(&mut self) -> &mut WssMarketClient {
+        &mut self.client
+    }
+
+    pub fn into_inner(self) -> WssMarketClient {
+        self.client
+    }
+
+    pub fn stop_handle(&self) -> StopHandle {
+        StopHandle {
+            tx: self.stop.clone(),
+        }
+    }
+
+    /// Call `handler` for every event, whatever its type.
+    pub fn on_event(
+        &mut self,
+        handler: impl Fn(&WssMarketEvent) + Send + Sync + 'static,
+    ) -> HandlerId {
+        let id = HandlerId(self.next_id);
+        self.next_id += 1;
+        self.handlers.push((id, Box::new(handler)));
+        id
+    }
+
+    pub fn on_book(&mut self, handler: impl Fn(&MarketBook) + Send + Sync + 'static) -> HandlerId {
+        self.on_event(move |evt| {
+            if let WssMarketEvent::Book(book) = evt {
+                handler(book);
+            }
+        })
+    }
+
+    pub fn on_agg_book(
+        &mut self,
+        handler: impl Fn(&AggBookMessage) + Send + Sync + 'static,
+    ) -> HandlerId {
+        self.on_event(move |evt| {
+            if let WssMarketEvent::AggBook(book) = evt {
+                handler(book);
+            }
+        })
+    }
+
+    pub fn on_price_change(
+        &mut self,
+        handler: impl Fn(&PriceChangeMessage) + Send + Sync + 'static,
+    ) -> HandlerId {
+        self.on_event(move |evt| {
+            if let WssMarketEvent::PriceChange(change) = evt {
+                handler(change);
+            }
+        })
+    }
+
+    pub fn on_tick_size_change(
+        &mut self,
+        handler: impl Fn(&TickSizeChangeMessage) + Send + Sync + 'static,
+    ) -> HandlerId {
+        self.on_event(move |evt| {
+            if let WssMarketEvent::TickSizeChange(change) = evt {
+                handler(change);
+            }
+        })
+    }
+
+    pub fn on_trade(
+        &mut self,
+        handler: impl Fn(&LastTradeMessage) + Send + Sync + 'static,
+    ) -> HandlerId {
+        self.on_event(move |evt| {
+            if let WssMarketEvent::LastTrade(trade) = evt {
+                handler(trade);
+            }
+        })
+    }
+
+    /// Unregister a handler. Returns `false` if `id` was already removed.
+    pub fn remove_handler(&mut self, id: HandlerId) -> bool {
+        let before = self.handlers.len();
+        self.handlers.retain(|(handler_id, _)| *handler_id != id);
+        self.handlers.len() != before
+    }
+
+    /// Read events and dispatch them, in registration order, until the
+    /// stream returns an error or [`StopHandle::stop`] is called. A pending
+    /// stop is consumed, so `run` can be called again afterwards; a stop that
+    /// interrupts a reconnect is picked up where it left off.
+    pub async fn run(&mut self) -> Result<()> {
+        let mut stop = self.stop.subscribe();
+        loop {
+            let evt = tokio::select! {
+                biased;
+                _ = stop.wait_for(|stopped| *stopped) => break,
+                evt = self.client.next_event() => evt?,
+            };
+            for (_, handler) in &self.handlers {
+                handler(&evt);
+            }
+        }
+        self.stop.send_replace(false);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_wss::{MockWssServer, gap_backfill_data, mock_stalled_backfill};
+    use serde_json::{Value, json};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    fn trade_frame(price: &str) -> Value {
+        json!({
+            "event_type": "last_trade_price",
+            "asset_id": "111",
+            "fee_rate_bps": "0",
+            "market": "0xabc",
+            "price": price,
+            "size": "10",
+            "side": "BUY",
+            "timestamp": "1700000000000"
+        })
+    }
+
+    fn book_frame() -> Value {
+        json!({
+            "event_type": "book", "asset_id": "111", "market": "0xabc",
+            "timestamp": "1", "hash": "h", "bids": [], "asks": []
+        })
+    }
+
+    #[tokio::test]
+    async fn test_trade_handler_fires_for_trades_only() {
+        let server = MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        client.subscribe(vec!["111".to_string()]).await.unwrap();
+        let mut dispatcher = MarketEventDispatcher::new(client);
+
+        let prices = Arc::new(Mutex::new(Vec::new()));
+        let seen = prices.clone();
+        let stop = dispatcher.stop_handle();
+        dispatcher.on_trade(move |trade| {
+            let mut seen = seen.lock().unwrap();
+            seen.push(trade.price.to_string());
+            if seen.len() == 2 {
+                stop.stop();
+            }
+        });
+        let books = Arc::new(Mutex::new(0));
+        let counted = books.clone();
+        let book_handler = dispatcher.on_book(move |_| *counted.lock().unwrap() += 1);
+        assert!(dispatcher.remove_handler(book_handler));
+        assert!(!dispatcher.remove_handler(book_handler));
+
+        server.push_json(book_frame());
+        server.push_json(trade_frame("0.41"));
+        server.push_json(book_frame());
+        server.push_json(trade_frame("0.42"));
+        dispatcher.run().await.unwrap();
+
+        assert_eq!(*prices.lock().unwrap(), ["0.41", "0.42"]);
+        assert_eq!(*books.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_stop_during_reconnect_then_run_again() {
+        let mut rest = mockito::Server::new_async().await;
+        let trades = mock_stalled_backfill(&mut rest).await;
+        let server = MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url())
+            .with_gap_backfill(gap_backfill_data(&rest.url()));
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+        server.push_json(crate::mock_wss::book_frame());
+        client.next_event().await.unwrap();
+        // The first connect subscribes twice.
+        let before = server.wait_for_subscriptions(2).await.len();
+        let mut dispatcher = MarketEventDispatcher::new(client);
+
+        let prices = Arc::new(Mutex::new(Vec::new()));
+        let seen = prices.clone();
+        let stop = dispatcher.stop_handle();
+        dispatcher.on_trade(move |trade| {
+            seen.lock().unwrap().push(trade.price.to_string());
+            stop.stop();
+        });
+
+        // Stop while the reconnect is still backfilling.
+        server.drop_connection();
+        let stop = dispatcher.stop_handle();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            stop.stop();
+        });
+        dispatcher.run().await.unwrap();
+        assert!(prices.lock().unwrap().is_empty());
+
+        dispatcher.run().await.unwrap();
+        assert_eq!(*prices.lock().unwrap(), ["0.55"]);
+        trades.assert_async().await;
+        assert_eq!(server.connection_count(), 2);
+        assert_eq!(server.subscriptions().len(), before + 1);
+    }
+}
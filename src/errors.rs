@@ -14,6 +14,7 @@ pub enum PolyError {
     #[error("Network error: {message}")]
     Network {
         message: String,
+        kind: NetworkErrorKind,
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
@@ -26,6 +27,12 @@ pub enum PolyError {
         error_code: Option<String>,
     },
 
+    /// Transient edge/CDN failures (Cloudflare 52x) that never reached the API
+    #[error(
+        "Upstream infrastructure error ({status}): {message} (Cloudflare edge failure, not an API response)"
+    )]
+    Infrastructure { status: u16, message: String },
+
     /// Authentication/authorization errors
     #[error("Auth error: {message}")]
     Auth {
@@ -131,6 +138,92 @@ pub enum MarketDataErrorKind {
     BookUnavailable,
 }
 
+/// Network error subcategories
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetworkErrorKind {
+    /// Host name could not be resolved (usually a config problem)
+    Dns,
+    /// TLS handshake or certificate failure
+    Tls,
+    ConnectionRefused,
+    ConnectionReset,
+    /// Other failures while establishing the connection
+    Connect,
+    Timeout,
+    /// The request could not be built (e.g. malformed URL)
+    Builder,
+    /// Failure while sending the request or reading the response
+    Request,
+    Unknown,
+}
+
+impl NetworkErrorKind {
+    /// Classify an error by walking its source chain.
+    ///
+    /// `reqwest` flags are checked first, then I/O error kinds, and finally
+    /// the messages of lower layers (hyper, native-tls) which don't expose
+    /// typed errors for DNS and TLS failures.
+    pub fn classify(err: &(dyn std::error::Error + 'static)) -> Self {
+        let mut chain = Vec::new();
+        let mut current: Option<&(dyn std::error::Error + 'static)> = Some(err);
+        while let Some(e) = current {
+            chain.push(e);
+            current = e.source();
+        }
+
+        for e in &chain {
+            if let Some(io) = e.downcast_ref::<std::io::Error>() {
+                match io.kind() {
+                    std::io::ErrorKind::ConnectionRefused => {
+                        return NetworkErrorKind::ConnectionRefused;
+                    }
+                    std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe => return NetworkErrorKind::ConnectionReset,
+                    std::io::ErrorKind::TimedOut => return NetworkErrorKind::Timeout,
+                    _ => {}
+                }
+            }
+        }
+
+        for e in &chain {
+            let message = e.to_string().to_lowercase();
+            if message.contains("dns error") || message.contains("failed to lookup address") {
+                return NetworkErrorKind::Dns;
+            }
+            if message.contains("tls") || message.contains("ssl") || message.contains("certificate")
+            {
+                return NetworkErrorKind::Tls;
+            }
+        }
+
+        if let Some(reqwest_err) = err.downcast_ref::<reqwest::Error>() {
+            if reqwest_err.is_timeout() {
+                return NetworkErrorKind::Timeout;
+            }
+            if reqwest_err.is_builder() {
+                return NetworkErrorKind::Builder;
+            }
+            if reqwest_err.is_connect() {
+                return NetworkErrorKind::Connect;
+            }
+            if reqwest_err.is_request() || reqwest_err.is_body() {
+                return NetworkErrorKind::Request;
+            }
+        }
+
+        NetworkErrorKind::Unknown
+    }
+
+    /// Whether failures of this kind are worth retrying as-is.
+    pub fn is_transient(&self) -> bool {
+        !matches!(
+            self,
+            NetworkErrorKind::Dns | NetworkErrorKind::Tls | NetworkErrorKind::Builder
+        )
+    }
+}
+
 /// Streaming error subcategories
 #[derive(Debug, Clone, PartialEq)]
 pub enum StreamErrorKind {
@@ -139,24 +232,33 @@ pub enum StreamErrorKind {
     SubscriptionFailed,
     MessageCorrupted,
     Reconnecting,
+    WriteTimeout,
+    Disconnected,
+    /// The server closed the socket for a policy violation (close code 1008).
+    PolicyViolation,
     Unknown,
 }
 
 impl PolyError {
     /// Check if this error is retryable
+    ///
+    /// Transient network failures, timeouts, rate limits (including API 429s)
+    /// and server-side 5xx responses are; other 4xx responses, auth, parse,
+    /// and validation errors are not.
     pub fn is_retryable(&self) -> bool {
         match self {
-            PolyError::Network { .. } => true,
-            PolyError::Api { status, .. } => {
-                // 5xx errors are typically retryable
-                *status >= 500 && *status < 600
-            }
+            PolyError::Network { kind, .. } => kind.is_transient(),
+            PolyError::Api { status, .. } => matches!(status, 429 | 500..=599),
+            PolyError::Infrastructure { .. } => true,
             PolyError::Timeout { .. } => true,
             PolyError::RateLimit { .. } => true,
             PolyError::Stream { kind, .. } => {
                 matches!(
                     kind,
-                    StreamErrorKind::ConnectionLost | StreamErrorKind::Reconnecting
+                    StreamErrorKind::ConnectionLost
+                        | StreamErrorKind::Reconnecting
+                        | StreamErrorKind::WriteTimeout
+                        | StreamErrorKind::Disconnected
                 )
             }
             _ => false,
@@ -167,13 +269,12 @@ impl PolyError {
     pub fn retry_delay(&self) -> Option<Duration> {
         match self {
             PolyError::Network { .. } => Some(Duration::from_millis(100)),
-            PolyError::Api { status, .. } => {
-                if *status >= 500 {
-                    Some(Duration::from_millis(500))
-                } else {
-                    None
-                }
-            }
+            PolyError::Api { status, .. } => match status {
+                429 => Some(Duration::from_secs(1)),
+                500.. => Some(Duration::from_millis(500)),
+                _ => None,
+            },
+            PolyError::Infrastructure { .. } => Some(Duration::from_secs(1)),
             PolyError::Timeout { .. } => Some(Duration::from_millis(50)),
             PolyError::RateLimit { retry_after, .. } => {
                 retry_after.or(Some(Duration::from_secs(1)))
@@ -196,11 +297,49 @@ impl PolyError {
         }
     }
 
+    /// Network failure classification, if this is a network error
+    pub fn network_kind(&self) -> Option<&NetworkErrorKind> {
+        match self {
+            PolyError::Network { kind, .. } => Some(kind),
+            _ => None,
+        }
+    }
+
+    /// HTTP status of the response behind an [`PolyError::Api`] or
+    /// [`PolyError::Infrastructure`] error.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            PolyError::Api { status, .. } | PolyError::Infrastructure { status, .. } => {
+                Some(*status)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the request was rejected for its credentials: any
+    /// [`PolyError::Auth`] error, or an API 401/403.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            PolyError::Auth { .. } => true,
+            PolyError::Api { status, .. } => matches!(status, 401 | 403),
+            _ => false,
+        }
+    }
+
+    /// Input field a validation error was raised for, if known
+    pub fn validation_field(&self) -> Option<&str> {
+        match self {
+            PolyError::Validation { field, .. } => field.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Get error category for metrics
     pub fn category(&self) -> &'static str {
         match self {
             PolyError::Network { .. } => "network",
             PolyError::Api { .. } => "api",
+            PolyError::Infrastructure { .. } => "infrastructure",
             PolyError::Auth { .. } => "auth",
             PolyError::Order { .. } => "order",
             PolyError::MarketData { .. } => "market_data",
@@ -221,13 +360,22 @@ impl PolyError {
         message: impl Into<String>,
         source: E,
     ) -> Self {
+        let kind = NetworkErrorKind::classify(&source);
         Self::Network {
             message: message.into(),
+            kind,
             source: Some(Box::new(source)),
         }
     }
 
+    /// Build an error from a non-success HTTP status.
+    ///
+    /// Cloudflare edge statuses (see [`PolyError::is_cloudflare_status`]) are
+    /// reported as [`PolyError::Infrastructure`] rather than [`PolyError::Api`].
     pub fn api(status: u16, message: impl Into<String>) -> Self {
+        if Self::is_cloudflare_status(status) {
+            return Self::infrastructure(status, message);
+        }
         Self::Api {
             status,
             message: message.into(),
@@ -235,6 +383,19 @@ impl PolyError {
         }
     }
 
+    pub fn infrastructure(status: u16, message: impl Into<String>) -> Self {
+        Self::Infrastructure {
+            status,
+            message: message.into(),
+        }
+    }
+
+    /// Cloudflare-specific 52x statuses (and 530), returned by the edge when
+    /// the origin is unreachable. Their bodies are HTML error pages.
+    pub fn is_cloudflare_status(status: u16) -> bool {
+        matches!(status, 520..=527 | 530)
+    }
+
     pub fn auth(message: impl Into<String>) -> Self {
         Self::Auth {
             message: message.into(),
@@ -307,6 +468,14 @@ impl PolyError {
         }
     }
 
+    /// Validation error blaming the input `field`.
+    pub fn invalid_field(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Validation {
+            message: message.into(),
+            field: Some(field.into()),
+        }
+    }
+
     pub fn internal<E: std::error::Error + Send + Sync + 'static>(
         message: impl Into<String>,
         source: E,
@@ -375,8 +544,13 @@ impl From<tokio_tungstenite::tungstenite::Error> for PolyError {
 impl Clone for PolyError {
     fn clone(&self) -> Self {
         match self {
-            PolyError::Network { message, source: _ } => PolyError::Network {
+            PolyError::Network {
+                message,
+                kind,
+                source: _,
+            } => PolyError::Network {
                 message: message.clone(),
+                kind: kind.clone(),
                 source: None,
             },
             PolyError::Api {
@@ -388,6 +562,10 @@ impl Clone for PolyError {
                 message: message.clone(),
                 error_code: error_code.clone(),
             },
+            PolyError::Infrastructure { status, message } => PolyError::Infrastructure {
+                status: *status,
+                message: message.clone(),
+            },
             PolyError::Auth { message, kind } => PolyError::Auth {
                 message: message.clone(),
                 kind: kind.clone(),
@@ -439,3 +617,171 @@ impl Clone for PolyError {
 
 /// Result type alias for convenience
 pub type Result<T> = std::result::Result<T, PolyError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_io_errors_are_classified() {
+        let refused = PolyError::network("x", io::Error::from(io::ErrorKind::ConnectionRefused));
+        assert_eq!(
+            refused.network_kind(),
+            Some(&NetworkErrorKind::ConnectionRefused)
+        );
+        assert!(refused.is_retryable());
+
+        let reset = PolyError::network("x", io::Error::from(io::ErrorKind::ConnectionReset));
+        assert_eq!(
+            reset.network_kind(),
+            Some(&NetworkErrorKind::ConnectionReset)
+        );
+        assert!(reset.is_retryable());
+    }
+
+    #[test]
+    fn test_dns_and_tls_messages_are_not_retryable() {
+        let dns = PolyError::network("x", io::Error::other("dns error: failed to lookup address"));
+        assert_eq!(dns.network_kind(), Some(&NetworkErrorKind::Dns));
+        assert!(!dns.is_retryable());
+
+        let tls = PolyError::network("x", io::Error::other("certificate verify failed"));
+        assert_eq!(tls.network_kind(), Some(&NetworkErrorKind::Tls));
+        assert!(!tls.is_retryable());
+
+        // Clones keep the classification.
+        assert_eq!(dns.clone().network_kind(), Some(&NetworkErrorKind::Dns));
+    }
+
+    #[tokio::test]
+    async fn test_reqwest_connect_refused() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let err = reqwest::get(format!("http://{}", addr)).await.unwrap_err();
+        let poly = PolyError::network("Request failed", err);
+        assert_eq!(
+            poly.network_kind(),
+            Some(&NetworkErrorKind::ConnectionRefused)
+        );
+    }
+
+    #[test]
+    fn test_cloudflare_status_is_infrastructure_error() {
+        let err = PolyError::api(522, "<html><body>Connection timed out</body></html>");
+        assert!(matches!(err, PolyError::Infrastructure { status: 522, .. }));
+        assert!(err.is_retryable());
+        assert_eq!(err.category(), "infrastructure");
+        assert!(err.to_string().contains("not an API response"));
+
+        let app = PolyError::api(500, "internal");
+        assert!(matches!(app, PolyError::Api { status: 500, .. }));
+    }
+
+    #[test]
+    fn test_classification_covers_every_constructor() {
+        let io_err = || io::Error::from(io::ErrorKind::ConnectionReset);
+        // (error, retryable, status, auth)
+        let cases = [
+            (PolyError::network("x", io_err()), true, None, false),
+            (PolyError::api(429, "slow down"), true, Some(429), false),
+            (PolyError::api(503, "unavailable"), true, Some(503), false),
+            (PolyError::api(400, "bad request"), false, Some(400), false),
+            (PolyError::api(401, "unauthorized"), false, Some(401), true),
+            (PolyError::api(403, "forbidden"), false, Some(403), true),
+            (PolyError::api(404, "missing"), false, Some(404), false),
+            (
+                PolyError::infrastructure(522, "edge"),
+                true,
+                Some(522),
+                false,
+            ),
+            (PolyError::auth("bad key"), false, None, true),
+            (PolyError::crypto("bad signature"), false, None, true),
+            (
+                PolyError::order("x", OrderErrorKind::InvalidPrice),
+                false,
+                None,
+                false,
+            ),
+            (
+                PolyError::market_data("x", MarketDataErrorKind::StaleData),
+                false,
+                None,
+                false,
+            ),
+            (PolyError::config("x"), false, None, false),
+            (PolyError::parse("x", None), false, None, false),
+            (
+                PolyError::timeout(Duration::from_secs(1), "x"),
+                true,
+                None,
+                false,
+            ),
+            (PolyError::rate_limit("x"), true, None, false),
+            (
+                PolyError::stream("x", StreamErrorKind::ConnectionLost),
+                true,
+                None,
+                false,
+            ),
+            (
+                PolyError::stream("x", StreamErrorKind::MessageCorrupted),
+                false,
+                None,
+                false,
+            ),
+            (PolyError::validation("x"), false, None, false),
+            (PolyError::invalid_field("slug", "x"), false, None, false),
+            (PolyError::internal("x", io_err()), false, None, false),
+        ];
+
+        for (err, retryable, status, auth) in cases {
+            assert_eq!(err.is_retryable(), retryable, "{:?}", err);
+            assert_eq!(err.status_code(), status, "{:?}", err);
+            assert_eq!(err.is_auth_error(), auth, "{:?}", err);
+        }
+        assert_eq!(
+            PolyError::api(429, "x").retry_delay(),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reqwest_builder_error() {
+        let err = reqwest::Client::new()
+            .get("not a url")
+            .send()
+            .await
+            .unwrap_err();
+        let poly = PolyError::network("Request failed", err);
+        assert_eq!(poly.network_kind(), Some(&NetworkErrorKind::Builder));
+        assert!(!poly.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_reqwest_timeout() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            // Accept and never answer.
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let err = reqwest::Client::builder()
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap()
+            .get(format!("http://{}", addr))
+            .send()
+            .await
+            .unwrap_err();
+        let poly = PolyError::network("Request failed", err);
+        assert_eq!(poly.network_kind(), Some(&NetworkErrorKind::Timeout));
+        assert!(poly.is_retryable());
+        server.abort();
+    }
+}
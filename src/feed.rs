@@ -0,0 +1,517 @@
+//! Market data feeds layered on top of the WSS and REST clients.
+//!
+//! `HybridFeed` prefers the public market websocket but degrades to periodic
+//! REST order book polling when the socket cannot be established, emitting
+//! the same `WssMarketEvent::Book` events so consumers don't need to care
+//! which transport is active.
+//!
+//! [`with_ticks`] merges any event stream with a fixed-cadence timer for
+//! strategies that act on either, and [`imbalance_updates`] turns book
+//! events into order-book imbalance readings.
+
+use crate::book::OrderBookManager;
+use crate::client::ClobClient;
+use crate::errors::{PolyError, Result};
+use crate::wss::{MarketBook, WssMarketClient, WssMarketEvent};
+use futures::{Stream, StreamExt, stream};
+use rust_decimal::Decimal;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use tokio::time::{Instant, MissedTickBehavior, interval_at, sleep_until};
+use tracing::warn;
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const DEFAULT_WSS_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Transport currently backing a [`HybridFeed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedMode {
+    /// Events are streamed from the market websocket.
+    Wss,
+    /// The websocket is unavailable; books are polled over REST.
+    RestPolling,
+}
+
+/// Market feed that falls back to REST polling while the websocket is down.
+pub struct HybridFeed {
+    wss: WssMarketClient,
+    rest: ClobClient,
+    asset_ids: Vec<String>,
+    mode: FeedMode,
+    poll_interval: Duration,
+    wss_retry_interval: Duration,
+    next_poll: Instant,
+    next_wss_attempt: Instant,
+    pending_events: VecDeque<WssMarketEvent>,
+}
+
+impl HybridFeed {
+    /// Build a feed from an existing WSS client and a REST client used for
+    /// fallback polling.
+    pub fn new(wss: WssMarketClient, rest: ClobClient) -> Self {
+        let now = Instant::now();
+        Self {
+            wss,
+            rest,
+            asset_ids: Vec::new(),
+            mode: FeedMode::Wss,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            wss_retry_interval: DEFAULT_WSS_RETRY_INTERVAL,
+            next_poll: now,
+            next_wss_attempt: now,
+            pending_events: VecDeque::new(),
+        }
+    }
+
+    /// Interval between REST order book polls while in fallback mode.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// How long to stay on REST polling before probing the websocket again.
+    pub fn with_wss_retry_interval(mut self, interval: Duration) -> Self {
+        self.wss_retry_interval = interval;
+        self
+    }
+
+    /// Transport currently in use.
+    pub fn mode(&self) -> FeedMode {
+        self.mode
+    }
+
+    /// Subscribe to the given asset IDs, falling back to REST polling if the
+    /// websocket cannot connect.
+    pub async fn subscribe(&mut self, asset_ids: Vec<String>) -> Result<()> {
+        self.asset_ids = asset_ids.clone();
+        match self.wss.subscribe(asset_ids).await {
+            Ok(()) => {
+                self.mode = FeedMode::Wss;
+                Ok(())
+            }
+            Err(err @ PolyError::Stream { .. }) => {
+                self.enter_fallback(&err);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Read the next event from whichever transport is active.
+    ///
+    /// While polling, each tick yields one `Book` event per subscribed asset.
+    /// Once the retry interval elapses the websocket is probed again and, if
+    /// it connects, the feed switches back to streaming.
+    pub async fn next_event(&mut self) -> Result<WssMarketEvent> {
+        loop {
+            if let Some(evt) = self.pending_events.pop_front() {
+                return Ok(evt);
+            }
+
+            match self.mode {
+                FeedMode::Wss => match self.wss.next_event().await {
+                    Ok(evt) => return Ok(evt),
+                    Err(err @ PolyError::Stream { .. }) => self.enter_fallback(&err),
+                    Err(err) => return Err(err),
+                },
+                FeedMode::RestPolling => {
+                    if Instant::now() >= self.next_wss_attempt && self.try_restore_wss().await {
+                        continue;
+                    }
+                    sleep_until(self.next_poll).await;
+                    self.next_poll = Instant::now() + self.poll_interval;
+                    self.poll_books().await;
+                }
+            }
+        }
+    }
+
+    fn enter_fallback(&mut self, err: &PolyError) {
+        warn!("WSS unavailable, falling back to REST polling: {}", err);
+        let now = Instant::now();
+        self.mode = FeedMode::RestPolling;
+        self.next_poll = now;
+        self.next_wss_attempt = now + self.wss_retry_interval;
+    }
+
+    async fn try_restore_wss(&mut self) -> bool {
+        match self.wss.subscribe(self.asset_ids.clone()).await {
+            Ok(()) => {
+                self.mode = FeedMode::Wss;
+                true
+            }
+            Err(err) => {
+                warn!("WSS still unavailable: {}", err);
+                self.next_wss_attempt = Instant::now() + self.wss_retry_interval;
+                false
+            }
+        }
+    }
+
+    async fn poll_books(&mut self) {
+        for asset_id in &self.asset_ids {
+            match self.rest.get_order_book(asset_id).await {
+                Ok(summary) => self
+                    .pending_events
+                    .push_back(WssMarketEvent::Book(MarketBook::from(summary))),
+                Err(err) => warn!("REST book poll for {} failed: {}", asset_id, err),
+            }
+        }
+    }
+}
+
+/// Item yielded by [`with_ticks`].
+#[derive(Debug, Clone)]
+pub enum FeedItem {
+    /// A market event from the underlying stream.
+    Event(WssMarketEvent),
+    /// The timer fired; carries the scheduled tick time.
+    Tick(Instant),
+}
+
+/// Adapt a market client into a stream of events.
+///
+/// The stream yields the first error returned by
+/// [`WssMarketClient::next_event`] and then ends.
+pub fn market_events(client: WssMarketClient) -> impl Stream<Item = Result<WssMarketEvent>> {
+    stream::unfold(Some(client), |client| async move {
+        let mut client = client?;
+        match client.next_event().await {
+            Ok(evt) => Some((Ok(evt), Some(client))),
+            Err(err) => Some((Err(err), None)),
+        }
+    })
+}
+
+/// Merge `events` with a timer firing every `period`, so callers can react
+/// to either without hand-rolling a `select!` loop.
+///
+/// The first tick fires one `period` after the call; ticks missed while the
+/// consumer is busy are skipped rather than burst. When both are ready,
+/// events are yielded first. The stream ends when `events` ends. Must be
+/// called from within a Tokio runtime.
+pub fn with_ticks<S>(events: S, period: Duration) -> impl Stream<Item = Result<FeedItem>>
+where
+    S: Stream<Item = Result<WssMarketEvent>>,
+{
+    let mut ticker = interval_at(Instant::now() + period, period);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    stream::unfold(
+        (Box::pin(events), ticker),
+        |(mut events, mut ticker)| async move {
+            let item = tokio::select! {
+                biased;
+                evt = events.next() => evt?.map(FeedItem::Event),
+                at = ticker.tick() => Ok(FeedItem::Tick(at)),
+            };
+            Some((item, (events, ticker)))
+        },
+    )
+}
+
+/// Item yielded by [`imbalance_updates`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImbalanceUpdate {
+    pub asset_id: String,
+    /// See [`crate::book::OrderBook::imbalance`]; `None` once the book's
+    /// top levels are empty.
+    pub imbalance: Option<Decimal>,
+}
+
+/// Track books from `events` and yield the top-`levels` imbalance of each
+/// asset whenever a `book` or `price_change` event moves it.
+///
+/// Books are kept in an [`OrderBookManager`]. A `price_change` for an asset
+/// without a snapshot yields a `BookUnavailable` error and the stream
+/// carries on; errors from `events` are passed through.
+pub fn imbalance_updates<S>(events: S, levels: usize) -> impl Stream<Item = Result<ImbalanceUpdate>>
+where
+    S: Stream<Item = Result<WssMarketEvent>>,
+{
+    struct State<S> {
+        events: std::pin::Pin<Box<S>>,
+        books: OrderBookManager,
+        /// Sequence of the last delta applied per asset with a snapshot.
+        sequences: HashMap<String, u64>,
+        last: HashMap<String, Option<Decimal>>,
+        pending: VecDeque<Result<ImbalanceUpdate>>,
+    }
+
+    let state = State {
+        events: Box::pin(events),
+        books: OrderBookManager::new(usize::MAX),
+        sequences: HashMap::new(),
+        last: HashMap::new(),
+        pending: VecDeque::new(),
+    };
+
+    stream::unfold(state, move |mut state| async move {
+        loop {
+            if let Some(item) = state.pending.pop_front() {
+                return Some((item, state));
+            }
+            let evt = match state.events.next().await? {
+                Ok(evt) => evt,
+                Err(err) => return Some((Err(err), state)),
+            };
+            let mut touched: Vec<String> = Vec::new();
+            match &evt {
+                WssMarketEvent::Book(book) => {
+                    match state.books.apply_snapshot(&book.to_snapshot()) {
+                        Ok(()) => {
+                            state.sequences.insert(book.asset_id.clone(), 0);
+                            touched.push(book.asset_id.clone());
+                        }
+                        Err(err) => state.pending.push_back(Err(err)),
+                    }
+                }
+                WssMarketEvent::PriceChange(change) => {
+                    let timestamp = evt.timestamp().unwrap_or_else(chrono::Utc::now);
+                    let mut missing: Vec<String> = Vec::new();
+                    for entry in &change.price_changes {
+                        let Some(sequence) = state.sequences.get_mut(&entry.asset_id) else {
+                            if !missing.contains(&entry.asset_id) {
+                                missing.push(entry.asset_id.clone());
+                            }
+                            continue;
+                        };
+                        *sequence += 1;
+                        if let Err(err) = state
+                            .books
+                            .apply_delta(entry.to_delta(timestamp, *sequence))
+                        {
+                            state.pending.push_back(Err(err));
+                        } else if !touched.contains(&entry.asset_id) {
+                            touched.push(entry.asset_id.clone());
+                        }
+                    }
+                    if !missing.is_empty() {
+                        state.pending.push_back(Err(PolyError::market_data(
+                            format!("price_change before snapshot for {}", missing.join(", ")),
+                            crate::errors::MarketDataErrorKind::BookUnavailable,
+                        )));
+                    }
+                }
+                _ => continue,
+            }
+            for asset_id in touched {
+                let Ok(imbalance) = state.books.imbalance(&asset_id, levels) else {
+                    continue;
+                };
+                if state.last.insert(asset_id.clone(), imbalance) != Some(imbalance) {
+                    state.pending.push_back(Ok(ImbalanceUpdate {
+                        asset_id,
+                        imbalance,
+                    }));
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_falls_back_to_rest_books_when_wss_unreachable() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "123".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "market": "0xabc",
+                    "asset_id": "123",
+                    "hash": "0xhash",
+                    "timestamp": "1700000000000",
+                    "bids": [{"price": "0.48", "size": "100"}],
+                    "asks": [{"price": "0.52", "size": "50"}]
+                }"#,
+            )
+            .expect_at_least(2)
+            .create_async()
+            .await;
+
+        let wss = WssMarketClient::with_url("ws://127.0.0.1:1").with_max_reconnect_attempts(1);
+        let rest = ClobClient::new(&server.url());
+        let mut feed = HybridFeed::new(wss, rest)
+            .with_poll_interval(Duration::from_millis(10))
+            .with_wss_retry_interval(Duration::from_secs(60));
+
+        feed.subscribe(vec!["123".to_string()]).await.unwrap();
+        assert_eq!(feed.mode(), FeedMode::RestPolling);
+
+        for _ in 0..2 {
+            match feed.next_event().await.unwrap() {
+                WssMarketEvent::Book(book) => {
+                    assert_eq!(book.event_type, "book");
+                    assert_eq!(book.asset_id, "123");
+                    assert_eq!(book.timestamp, "1700000000000");
+                    assert_eq!(book.bids.len(), 1);
+                }
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        assert_eq!(feed.mode(), FeedMode::RestPolling);
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rest_fallback_skips_assets_whose_book_fails() {
+        let mut server = Server::new_async().await;
+        let _missing = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "bad".into()))
+            .with_status(404)
+            .with_body(r#"{"error": "No orderbook exists for the requested token id"}"#)
+            .create_async()
+            .await;
+        let _book = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "123".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "market": "0xabc",
+                    "asset_id": "123",
+                    "hash": "0xhash",
+                    "timestamp": "1700000000000",
+                    "bids": [{"price": "0.48", "size": "100"}],
+                    "asks": [{"price": "0.52", "size": "50"}]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let wss = WssMarketClient::with_url("ws://127.0.0.1:1").with_max_reconnect_attempts(1);
+        let rest = ClobClient::new(&server.url());
+        let mut feed = HybridFeed::new(wss, rest)
+            .with_poll_interval(Duration::from_millis(10))
+            .with_wss_retry_interval(Duration::from_secs(60));
+
+        feed.subscribe(vec!["bad".to_string(), "123".to_string()])
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            match feed.next_event().await.unwrap() {
+                WssMarketEvent::Book(book) => assert_eq!(book.asset_id, "123"),
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+    }
+
+    fn last_trade(asset_id: &str) -> WssMarketEvent {
+        let frame = serde_json::json!({
+            "event_type": "last_trade_price",
+            "asset_id": asset_id,
+            "market": "0xabc",
+            "price": "0.5",
+            "side": "BUY",
+            "size": "10",
+            "fee_rate_bps": "0",
+            "timestamp": "1700000000000"
+        });
+        WssMarketEvent::LastTrade(serde_json::from_value(frame).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_imbalance_updates_follow_book_changes() {
+        let book = |asset_id: &str| {
+            WssMarketEvent::Book(
+                serde_json::from_value(serde_json::json!({
+                    "event_type": "book",
+                    "asset_id": asset_id,
+                    "market": "0xabc",
+                    "timestamp": "1",
+                    "hash": "h0",
+                    "bids": [{"price": "0.50", "size": "300"}, {"price": "0.49", "size": "100"}],
+                    "asks": [{"price": "0.52", "size": "100"}]
+                }))
+                .unwrap(),
+            )
+        };
+        let change = |asset_id: &str, price: &str, size: &str, side: &str| {
+            WssMarketEvent::PriceChange(
+                serde_json::from_value(serde_json::json!({
+                    "event_type": "price_change",
+                    "market": "0xabc",
+                    "timestamp": "2",
+                    "price_changes": [{
+                        "asset_id": asset_id, "price": price, "size": size, "side": side,
+                        "hash": "h1", "best_bid": "0.50", "best_ask": "0.52"
+                    }]
+                }))
+                .unwrap(),
+            )
+        };
+        let events = stream::iter(
+            vec![
+                book("1"),
+                last_trade("1"),
+                // Beyond the top level: no update.
+                change("1", "0.49", "50", "BUY"),
+                change("1", "0.52", "300", "SELL"),
+                change("2", "0.40", "10", "BUY"),
+            ]
+            .into_iter()
+            .map(Ok),
+        );
+
+        let items: Vec<_> = imbalance_updates(events, 1).collect().await;
+
+        assert_eq!(items.len(), 3);
+        let update = |imbalance| ImbalanceUpdate {
+            asset_id: "1".to_string(),
+            imbalance: Some(imbalance),
+        };
+        assert_eq!(items[0].as_ref().unwrap(), &update(Decimal::new(5, 1)));
+        assert_eq!(items[1].as_ref().unwrap(), &update(Decimal::ZERO));
+        assert!(matches!(
+            items[2],
+            Err(PolyError::MarketData {
+                kind: crate::errors::MarketDataErrorKind::BookUnavailable,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_with_ticks_interleaves_events_and_ticks() {
+        let start = Instant::now();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for (delay_ms, asset_id) in [(1_500, "a"), (1_000, "b")] {
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                tx.send(Ok(last_trade(asset_id))).unwrap();
+            }
+        });
+        let events = stream::unfold(
+            rx,
+            |mut rx| async move { rx.recv().await.map(|evt| (evt, rx)) },
+        );
+
+        let items: Vec<String> = with_ticks(events, Duration::from_secs(1))
+            .map(|item| match item.unwrap() {
+                FeedItem::Tick(at) => format!("tick@{}", (at - start).as_millis()),
+                FeedItem::Event(WssMarketEvent::LastTrade(trade)) => {
+                    format!("event:{}@{}", trade.asset_id, start.elapsed().as_millis())
+                }
+                FeedItem::Event(other) => panic!("unexpected event: {:?}", other),
+            })
+            .collect()
+            .await;
+
+        assert_eq!(
+            items,
+            vec!["tick@1000", "event:a@1500", "tick@2000", "event:b@2500"]
+        );
+    }
+}
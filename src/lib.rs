@@ -6,22 +6,39 @@
 pub mod api;
 pub mod auth;
 pub mod book;
+pub mod candle;
 pub mod client;
 pub mod config;
 pub mod decode;
+pub mod dispatch;
 pub mod errors;
+pub mod feed;
 pub mod fill;
+#[cfg(test)]
+pub(crate) mod mock_wss;
+pub mod neg_risk;
 pub mod orders;
+pub mod portfolio;
+pub mod shutdown;
+pub mod snapshot;
 pub mod types;
 pub mod utils;
 pub mod ws;
 pub mod wss;
 
-pub use api::GammaClient;
+pub use api::{DataClient, GammaClient};
 
 pub use crate::client::{
     ClobClient, CreateOrderOptions, DataApiClient, MarketClient, OrderArgs, PolyClient,
+    SharedCredentials,
 };
+pub use crate::dispatch::{HandlerId, MarketEventDispatcher, StopHandle};
 pub use crate::errors::{PolyError, Result};
+pub use crate::feed::{FeedItem, FeedMode, HybridFeed};
+pub use crate::shutdown::shutdown_signal;
+pub use crate::snapshot::snapshot_market;
 pub use crate::types::{ApiCredentials, SignedOrderRequest};
-pub use crate::wss::{WssMarketClient, WssMarketEvent, WssUserClient, WssUserEvent};
+pub use crate::wss::{
+    EventSink, LocalBook, LocalBooks, SinkErrorPolicy, SubscriptionMode, TopOfBook, WssCloseAction,
+    WssCloseReason, WssConfig, WssMarketClient, WssMarketEvent, WssUserClient, WssUserEvent,
+};
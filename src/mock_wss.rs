@@ -0,0 +1,274 @@
+//! Local websocket server for exercising the WSS clients in tests.
+//!
+//! `MockWssServer` serves one connection at a time on `127.0.0.1`, records
+//! every text frame the client sends, and forwards frames queued with
+//! [`MockWssServer::push`]. Frames pushed before a client connects are held
+//! until it does. [`MockWssServer::drop_connection`] closes the current
+//! socket so reconnect paths can be driven; the server then waits for the
+//! next connection.
+//!
+//! [`mock_stalled_backfill`] and [`gap_backfill_data`] give the gap backfill a
+//! Data API side whose first response stalls, to land a timeout mid-reconnect.
+
+use crate::api::DataClient;
+use futures::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::{Notify, mpsc};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::{CloseFrame, Message};
+
+enum Command {
+    Frame(Message),
+    Close(Option<CloseFrame>),
+}
+
+#[derive(Default)]
+struct Shared {
+    received: Mutex<Vec<String>>,
+    received_changed: Notify,
+    connections: AtomicUsize,
+}
+
+pub(crate) struct MockWssServer {
+    url: String,
+    commands: mpsc::UnboundedSender<Command>,
+    shared: Arc<Shared>,
+    handle: JoinHandle<()>,
+}
+
+impl MockWssServer {
+    pub(crate) async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (commands, mut rx) = mpsc::unbounded_channel();
+        let shared = Arc::new(Shared::default());
+
+        let state = shared.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let Ok(mut ws) = tokio_tungstenite::accept_async(stream).await else {
+                    continue;
+                };
+                state.connections.fetch_add(1, Ordering::SeqCst);
+                loop {
+                    tokio::select! {
+                        incoming = ws.next() => match incoming {
+                            Some(Ok(Message::Text(text))) => {
+                                state.received.lock().unwrap().push(text.to_string());
+                                state.received_changed.notify_waiters();
+                            }
+                            Some(Ok(_)) => {}
+                            Some(Err(_)) | None => break,
+                        },
+                        command = rx.recv() => match command {
+                            Some(Command::Frame(frame)) => {
+                                if ws.send(frame).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Some(Command::Close(frame)) => {
+                                let _ = ws.close(frame).await;
+                                // Finish the handshake so the client sees a clean close.
+                                while let Some(Ok(_)) = ws.next().await {}
+                                break;
+                            }
+                            None => return,
+                        },
+                    }
+                }
+            }
+        });
+
+        Self {
+            url,
+            commands,
+            shared,
+            handle,
+        }
+    }
+
+    /// Base URL to hand to `WssMarketClient::with_url` / `WssUserClient::with_url`.
+    pub(crate) fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Queue a text frame for the connected (or next) client.
+    pub(crate) fn push(&self, frame: impl Into<String>) {
+        let text: String = frame.into();
+        let _ = self
+            .commands
+            .send(Command::Frame(Message::Text(text.into())));
+    }
+
+    pub(crate) fn push_json(&self, value: Value) {
+        self.push(value.to_string());
+    }
+
+    /// Close the current connection once queued frames have been sent.
+    pub(crate) fn drop_connection(&self) {
+        let _ = self.commands.send(Command::Close(None));
+    }
+
+    /// Like [`MockWssServer::drop_connection`], with a close frame carrying
+    /// `code` and `reason`.
+    pub(crate) fn close_with(&self, code: u16, reason: &str) {
+        let frame = CloseFrame {
+            code: CloseCode::from(code),
+            reason: reason.to_string().into(),
+        };
+        let _ = self.commands.send(Command::Close(Some(frame)));
+    }
+
+    /// Connections accepted so far.
+    pub(crate) fn connection_count(&self) -> usize {
+        self.shared.connections.load(Ordering::SeqCst)
+    }
+
+    /// Every text frame received, across connections, in arrival order.
+    pub(crate) fn received(&self) -> Vec<String> {
+        self.shared.received.lock().unwrap().clone()
+    }
+
+    /// Received frames that parse as JSON objects with an `assets_ids` or
+    /// `markets` list, i.e. subscription requests.
+    pub(crate) fn subscriptions(&self) -> Vec<Value> {
+        self.received()
+            .iter()
+            .filter_map(|text| serde_json::from_str::<Value>(text).ok())
+            .filter(|value| value.get("assets_ids").is_some() || value.get("markets").is_some())
+            .collect()
+    }
+
+    /// Wait until at least `count` subscription frames have arrived.
+    pub(crate) async fn wait_for_subscriptions(&self, count: usize) -> Vec<Value> {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let notified = self.shared.received_changed.notified();
+                let subscriptions = self.subscriptions();
+                if subscriptions.len() >= count {
+                    return subscriptions;
+                }
+                notified.await;
+            }
+        })
+        .await
+        .expect("timed out waiting for subscription frames")
+    }
+}
+
+/// Data API client for `WssMarketClient::with_gap_backfill` against a mock
+/// server.
+pub(crate) fn gap_backfill_data(rest_url: &str) -> DataClient {
+    DataClient::new().with_base_url(rest_url)
+}
+
+/// Print time of the mock backfill trades, far enough ahead to fall inside
+/// any gap a test opens.
+pub(crate) const BACKFILL_TRADE_SECS: i64 = 4_000_000_000;
+
+/// One Data API trade on asset "123" of market "0xabc".
+pub(crate) fn data_trade(side: &str, price: f64, size: f64, timestamp: i64) -> Value {
+    json!({
+        "proxyWallet": "0xwallet", "side": side, "asset": "123", "conditionId": "0xabc",
+        "size": size, "price": price, "timestamp": timestamp
+    })
+}
+
+/// `book` frame for asset "123", which tells the client its market.
+pub(crate) fn book_frame() -> Value {
+    json!({
+        "event_type": "book", "asset_id": "123", "market": "0xabc",
+        "timestamp": "1", "hash": "h", "bids": [], "asks": []
+    })
+}
+
+/// `/trades` returning one trade, expected twice. The first response
+/// stalls for 2s so a caller's timeout fires mid-reconnect.
+pub(crate) async fn mock_stalled_backfill(rest: &mut mockito::ServerGuard) -> mockito::Mock {
+    let calls = AtomicUsize::new(0);
+    rest.mock("GET", "/trades")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "market".into(),
+            "0xabc".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(move |w| {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                std::thread::sleep(Duration::from_secs(2));
+            }
+            let body = json!([data_trade("BUY", 0.55, 10.0, BACKFILL_TRADE_SECS)]);
+            w.write_all(body.to_string().as_bytes())
+        })
+        .expect(2)
+        .create_async()
+        .await
+}
+
+impl Drop for MockWssServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wss::{WssMarketClient, WssMarketEvent};
+
+    fn book_frame(asset_id: &str) -> Value {
+        json!({
+            "event_type": "book",
+            "asset_id": asset_id,
+            "market": "0xabc",
+            "timestamp": "1",
+            "hash": "h",
+            "bids": [{"price": "0.40", "size": "10"}],
+            "asks": []
+        })
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_and_receive_round_trip() {
+        let server = MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        client
+            .subscribe(vec!["111".to_string(), "222".to_string()])
+            .await
+            .unwrap();
+
+        let subscriptions = server.wait_for_subscriptions(1).await;
+        assert_eq!(subscriptions[0]["assets_ids"], json!(["111", "222"]));
+
+        server.push_json(book_frame("222"));
+        match client.next_event().await.unwrap() {
+            WssMarketEvent::Book(book) => assert_eq!(book.asset_id, "222"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert_eq!(server.connection_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_connection_is_resubscribed() {
+        let server = MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        client.subscribe(vec!["111".to_string()]).await.unwrap();
+        let before = server.wait_for_subscriptions(1).await.len();
+
+        server.drop_connection();
+        server.push_json(book_frame("111"));
+        match client.next_event().await.unwrap() {
+            WssMarketEvent::Book(book) => assert_eq!(book.asset_id, "111"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        assert_eq!(server.connection_count(), 2);
+        let subscriptions = server.wait_for_subscriptions(before + 1).await;
+        assert_eq!(subscriptions.last().unwrap()["assets_ids"], json!(["111"]));
+    }
+}
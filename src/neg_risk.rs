@@ -0,0 +1,222 @@
+//! Entry pricing for neg-risk market groups.
+//!
+//! Exactly one outcome of a neg-risk group resolves YES, so holding one NO
+//! share on every other outcome is worth the same as one YES share on the
+//! target plus `n - 2` USDC, which the neg-risk adapter pays out when the NO
+//! shares are converted. [`NegRiskGroup::best_entry`] prices both routes
+//! against the current books and picks the cheaper one.
+
+use crate::types::Side;
+use crate::wss::MarketBook;
+use rust_decimal::Decimal;
+
+/// Books for one outcome of a neg-risk group.
+#[derive(Debug, Clone)]
+pub struct NegRiskOutcome {
+    pub label: String,
+    pub yes_book: MarketBook,
+    pub no_book: MarketBook,
+}
+
+/// Outcomes sharing a neg-risk market ID, with their current books.
+#[derive(Debug, Clone)]
+pub struct NegRiskGroup {
+    pub neg_risk_market_id: String,
+    pub outcomes: Vec<NegRiskOutcome>,
+}
+
+/// How a YES position on the target outcome is acquired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryRoute {
+    /// Buy the target's YES token.
+    Direct,
+    /// Buy NO on every other outcome and convert.
+    Synthetic,
+}
+
+/// One taker buy making up an [`EntryPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryLeg {
+    pub asset_id: String,
+    pub side: Side,
+    pub size: Decimal,
+    pub avg_price: Option<Decimal>,
+    pub cost: Decimal,
+}
+
+/// Cheapest way found to buy `size` YES exposure on an outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPlan {
+    pub route: EntryRoute,
+    pub legs: Vec<EntryLeg>,
+    /// USDC spent across all legs.
+    pub cost: Decimal,
+    /// USDC returned by converting the NO shares; zero for direct entries.
+    pub rebate: Decimal,
+    /// Whether every leg fills the full size against the books.
+    pub complete: bool,
+}
+
+impl EntryPlan {
+    /// Cost after the conversion rebate.
+    pub fn net_cost(&self) -> Decimal {
+        self.cost - self.rebate
+    }
+
+    /// Net cost per share of YES exposure.
+    pub fn effective_price(&self, size: Decimal) -> Option<Decimal> {
+        if size.is_zero() {
+            return None;
+        }
+        Some(self.net_cost() / size)
+    }
+}
+
+impl NegRiskGroup {
+    /// Cheaper of buying the target's YES directly or synthesizing it from
+    /// NO on every other outcome. A route the books can't fill in full only
+    /// wins when neither route can.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_outcome_index` is out of bounds.
+    pub fn best_entry(&self, target_outcome_index: usize, size: Decimal) -> EntryPlan {
+        let target = &self.outcomes[target_outcome_index];
+        let direct = plan(EntryRoute::Direct, [&target.yes_book], size, Decimal::ZERO);
+        if self.outcomes.len() < 2 {
+            return direct;
+        }
+
+        let others = self
+            .outcomes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != target_outcome_index)
+            .map(|(_, outcome)| &outcome.no_book);
+        let rebate = size * Decimal::from(self.outcomes.len() - 2);
+        let synthetic = plan(EntryRoute::Synthetic, others, size, rebate);
+
+        match (direct.complete, synthetic.complete) {
+            (true, false) => direct,
+            (false, true) => synthetic,
+            _ if synthetic.net_cost() < direct.net_cost() => synthetic,
+            _ => direct,
+        }
+    }
+}
+
+fn plan<'a>(
+    route: EntryRoute,
+    books: impl IntoIterator<Item = &'a MarketBook>,
+    size: Decimal,
+    rebate: Decimal,
+) -> EntryPlan {
+    let legs: Vec<EntryLeg> = books
+        .into_iter()
+        .map(|book| {
+            let fill = book.simulate_fill(Side::BUY, size);
+            EntryLeg {
+                asset_id: book.asset_id.clone(),
+                side: Side::BUY,
+                size: fill.filled,
+                avg_price: fill.avg_price,
+                cost: fill.avg_price.unwrap_or_default() * fill.filled,
+            }
+        })
+        .collect();
+
+    EntryPlan {
+        route,
+        complete: legs.iter().all(|leg| leg.size == size),
+        cost: legs.iter().map(|leg| leg.cost).sum(),
+        rebate,
+        legs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::OrderSummary;
+    use rust_decimal_macros::dec;
+
+    fn book(asset_id: &str, asks: &[(Decimal, Decimal)]) -> MarketBook {
+        MarketBook {
+            event_type: "book".to_string(),
+            asset_id: asset_id.to_string(),
+            market: "0xgroup".to_string(),
+            timestamp: "1".to_string(),
+            hash: "h".to_string(),
+            bids: Vec::new(),
+            asks: asks
+                .iter()
+                .map(|&(price, size)| OrderSummary { price, size })
+                .collect(),
+        }
+    }
+
+    fn outcome(label: &str, yes_ask: Decimal, no_ask: Decimal) -> NegRiskOutcome {
+        NegRiskOutcome {
+            label: label.to_string(),
+            yes_book: book(&format!("{label}-yes"), &[(yes_ask, dec!(1000))]),
+            no_book: book(&format!("{label}-no"), &[(no_ask, dec!(1000))]),
+        }
+    }
+
+    fn group(outcomes: Vec<NegRiskOutcome>) -> NegRiskGroup {
+        NegRiskGroup {
+            neg_risk_market_id: "0xgroup".to_string(),
+            outcomes,
+        }
+    }
+
+    #[test]
+    fn test_synthetic_route_wins_when_complements_are_cheap() {
+        // YES on A costs 0.60; NO on B and C cost 0.75 + 0.80 - 1 = 0.55.
+        let group = group(vec![
+            outcome("a", dec!(0.60), dec!(0.42)),
+            outcome("b", dec!(0.27), dec!(0.75)),
+            outcome("c", dec!(0.22), dec!(0.80)),
+        ]);
+
+        let plan = group.best_entry(0, dec!(100));
+
+        assert_eq!(plan.route, EntryRoute::Synthetic);
+        assert!(plan.complete);
+        let assets: Vec<_> = plan.legs.iter().map(|leg| leg.asset_id.as_str()).collect();
+        assert_eq!(assets, ["b-no", "c-no"]);
+        assert_eq!(plan.cost, dec!(155));
+        assert_eq!(plan.rebate, dec!(100));
+        assert_eq!(plan.effective_price(dec!(100)), Some(dec!(0.55)));
+    }
+
+    #[test]
+    fn test_direct_route_wins_when_cheaper() {
+        let group = group(vec![
+            outcome("a", dec!(0.50), dec!(0.52)),
+            outcome("b", dec!(0.27), dec!(0.75)),
+            outcome("c", dec!(0.22), dec!(0.80)),
+        ]);
+
+        let plan = group.best_entry(0, dec!(10));
+
+        assert_eq!(plan.route, EntryRoute::Direct);
+        assert_eq!(plan.legs.len(), 1);
+        assert_eq!(plan.net_cost(), dec!(5));
+    }
+
+    #[test]
+    fn test_thin_synthetic_leg_falls_back_to_direct() {
+        let mut outcomes = vec![
+            outcome("a", dec!(0.60), dec!(0.42)),
+            outcome("b", dec!(0.27), dec!(0.75)),
+            outcome("c", dec!(0.22), dec!(0.80)),
+        ];
+        outcomes[2].no_book = book("c-no", &[(dec!(0.80), dec!(5))]);
+
+        let plan = group(outcomes).best_entry(0, dec!(100));
+
+        assert_eq!(plan.route, EntryRoute::Direct);
+        assert!(plan.complete);
+    }
+}
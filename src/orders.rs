@@ -5,6 +5,7 @@
 
 use crate::auth::sign_order_message;
 use crate::client::OrderArgs;
+pub use crate::config::{ContractConfig, get_contract_config};
 use crate::errors::{OrderErrorKind, PolyError, Result};
 use crate::types::{ExtraOrderArgs, MarketOrderArgs, OrderOptions, Side, SignedOrderRequest};
 use alloy_primitives::{Address, U256};
@@ -36,18 +37,13 @@ pub struct RoundConfig {
     amount: u32,
 }
 
-/// Contract configuration
-pub struct ContractConfig {
-    pub exchange: String,
-    pub collateral: String,
-    pub conditional_tokens: String,
-}
-
 /// Order builder for creating and signing orders
+#[derive(Clone)]
 pub struct OrderBuilder {
     signer: PrivateKeySigner,
     sig_type: SigType,
     funder: Address,
+    contracts: Option<ContractConfig>,
 }
 
 /// Rounding configurations for different tick sizes
@@ -88,23 +84,6 @@ static ROUNDING_CONFIG: LazyLock<HashMap<Decimal, RoundConfig>> = LazyLock::new(
     ])
 });
 
-/// Get contract configuration for chain
-pub fn get_contract_config(chain_id: u64, neg_risk: bool) -> Option<ContractConfig> {
-    match (chain_id, neg_risk) {
-        (137, false) => Some(ContractConfig {
-            exchange: "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_string(),
-            collateral: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".to_string(),
-            conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_string(),
-        }),
-        (137, true) => Some(ContractConfig {
-            exchange: "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_string(),
-            collateral: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".to_string(),
-            conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_string(),
-        }),
-        _ => None,
-    }
-}
-
 /// Generate a random seed for order salt
 fn generate_seed() -> u64 {
     let mut rng = rand::rng();
@@ -140,6 +119,40 @@ fn decimal_to_token_units(amount: Decimal) -> Result<u64> {
     })
 }
 
+/// Rebuild the EIP-712 order struct from its wire representation.
+fn order_from_request(signed: &SignedOrderRequest) -> Result<crate::auth::Order> {
+    let address = |field: &str, value: &str| {
+        Address::from_str(value)
+            .map_err(|e| PolyError::validation(format!("Invalid {} address: {}", field, e)))
+    };
+    let uint = |field: &str, value: &str| {
+        U256::from_str_radix(value, 10)
+            .map_err(|e| PolyError::validation(format!("Invalid {}: {}", field, e)))
+    };
+    let side = match signed.side.as_str() {
+        "BUY" => Side::BUY,
+        "SELL" => Side::SELL,
+        other => {
+            return Err(PolyError::validation(format!("Invalid side: {}", other)));
+        }
+    };
+
+    Ok(crate::auth::Order {
+        salt: U256::from(signed.salt),
+        maker: address("maker", &signed.maker)?,
+        signer: address("signer", &signed.signer)?,
+        taker: address("taker", &signed.taker)?,
+        tokenId: uint("tokenId", &signed.token_id)?,
+        makerAmount: uint("makerAmount", &signed.maker_amount)?,
+        takerAmount: uint("takerAmount", &signed.taker_amount)?,
+        expiration: uint("expiration", &signed.expiration)?,
+        nonce: uint("nonce", &signed.nonce)?,
+        feeRateBps: uint("feeRateBps", &signed.fee_rate_bps)?,
+        side: side as u8,
+        signatureType: signed.signature_type,
+    })
+}
+
 impl OrderBuilder {
     /// Create a new order builder
     pub fn new(
@@ -154,6 +167,7 @@ impl OrderBuilder {
             signer,
             sig_type,
             funder,
+            contracts: None,
         }
     }
 
@@ -168,6 +182,46 @@ impl OrderBuilder {
         self.funder = funder;
     }
 
+    /// Sign against `contracts` instead of the built-in addresses for the
+    /// order's chain id.
+    pub fn set_contract_config(&mut self, contracts: ContractConfig) {
+        self.contracts = Some(contracts);
+    }
+
+    /// Check that `signed` carries a valid EIP-712 signature from this
+    /// builder's signer for the given exchange, and names this builder's
+    /// signer and funder. Returns `Ok(false)` on any mismatch and an error
+    /// only if the order fields cannot be decoded.
+    pub fn verify_signed_order(
+        &self,
+        chain_id: u64,
+        signed: &SignedOrderRequest,
+        neg_risk: bool,
+    ) -> Result<bool> {
+        let exchange = self.exchange_address(chain_id, neg_risk)?;
+        let order = order_from_request(signed)?;
+        if order.signer != self.signer.address() || order.maker != self.funder {
+            return Ok(false);
+        }
+
+        let recovered =
+            crate::auth::recover_order_signer(&order, &signed.signature, chain_id, exchange)?;
+        Ok(recovered == self.signer.address())
+    }
+
+    fn exchange_address(&self, chain_id: u64, neg_risk: bool) -> Result<Address> {
+        let contracts = match &self.contracts {
+            Some(contracts) => contracts.clone(),
+            None => ContractConfig::for_chain(chain_id),
+        };
+        let exchange = contracts.exchange_for(neg_risk).ok_or_else(|| {
+            PolyError::config("No contract found with given chain_id and neg_risk")
+        })?;
+
+        Address::from_str(exchange)
+            .map_err(|e| PolyError::config(format!("Invalid exchange address: {}", e)))
+    }
+
     /// Fix amount rounding according to configuration
     fn fix_amount_rounding(&self, mut amt: Decimal, round_config: &RoundConfig) -> Decimal {
         if amt.scale() > round_config.amount {
@@ -275,12 +329,7 @@ impl OrderBuilder {
             .neg_risk
             .ok_or_else(|| PolyError::validation("Cannot create order without neg_risk"))?;
 
-        let contract_config = get_contract_config(chain_id, neg_risk).ok_or_else(|| {
-            PolyError::config("No contract found with given chain_id and neg_risk")
-        })?;
-
-        let exchange_address = Address::from_str(&contract_config.exchange)
-            .map_err(|e| PolyError::config(format!("Invalid exchange address: {}", e)))?;
+        let exchange_address = self.exchange_address(chain_id, neg_risk)?;
 
         self.build_signed_order(
             order_args.token_id.clone(),
@@ -318,12 +367,7 @@ impl OrderBuilder {
             .neg_risk
             .ok_or_else(|| PolyError::validation("Cannot create order without neg_risk"))?;
 
-        let contract_config = get_contract_config(chain_id, neg_risk).ok_or_else(|| {
-            PolyError::config("No contract found with given chain_id and neg_risk")
-        })?;
-
-        let exchange_address = Address::from_str(&contract_config.exchange)
-            .map_err(|e| PolyError::config(format!("Invalid exchange address: {}", e)))?;
+        let exchange_address = self.exchange_address(chain_id, neg_risk)?;
 
         self.build_signed_order(
             order_args.token_id.clone(),
@@ -0,0 +1,246 @@
+//! Aggregate exposure and PnL figures over data-api positions.
+
+use crate::types::{Position, outcome_labels_match};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+/// Which side of a binary market a position token represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionSide {
+    /// First outcome (YES for binary markets).
+    Yes,
+    /// Second outcome (NO for binary markets).
+    No,
+}
+
+impl PositionSide {
+    /// Classify a position from its outcome index, falling back to the
+    /// outcome label when the index is missing.
+    pub fn of(position: &Position) -> Self {
+        match position.outcome_index {
+            Some(1) => PositionSide::No,
+            Some(_) => PositionSide::Yes,
+            None => match position.outcome.as_deref() {
+                Some(label) if outcome_labels_match(label, "no") => PositionSide::No,
+                _ => PositionSide::Yes,
+            },
+        }
+    }
+}
+
+/// Snapshot of a user's positions with exposure helpers.
+///
+/// Every position token is long its own outcome, so notional is always
+/// `size * cur_price`. For net exposure, YES tokens count as long and NO
+/// tokens as short the underlying market.
+#[derive(Debug, Clone, Default)]
+pub struct Portfolio {
+    positions: Vec<Position>,
+}
+
+impl Portfolio {
+    /// Build a portfolio from positions returned by
+    /// [`crate::api::DataClient::get_positions`].
+    pub fn from_positions(positions: Vec<Position>) -> Self {
+        Self { positions }
+    }
+
+    /// Positions backing this portfolio.
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    /// Sum of the absolute mark-to-market notional across all positions.
+    pub fn gross_exposure(&self) -> Decimal {
+        self.positions
+            .iter()
+            .map(|p| (p.size * p.cur_price).abs())
+            .sum()
+    }
+
+    /// YES notional minus NO notional.
+    pub fn net_exposure(&self) -> Decimal {
+        self.positions
+            .iter()
+            .map(|p| {
+                let notional = p.size * p.cur_price;
+                match PositionSide::of(p) {
+                    PositionSide::Yes => notional,
+                    PositionSide::No => -notional,
+                }
+            })
+            .sum()
+    }
+
+    /// Mark-to-market PnL of the open size against its average entry price.
+    pub fn unrealized_pnl(&self) -> Decimal {
+        self.positions
+            .iter()
+            .map(|p| p.size * (p.cur_price - p.avg_price))
+            .sum()
+    }
+}
+
+/// Net exposure of one correlation group in a [`RiskBook`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupExposure {
+    /// YES shares held across the group.
+    pub long_shares: Decimal,
+    /// NO shares held across the group.
+    pub short_shares: Decimal,
+    /// YES notional minus NO notional at current prices.
+    pub usdc_delta: Decimal,
+    pub positions: usize,
+}
+
+impl GroupExposure {
+    /// Long minus short shares; positive is net long the group's event.
+    pub fn net_shares(&self) -> Decimal {
+        self.long_shares - self.short_shares
+    }
+}
+
+/// Positions netted per correlation group, e.g. every market on the same
+/// election or game, so offsetting bets across markets cancel out.
+#[derive(Debug, Clone, Default)]
+pub struct RiskBook {
+    groups: BTreeMap<String, GroupExposure>,
+}
+
+impl RiskBook {
+    /// Group `positions` by `correlation_key` and net each group. YES
+    /// tokens count as long and NO tokens as short, as in
+    /// [`Portfolio::net_exposure`].
+    pub fn new<F>(positions: &[Position], correlation_key: F) -> Self
+    where
+        F: Fn(&Position) -> String,
+    {
+        let mut groups: BTreeMap<String, GroupExposure> = BTreeMap::new();
+        for position in positions {
+            let group = groups.entry(correlation_key(position)).or_default();
+            let notional = position.size * position.cur_price;
+            match PositionSide::of(position) {
+                PositionSide::Yes => {
+                    group.long_shares += position.size;
+                    group.usdc_delta += notional;
+                }
+                PositionSide::No => {
+                    group.short_shares += position.size;
+                    group.usdc_delta -= notional;
+                }
+            }
+            group.positions += 1;
+        }
+        Self { groups }
+    }
+
+    pub fn group(&self, key: &str) -> Option<&GroupExposure> {
+        self.groups.get(key)
+    }
+
+    /// Every group, ordered by key.
+    pub fn groups(&self) -> impl Iterator<Item = (&str, &GroupExposure)> {
+        self.groups
+            .iter()
+            .map(|(key, exposure)| (key.as_str(), exposure))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn position(outcome: &str, index: u32, size: Decimal, avg: Decimal, cur: Decimal) -> Position {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xwallet",
+            "asset": format!("asset-{}", outcome),
+            "conditionId": "0xcondition",
+            "size": size,
+            "avgPrice": avg,
+            "initialValue": size * avg,
+            "currentValue": size * cur,
+            "cashPnl": "0",
+            "percentPnl": "0",
+            "totalBought": size,
+            "realizedPnl": "0",
+            "percentRealizedPnl": "0",
+            "curPrice": cur,
+            "redeemable": false,
+            "mergeable": false,
+            "outcome": outcome,
+            "outcomeIndex": index
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_portfolio_aggregates() {
+        let portfolio = Portfolio::from_positions(vec![
+            position("Yes", 0, dec!(100), dec!(0.40), dec!(0.55)),
+            position("No", 1, dec!(50), dec!(0.70), dec!(0.60)),
+        ]);
+
+        // 100 * 0.55 + 50 * 0.60
+        assert_eq!(portfolio.gross_exposure(), dec!(85));
+        // 55 - 30
+        assert_eq!(portfolio.net_exposure(), dec!(25));
+        // 100 * 0.15 + 50 * -0.10
+        assert_eq!(portfolio.unrealized_pnl(), dec!(10));
+    }
+
+    fn grouped_position(slug: &str, outcome_index: u32, size: Decimal, cur: Decimal) -> Position {
+        serde_json::from_value(serde_json::json!({
+            "asset": format!("{}-{}", slug, outcome_index),
+            "conditionId": format!("0x{}", slug),
+            "size": size,
+            "avgPrice": "0.5",
+            "curPrice": cur,
+            "eventSlug": slug.split('-').next().unwrap(),
+            "outcomeIndex": outcome_index
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_risk_book_nets_per_group() {
+        let positions = vec![
+            grouped_position("election-winner", 0, dec!(100), dec!(0.60)),
+            grouped_position("election-margin", 1, dec!(40), dec!(0.30)),
+            grouped_position("election-turnout", 0, dec!(10), dec!(0.50)),
+            grouped_position("fed-cut", 1, dec!(200), dec!(0.80)),
+        ];
+
+        let portfolio = Portfolio::from_positions(positions);
+        let book = RiskBook::new(portfolio.positions(), |p| {
+            p.event_slug.clone().unwrap_or_default()
+        });
+
+        let election = book.group("election").unwrap();
+        assert_eq!(election.positions, 3);
+        assert_eq!(election.long_shares, dec!(110));
+        assert_eq!(election.short_shares, dec!(40));
+        assert_eq!(election.net_shares(), dec!(70));
+        // 60 + 5 - 12
+        assert_eq!(election.usdc_delta, dec!(53));
+
+        let fed = book.group("fed").unwrap();
+        assert_eq!(fed.net_shares(), dec!(-200));
+        assert_eq!(fed.usdc_delta, dec!(-160));
+
+        let keys: Vec<_> = book.groups().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["election", "fed"]);
+        assert!(book.group("missing").is_none());
+    }
+
+    #[test]
+    fn test_position_side_falls_back_to_label() {
+        let mut no = position("No", 1, dec!(1), dec!(0.5), dec!(0.5));
+        no.outcome_index = None;
+        assert_eq!(PositionSide::of(&no), PositionSide::No);
+
+        let empty = Portfolio::default();
+        assert_eq!(empty.gross_exposure(), Decimal::ZERO);
+        assert_eq!(empty.net_exposure(), Decimal::ZERO);
+    }
+}
@@ -0,0 +1,87 @@
+//! Graceful shutdown for long-running consumers.
+//!
+//! [`shutdown_signal`] resolves on Ctrl-C (SIGINT) or, on Unix, SIGTERM. Race
+//! it against the event loop with `tokio::select!` so the loop can stop
+//! between events instead of being killed mid-write.
+
+/// Resolve once the process is asked to stop (SIGINT, or SIGTERM on Unix).
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            tracing::warn!("failed to listen for Ctrl-C: {}", err);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(err) => {
+                tracing::warn!("failed to listen for SIGTERM: {}", err);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock_wss::MockWssServer;
+    use crate::wss::WssMarketClient;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_select_loop_stops_on_shutdown() {
+        let server = MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        client.subscribe(vec!["111".to_string()]).await.unwrap();
+        server.push_json(json!({
+            "event_type": "book",
+            "asset_id": "111",
+            "market": "0xabc",
+            "timestamp": "1",
+            "hash": "h",
+            "bids": [],
+            "asks": []
+        }));
+
+        // Stand-in for `shutdown_signal()`, fired by hand after the first event.
+        let (trigger, shutdown) = tokio::sync::oneshot::channel::<()>();
+        let mut trigger = Some(trigger);
+        let mut shutdown = shutdown;
+        let mut events = 0;
+
+        let run = async {
+            loop {
+                tokio::select! {
+                    _ = &mut shutdown => break,
+                    event = client.next_event() => {
+                        event.unwrap();
+                        events += 1;
+                        if let Some(trigger) = trigger.take() {
+                            trigger.send(()).unwrap();
+                        }
+                    }
+                }
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(5), run)
+            .await
+            .expect("loop did not stop after shutdown");
+
+        assert_eq!(events, 1);
+    }
+}
@@ -0,0 +1,175 @@
+//! One-shot, point-in-time market views for scripts and quick tooling.
+
+use crate::client::{ClobClient, DEFAULT_CLOB_BASE};
+use crate::errors::{MarketDataErrorKind, PolyError, Result};
+use crate::types::{Market, OrderBookSummary};
+use rust_decimal::Decimal;
+use serde_json::Value;
+use std::str::FromStr;
+
+/// Everything needed to eyeball a binary market in a single struct.
+///
+/// Unlike [`crate::types::MarketSnapshot`], which is a compact quote summary,
+/// this carries the resolved market and both full order books.
+#[derive(Debug, Clone)]
+pub struct MarketSnapshot {
+    pub market: Market,
+    pub yes_book: OrderBookSummary,
+    pub no_book: OrderBookSummary,
+    /// Midpoint of the YES token, if the book has both sides.
+    pub midpoint: Option<Decimal>,
+    /// Last traded price of the YES token, if any trade happened.
+    pub last_trade: Option<Decimal>,
+}
+
+/// Fetch a [`MarketSnapshot`] for `condition_id` using ephemeral clients
+/// pointed at the production endpoints.
+pub async fn snapshot_market(condition_id: &str) -> Result<MarketSnapshot> {
+    snapshot_market_with(&ClobClient::new(DEFAULT_CLOB_BASE), condition_id).await
+}
+
+/// Same as [`snapshot_market`] but reuses an existing client, which lets
+/// callers target custom hosts.
+pub async fn snapshot_market_with(
+    client: &ClobClient,
+    condition_id: &str,
+) -> Result<MarketSnapshot> {
+    let market = client.get_market_by_condition_id(condition_id).await?;
+
+    let yes_token = market.tokens[0].token_id.clone();
+    let no_token = market.tokens[1].token_id.clone();
+    if yes_token.is_empty() || no_token.is_empty() {
+        return Err(PolyError::market_data(
+            format!("Market {} has no CLOB token ids", condition_id),
+            MarketDataErrorKind::IncompleteData,
+        ));
+    }
+
+    let (yes_book, no_book) = tokio::try_join!(
+        client.get_order_book(&yes_token),
+        client.get_order_book(&no_token)
+    )?;
+
+    // Empty books have no midpoint and fresh markets have no trades; neither
+    // should sink the whole snapshot.
+    let midpoint = client.get_midpoint(&yes_token).await.ok().flatten();
+    let last_trade = client
+        .get_last_trade_price(&yes_token)
+        .await
+        .ok()
+        .as_ref()
+        .and_then(|value| value.get("price"))
+        .and_then(decimal_from_value);
+
+    Ok(MarketSnapshot {
+        market,
+        yes_book,
+        no_book,
+        midpoint,
+        last_trade,
+    })
+}
+
+fn decimal_from_value(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::String(s) => Decimal::from_str(s).ok(),
+        Value::Number(n) => Decimal::from_str(&n.to_string()).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use rust_decimal_macros::dec;
+
+    fn book_body(asset_id: &str) -> String {
+        format!(
+            r#"{{"market": "0xabc", "asset_id": "{}", "hash": "h", "timestamp": "1",
+                "bids": [{{"price": "0.40", "size": "10"}}],
+                "asks": [{{"price": "0.60", "size": "10"}}]}}"#,
+            asset_id
+        )
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_market_assembles_parts() {
+        let mut server = Server::new_async().await;
+        let market_mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded("condition_ids".into(), "0xabc".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[{"conditionId": "0xabc", "slug": "test", "active": true, "closed": false,
+                     "outcomes": "[\"Yes\",\"No\"]", "clobTokenIds": "[\"111\",\"222\"]"}]"#,
+            )
+            .create_async()
+            .await;
+        let yes_mock = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "111".into()))
+            .with_status(200)
+            .with_body(book_body("111"))
+            .create_async()
+            .await;
+        let no_mock = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "222".into()))
+            .with_status(200)
+            .with_body(book_body("222"))
+            .create_async()
+            .await;
+        let _mid_mock = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "111".into()))
+            .with_status(200)
+            .with_body(r#"{"mid": "0.50"}"#)
+            .create_async()
+            .await;
+        let _trade_mock = server
+            .mock("GET", "/last-trade-price")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "111".into()))
+            .with_status(200)
+            .with_body(r#"{"price": "0.55", "side": "BUY"}"#)
+            .create_async()
+            .await;
+
+        let client = ClobClient::new(&server.url()).with_gamma_base(&server.url());
+        let snapshot = snapshot_market_with(&client, "0xabc").await.unwrap();
+
+        market_mock.assert_async().await;
+        yes_mock.assert_async().await;
+        no_mock.assert_async().await;
+        assert_eq!(snapshot.market.condition_id, "0xabc");
+        assert_eq!(snapshot.yes_book.asset_id, "111");
+        assert_eq!(snapshot.no_book.asset_id, "222");
+        assert_eq!(snapshot.midpoint, Some(dec!(0.50)));
+        assert_eq!(snapshot.last_trade, Some(dec!(0.55)));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_market_not_found() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = ClobClient::new(&server.url()).with_gamma_base(&server.url());
+        let err = snapshot_market_with(&client, "0xmissing")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PolyError::MarketData {
+                kind: MarketDataErrorKind::MarketNotFound,
+                ..
+            }
+        ));
+    }
+}
@@ -241,6 +241,42 @@ pub enum OrderStatus {
     Partial,
     #[serde(rename = "EXPIRED")]
     Expired,
+    /// Marketable order held back by the matching delay.
+    #[serde(rename = "DELAYED")]
+    Delayed,
+    #[serde(rename = "MATCHED")]
+    Matched,
+    /// Marketable order that found no liquidity once the delay elapsed.
+    #[serde(rename = "UNMATCHED")]
+    Unmatched,
+}
+
+impl OrderStatus {
+    /// Parse a status as the CLOB reports it. Case-insensitive, and accepts
+    /// the `ORDER_STATUS_` prefix and the `CANCELED` spelling.
+    pub fn from_api(raw: &str) -> Option<Self> {
+        let upper = raw.trim().to_ascii_uppercase();
+        let name = upper.strip_prefix("ORDER_STATUS_").unwrap_or(&upper);
+        match name {
+            "LIVE" => Some(OrderStatus::Live),
+            "CANCELLED" | "CANCELED" => Some(OrderStatus::Cancelled),
+            "FILLED" => Some(OrderStatus::Filled),
+            "PARTIAL" => Some(OrderStatus::Partial),
+            "EXPIRED" => Some(OrderStatus::Expired),
+            "DELAYED" => Some(OrderStatus::Delayed),
+            "MATCHED" => Some(OrderStatus::Matched),
+            "UNMATCHED" => Some(OrderStatus::Unmatched),
+            _ => None,
+        }
+    }
+
+    /// Whether the order can no longer change state.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(
+            self,
+            OrderStatus::Live | OrderStatus::Partial | OrderStatus::Delayed
+        )
+    }
 }
 
 /// Market snapshot representing current state
@@ -382,10 +418,10 @@ impl FastOrderDelta {
         tick_size: Option<Decimal>,
     ) -> std::result::Result<Self, &'static str> {
         // Validate tick alignment if we have a tick size
-        if let Some(tick_size) = tick_size {
-            if !is_price_tick_aligned(delta.price, tick_size) {
-                return Err("Price not aligned to tick size");
-            }
+        if let Some(tick_size) = tick_size
+            && !is_price_tick_aligned(delta.price, tick_size)
+        {
+            return Err("Price not aligned to tick size");
         }
 
         // Convert to fixed-point with validation
@@ -572,6 +608,7 @@ impl PostOrder {
 pub struct BatchOrderResponse {
     pub success: bool,
     pub error_msg: Option<String>,
+    #[serde(alias = "orderID")]
     pub order_id: Option<String>,
     pub making_amount: Option<String>,
     pub taking_amount: Option<String>,
@@ -580,10 +617,18 @@ pub struct BatchOrderResponse {
 
 impl BatchOrderResponse {
     pub fn has_error(&self) -> bool {
-        !self.success || self.error_msg.is_some()
+        !self.success || self.error_msg.as_deref().is_some_and(|msg| !msg.is_empty())
     }
 }
 
+/// An order accepted by the exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlacedOrder {
+    pub order_id: String,
+    /// Exchange status, e.g. `live`, `matched`, or `delayed`.
+    pub status: Option<String>,
+}
+
 /// Market information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Market {
@@ -649,13 +694,200 @@ pub struct Market {
     pub volume_amm: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub volume_clob: Option<Decimal>,
+    /// Gamma series this market recurs in (e.g. a weekly market), if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub series_id: Option<String>,
+    /// Winning outcome label once the market has resolved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_outcome: Option<String>,
+    /// Number of comments on the market, when Gamma reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_count: Option<u64>,
+    /// Price-dependent tick sizes: the CLOB's tail bands (see
+    /// [`TickBand::tail_bands`]) when decoded from Gamma or the CLOB. Empty
+    /// for a flat-tick market, in which case `minimum_tick_size` applies
+    /// everywhere.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tick_bands: Vec<TickBand>,
+    /// Game metadata for sports markets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sports: Option<SportsInfo>,
+    /// Where the outcome is checked (a URL or an agency name), if stated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution_source: Option<String>,
+    /// Whether the market trades on the CLOB. Markets without an order book
+    /// (AMM-only or resolved before CLOB listing) still have token ids, but
+    /// books, prices, and market-channel subscriptions for them stay empty.
+    /// Assumed `true` when the source doesn't say.
+    #[serde(default = "default_has_order_book", alias = "enable_order_book")]
+    pub has_order_book: bool,
+}
+
+fn default_has_order_book() -> bool {
+    true
+}
+
+/// Game metadata attached to a sports market.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SportsInfo {
+    /// Team names; taken from the outcomes of moneyline markets, empty for
+    /// spreads, totals, and props.
+    pub teams: Vec<String>,
+    /// League label from the Gamma `/sports` listing; see
+    /// [`Market::resolve_league`].
+    pub league: Option<String>,
+    pub game_start_time: Option<String>,
+    pub game_id: Option<String>,
+    /// Gamma `sportsMarketType`, e.g. `moneyline`, `spreads`, `totals`.
+    pub market_type: Option<String>,
+}
+
+/// Tick size that applies to prices in `[min_price, max_price]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickBand {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub min_price: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub max_price: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub tick_size: Decimal,
+}
+
+impl TickBand {
+    pub fn new(min_price: Decimal, max_price: Decimal, tick_size: Decimal) -> Self {
+        Self {
+            min_price,
+            max_price,
+            tick_size,
+        }
+    }
+
+    pub fn contains(&self, price: Decimal) -> bool {
+        price >= self.min_price && price <= self.max_price
+    }
+
+    /// Bands for the CLOB's tail rule: once a book trades below 0.04 or
+    /// above 0.96 its tick drops to 0.001, which the market channel
+    /// announces as `tick_size_change`. Empty when `base_tick` is already
+    /// that fine or unknown (zero).
+    pub fn tail_bands(base_tick: Decimal) -> Vec<TickBand> {
+        let tail_tick = Decimal::new(1, 3);
+        if base_tick <= tail_tick {
+            return Vec::new();
+        }
+        vec![
+            Self::new(Decimal::ZERO, Decimal::new(39, 3), tail_tick),
+            Self::new(Decimal::new(961, 3), Decimal::ONE, tail_tick),
+        ]
+    }
+}
+
+impl Market {
+    /// Whether the market is a sports game market.
+    pub fn is_sports(&self) -> bool {
+        self.sports.is_some()
+    }
+
+    /// Fill in [`SportsInfo::league`] from the Gamma `/sports` listing by
+    /// matching the market's series against each sport's series.
+    pub fn resolve_league(&mut self, sports: &[Sport]) {
+        let (Some(info), Some(series_id)) = (self.sports.as_mut(), self.series_id.as_deref())
+        else {
+            return;
+        };
+        if let Some(sport) = sports
+            .iter()
+            .find(|sport| sport.series_id().as_deref() == Some(series_id))
+        {
+            info.league = sport.label();
+        }
+    }
+
+    /// Tick size in effect at `price`: the first band containing it, or
+    /// `minimum_tick_size` when no band applies.
+    pub fn tick_size_at(&self, price: Decimal) -> Decimal {
+        self.tick_bands
+            .iter()
+            .find(|band| band.contains(price))
+            .map(|band| band.tick_size)
+            .unwrap_or(self.minimum_tick_size)
+    }
+
+    /// Check that `price` is inside `[tick, 1 - tick]` and aligned to the
+    /// tick size in effect at that price.
+    pub fn validate_order_price(&self, price: Decimal) -> Result<()> {
+        let tick_size = self.tick_size_at(price);
+        if price < tick_size || price > Decimal::ONE - tick_size {
+            return Err(crate::errors::PolyError::validation(format!(
+                "Price {} is not in range of tick_size {}",
+                price, tick_size
+            )));
+        }
+        if !is_price_tick_aligned(price, tick_size) {
+            return Err(crate::errors::PolyError::validation(format!(
+                "Price {} is not aligned to tick_size {}",
+                price, tick_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Token whose outcome label matches `outcome`, ignoring case and
+    /// surrounding whitespace.
+    pub fn token_for_outcome(&self, outcome: &str) -> Option<&Token> {
+        self.tokens.iter().find(|token| token.has_outcome(outcome))
+    }
+
+    /// Token ID for `outcome`; see [`Market::token_for_outcome`].
+    pub fn token_id_for_outcome(&self, outcome: &str) -> Option<&str> {
+        self.token_for_outcome(outcome)
+            .map(|token| token.token_id.as_str())
+    }
+
+    /// Rules the market resolves by, from its description.
+    pub fn resolution_criteria(&self) -> Option<&str> {
+        Some(self.description.as_str()).filter(|text| !text.trim().is_empty())
+    }
+
+    /// Price of `outcome` formatted per `config`, when the market carries
+    /// outcome prices.
+    pub fn display_price(
+        &self,
+        outcome: &str,
+        config: &crate::utils::display::DisplayConfig,
+    ) -> Option<String> {
+        let price = self.token_for_outcome(outcome)?.price?;
+        Some(crate::utils::display::format_decimal(price, config))
+    }
 }
 
 /// Token information within a market
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Token {
     pub token_id: String,
+    /// Outcome label exactly as the API returned it.
     pub outcome: String,
+    /// Last outcome price, when the listing includes one.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
+    pub price: Option<Decimal>,
+}
+
+impl Token {
+    /// Whether this token's outcome is `label`, ignoring case and
+    /// surrounding whitespace.
+    pub fn has_outcome(&self, label: &str) -> bool {
+        outcome_labels_match(&self.outcome, label)
+    }
+}
+
+/// Compare outcome labels after trimming and case-folding, since markets
+/// spell them inconsistently ("Yes", "YES", " yes ").
+pub(crate) fn outcome_labels_match(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
 }
 
 impl GammaMarket {
@@ -666,22 +898,109 @@ impl GammaMarket {
             .unwrap_or_default()
     }
 
+    /// Game metadata when Gamma marks this as a sports market.
+    pub fn sports_info(&self) -> Option<SportsInfo> {
+        if self.game_id.is_none() && self.sports_market_type.is_none() {
+            return None;
+        }
+        let teams = match self.sports_market_type.as_deref() {
+            Some("moneyline") => self.normalized_outcomes(),
+            _ => Vec::new(),
+        };
+        Some(SportsInfo {
+            teams,
+            league: None,
+            game_start_time: self.game_start_time.clone(),
+            game_id: self.game_id.clone(),
+            market_type: self.sports_market_type.clone(),
+        })
+    }
+
+    /// ID of the first series referenced by the market's parent events.
+    pub fn series_id(&self) -> Option<String> {
+        self.events
+            .iter()
+            .flat_map(|event| event.series.iter())
+            .find_map(|series| series.id.clone())
+    }
+
+    /// Resolution text, source, and UMA request data for the market.
+    pub fn resolution_details(&self) -> ResolutionDetails {
+        ResolutionDetails {
+            condition_id: self.condition_id.clone(),
+            question: non_empty(self.question.clone()),
+            resolution_criteria: non_empty(self.description.clone()),
+            resolution_source: non_empty(self.resolution_source.clone()),
+            question_id: non_empty(self.question_id.clone()),
+            ancillary_data: non_empty(self.ancillary_data.clone()),
+            uma_resolution_status: non_empty(self.uma_resolution_status.clone()),
+            resolved_outcome: self.resolved_outcome(),
+        }
+    }
+
+    /// Winning outcome of a closed market: the outcome whose final price
+    /// settled at 1. `None` while open or if prices haven't settled.
+    pub fn resolved_outcome(&self) -> Option<String> {
+        if !self.closed {
+            return None;
+        }
+        let winner = self
+            .parsed_outcome_prices()
+            .iter()
+            .position(|price| *price == Some(Decimal::ONE))?;
+        self.normalized_outcomes().into_iter().nth(winner)
+    }
+
+    /// `outcomePrices` decoded, aligned with the outcomes; unparsable
+    /// entries are `None`.
+    fn parsed_outcome_prices(&self) -> Vec<Option<Decimal>> {
+        self.outcome_prices
+            .as_deref()
+            .and_then(|raw| serde_json::from_str::<Vec<String>>(raw).ok())
+            .unwrap_or_default()
+            .iter()
+            .map(|price| price.trim().parse::<Decimal>().ok())
+            .collect()
+    }
+
+    /// When the market closed, falling back to its scheduled end date.
+    pub fn resolved_at(&self) -> Option<DateTime<Utc>> {
+        self.closed_time
+            .as_deref()
+            .or(self.end_date.as_deref())
+            .and_then(parse_gamma_time)
+    }
+
     fn normalized_outcomes(&self) -> Vec<String> {
         let default_outcomes = vec!["Yes".to_string(), "No".to_string()];
-        if let Some(raw) = self.outcomes.as_ref() {
-            if let Ok(values) = serde_json::from_str::<Vec<String>>(raw) {
-                return values;
-            }
+        if let Some(raw) = self.outcomes.as_ref()
+            && let Ok(values) = serde_json::from_str::<Vec<String>>(raw)
+        {
+            return values;
         }
 
         default_outcomes
     }
 }
 
+/// Gamma timestamps are RFC 3339 (`endDate`) or `2024-11-06 05:32:11+00`
+/// (`closedTime`).
+fn parse_gamma_time(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .or_else(|_| DateTime::parse_from_str(raw, "%Y-%m-%d %H:%M:%S%#z"))
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
 impl From<GammaMarket> for Market {
     fn from(gamma: GammaMarket) -> Self {
         let token_ids = gamma.parse_token_ids();
         let outcomes = gamma.normalized_outcomes();
+        let series_id = gamma.series_id();
+        let resolved_outcome = gamma.resolved_outcome();
+        let prices = gamma.parsed_outcome_prices();
+        let sports = gamma.sports_info();
+        let minimum_tick_size = gamma.order_tick_size.unwrap_or(Decimal::ZERO);
 
         let tokens = [
             Token {
@@ -690,10 +1009,12 @@ impl From<GammaMarket> for Market {
                     .first()
                     .cloned()
                     .unwrap_or_else(|| "Yes".to_string()),
+                price: prices.first().copied().flatten(),
             },
             Token {
                 token_id: token_ids.get(1).cloned().unwrap_or_default(),
                 outcome: outcomes.get(1).cloned().unwrap_or_else(|| "No".to_string()),
+                price: prices.get(1).copied().flatten(),
             },
         ];
 
@@ -716,11 +1037,11 @@ impl From<GammaMarket> for Market {
             closed: gamma.closed,
             question_id: gamma.condition_id.clone(),
             minimum_order_size: gamma.order_min_size.unwrap_or(Decimal::ZERO),
-            minimum_tick_size: gamma.order_tick_size.unwrap_or(Decimal::ZERO),
+            minimum_tick_size,
             description: gamma.description.unwrap_or_default(),
             category: gamma.category.clone(),
             end_date_iso: gamma.end_date.clone(),
-            game_start_time: None,
+            game_start_time: gamma.game_start_time.clone(),
             question: gamma.question.unwrap_or_default(),
             market_slug: gamma.slug.clone(),
             seconds_delay: Decimal::ZERO,
@@ -746,6 +1067,13 @@ impl From<GammaMarket> for Market {
             volume_1yr_clob: gamma.volume_1yr_clob,
             volume_amm: gamma.volume_amm,
             volume_clob: gamma.volume_clob,
+            series_id,
+            resolved_outcome,
+            has_order_book: gamma.enable_order_book.unwrap_or(true),
+            comment_count: gamma.comment_count,
+            tick_bands: TickBand::tail_bands(minimum_tick_size),
+            sports,
+            resolution_source: non_empty(gamma.resolution_source),
         }
     }
 }
@@ -923,6 +1251,42 @@ impl OpenOrderParams {
     }
 }
 
+/// Narrows [`ClobClient::get_open_orders`](crate::client::ClobClient::get_open_orders)
+/// to one market and/or one outcome token.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpenOrderFilter {
+    /// Condition ID of the market.
+    pub market: Option<String>,
+    /// Outcome token ID.
+    pub asset_id: Option<String>,
+}
+
+impl OpenOrderFilter {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn market(mut self, condition_id: &str) -> Self {
+        self.market = Some(condition_id.to_string());
+        self
+    }
+
+    pub fn asset_id(mut self, token_id: &str) -> Self {
+        self.asset_id = Some(token_id.to_string());
+        self
+    }
+}
+
+impl From<OpenOrderFilter> for OpenOrderParams {
+    fn from(filter: OpenOrderFilter) -> Self {
+        Self {
+            id: None,
+            asset_id: filter.asset_id,
+            market: filter.market,
+        }
+    }
+}
+
 /// Parameters for querying trades
 #[derive(Debug, Clone)]
 pub struct TradeParams {
@@ -992,6 +1356,42 @@ pub struct OpenOrder {
     pub created_at: u64,
 }
 
+impl OpenOrder {
+    /// Size still resting on the book.
+    pub fn remaining_size(&self) -> Decimal {
+        (self.original_size - self.size_matched).max(Decimal::ZERO)
+    }
+}
+
+/// Result of a cancel request: the IDs that were cancelled and the reason
+/// each remaining one was not.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CancelResponse {
+    #[serde(default)]
+    pub canceled: Vec<String>,
+    #[serde(default)]
+    pub not_canceled: std::collections::HashMap<String, String>,
+}
+
+impl CancelResponse {
+    /// Per-order outcome: `Ok(())` if cancelled, otherwise the reason.
+    pub fn results(&self) -> std::collections::HashMap<String, std::result::Result<(), String>> {
+        self.canceled
+            .iter()
+            .map(|id| (id.clone(), Ok(())))
+            .chain(
+                self.not_canceled
+                    .iter()
+                    .map(|(id, reason)| (id.clone(), Err(reason.clone()))),
+            )
+            .collect()
+    }
+
+    pub fn is_canceled(&self, order_id: &str) -> bool {
+        self.canceled.iter().any(|id| id == order_id)
+    }
+}
+
 /// Balance allowance information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BalanceAllowance {
@@ -1104,6 +1504,56 @@ pub struct MidpointResponse {
     pub mid: Decimal,
 }
 
+/// Lookback window for [`crate::client::ClobClient::get_price_history`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceInterval {
+    OneHour,
+    SixHours,
+    OneDay,
+    OneWeek,
+    OneMonth,
+    Max,
+}
+
+impl PriceInterval {
+    /// Value sent as the `interval` query parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceInterval::OneHour => "1h",
+            PriceInterval::SixHours => "6h",
+            PriceInterval::OneDay => "1d",
+            PriceInterval::OneWeek => "1w",
+            PriceInterval::OneMonth => "1m",
+            PriceInterval::Max => "max",
+        }
+    }
+}
+
+/// One sample of a token's price history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PricePoint {
+    #[serde(
+        rename = "t",
+        deserialize_with = "crate::decode::deserializers::datetime_from_timestamp"
+    )]
+    pub timestamp: DateTime<Utc>,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+}
+
+/// Liquidity rewards earned on one market for a day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RewardEarning {
+    #[serde(alias = "condition_id")]
+    pub market: String,
+    #[serde(alias = "asset_address")]
+    pub asset_id: String,
+    #[serde(deserialize_with = "crate::decode::deserializers::number_from_string")]
+    pub earnings: Decimal,
+    #[serde(deserialize_with = "crate::decode::deserializers::number_from_string")]
+    pub asset_rate: Decimal,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PriceResponse {
     #[serde(with = "rust_decimal::serde::str")]
@@ -1133,17 +1583,36 @@ pub struct BookParams {
     pub side: Side,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+/// REST order book snapshot.
+///
+/// The CLOB answers unknown tokens with an empty book that may leave out the
+/// market, hash, timestamp, and levels; those default to empty/zero.
+#[derive(Debug, Default, Deserialize, Clone)]
 pub struct OrderBookSummary {
+    #[serde(default)]
     pub market: String,
+    #[serde(default)]
     pub asset_id: String,
+    #[serde(default)]
     pub hash: String,
-    #[serde(deserialize_with = "crate::decode::deserializers::number_from_string")]
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::number_from_string"
+    )]
     pub timestamp: u64,
+    #[serde(default)]
     pub bids: Vec<OrderSummary>,
+    #[serde(default)]
     pub asks: Vec<OrderSummary>,
 }
 
+impl OrderBookSummary {
+    /// Whether the book has no resting orders on either side.
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct OrderSummary {
     #[serde(with = "rust_decimal::serde::str")]
@@ -1152,6 +1621,15 @@ pub struct OrderSummary {
     pub size: Decimal,
 }
 
+impl From<BookLevel> for OrderSummary {
+    fn from(level: BookLevel) -> Self {
+        Self {
+            price: level.price,
+            size: level.size,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MarketsResponse {
     #[serde(with = "rust_decimal::serde::str")]
@@ -1162,6 +1640,82 @@ pub struct MarketsResponse {
     pub data: Vec<Market>,
 }
 
+/// What changed between two listings of markets, keyed by condition ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarketsDiff {
+    /// Markets present now but not before.
+    pub added: Vec<String>,
+    /// Markets present before but gone now.
+    pub removed: Vec<String>,
+    /// Markets in both listings where an outcome price moved by more than
+    /// the tolerance.
+    pub price_moved: Vec<String>,
+}
+
+impl MarketsDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.price_moved.is_empty()
+    }
+}
+
+impl MarketsResponse {
+    /// Compare with an earlier fetch; any outcome price change counts as a
+    /// move. See [`MarketsResponse::diff_with_tolerance`].
+    pub fn diff(&self, previous: &MarketsResponse) -> MarketsDiff {
+        self.diff_with_tolerance(previous, Decimal::ZERO)
+    }
+
+    /// Compare with an earlier fetch. A market counts as moved when any of
+    /// its tokens has a price on both sides that differs by more than
+    /// `tolerance`; prices missing on either side are ignored. IDs are
+    /// listed in page order.
+    pub fn diff_with_tolerance(
+        &self,
+        previous: &MarketsResponse,
+        tolerance: Decimal,
+    ) -> MarketsDiff {
+        let before: std::collections::HashMap<&str, &Market> = previous
+            .data
+            .iter()
+            .map(|market| (market.condition_id.as_str(), market))
+            .collect();
+        let now: std::collections::HashSet<&str> = self
+            .data
+            .iter()
+            .map(|market| market.condition_id.as_str())
+            .collect();
+
+        let mut diff = MarketsDiff::default();
+        for market in &self.data {
+            match before.get(market.condition_id.as_str()) {
+                None => diff.added.push(market.condition_id.clone()),
+                Some(old) if prices_moved(old, market, tolerance) => {
+                    diff.price_moved.push(market.condition_id.clone())
+                }
+                Some(_) => {}
+            }
+        }
+        diff.removed = previous
+            .data
+            .iter()
+            .filter(|market| !now.contains(market.condition_id.as_str()))
+            .map(|market| market.condition_id.clone())
+            .collect();
+        diff
+    }
+}
+
+fn prices_moved(old: &Market, new: &Market, tolerance: Decimal) -> bool {
+    new.tokens.iter().any(|token| {
+        let previous = old
+            .tokens
+            .iter()
+            .find(|candidate| candidate.token_id == token.token_id)
+            .and_then(|candidate| candidate.price);
+        matches!((previous, token.price), (Some(a), Some(b)) if (a - b).abs() > tolerance)
+    })
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SimplifiedMarketsResponse {
     #[serde(with = "rust_decimal::serde::str")]
@@ -1186,7 +1740,7 @@ pub struct SimplifiedMarket {
 }
 
 /// Common query parameters for Gamma API list endpoints
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct GammaListParams {
     pub limit: Option<u32>,
     pub offset: Option<u32>,
@@ -1194,7 +1748,7 @@ pub struct GammaListParams {
     pub tag_id: Option<String>,
     pub exclude_tag_id: Option<String>,
     pub related_tags: Option<String>,
-    pub order: Option<String>,
+    pub order: Option<OrderBy>,
     pub ascending: Option<bool>,
     pub liquidity_num_min: Option<Decimal>,
     pub liquidity_num_max: Option<Decimal>,
@@ -1216,6 +1770,115 @@ pub struct GammaListParams {
     pub rewards_min_size: Option<Decimal>,
     pub question_ids: Option<Vec<String>>,
     pub include_tag: Option<bool>,
+    /// Gamma field names (e.g. `question`, `liquidityNum`) to keep on each
+    /// returned market. See [`GammaListParams::fields`].
+    pub fields: Option<Vec<String>>,
+    /// Drop markets without a CLOB order book. Applied client-side; see
+    /// [`GammaListParams::order_book_only`].
+    pub order_book_only: Option<bool>,
+}
+
+/// Sort key for Gamma list endpoints, sent as the `order` query parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderBy {
+    Volume,
+    Liquidity,
+    EndDate,
+    StartDate,
+    CreatedAt,
+    /// Any other Gamma field name, passed through verbatim.
+    Raw(String),
+}
+
+impl OrderBy {
+    /// Gamma field name for this key.
+    pub fn as_str(&self) -> &str {
+        match self {
+            OrderBy::Volume => "volumeNum",
+            OrderBy::Liquidity => "liquidityNum",
+            OrderBy::EndDate => "endDate",
+            OrderBy::StartDate => "startDate",
+            OrderBy::CreatedAt => "createdAt",
+            OrderBy::Raw(field) => field,
+        }
+    }
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for OrderBy {
+    /// Parse a Gamma field name; unknown names become [`OrderBy::Raw`].
+    fn from(field: &str) -> Self {
+        match field {
+            "volumeNum" => OrderBy::Volume,
+            "liquidityNum" => OrderBy::Liquidity,
+            "endDate" => OrderBy::EndDate,
+            "startDate" => OrderBy::StartDate,
+            "createdAt" => OrderBy::CreatedAt,
+            other => OrderBy::Raw(other.to_string()),
+        }
+    }
+}
+
+/// Named, storable set of Gamma market filters, e.g. "high-liquidity
+/// politics". Load one from config and turn it into a query with
+/// [`GammaListParams::from_profile`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilterProfile {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_tag_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related_tags: Option<String>,
+    /// Gamma field to sort by, e.g. `volumeNum`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ascending: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liquidity_num_min: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liquidity_num_max: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_num_min: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_num_max: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewards_min_size: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sports_market_types: Option<Vec<String>>,
+}
+
+impl From<&FilterProfile> for GammaListParams {
+    fn from(profile: &FilterProfile) -> Self {
+        GammaListParams {
+            limit: profile.limit,
+            closed: profile.closed,
+            tag_id: profile.tag_id.clone(),
+            exclude_tag_id: profile.exclude_tag_id.clone(),
+            related_tags: profile.related_tags.clone(),
+            order: profile.order.as_deref().map(OrderBy::from),
+            ascending: profile.ascending,
+            liquidity_num_min: profile.liquidity_num_min,
+            liquidity_num_max: profile.liquidity_num_max,
+            volume_num_min: profile.volume_num_min,
+            volume_num_max: profile.volume_num_max,
+            rewards_min_size: profile.rewards_min_size,
+            sports_market_types: profile.sports_market_types.clone(),
+            ..GammaListParams::default()
+        }
+    }
 }
 
 impl GammaListParams {
@@ -1223,6 +1886,70 @@ impl GammaListParams {
         Self::default()
     }
 
+    /// Finish a builder chain. The setters already return the params, so this
+    /// only exists to make the end of the chain explicit.
+    pub fn build(self) -> Self {
+        self
+    }
+
+    // Builder methods for pagination and core filters
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = Some(closed);
+        self
+    }
+
+    pub fn tag_id(mut self, tag_id: impl Into<String>) -> Self {
+        self.tag_id = Some(tag_id.into());
+        self
+    }
+
+    pub fn exclude_tag_id(mut self, tag_id: impl Into<String>) -> Self {
+        self.exclude_tag_id = Some(tag_id.into());
+        self
+    }
+
+    pub fn related_tags(mut self, related_tags: impl Into<String>) -> Self {
+        self.related_tags = Some(related_tags.into());
+        self
+    }
+
+    pub fn liquidity_num_min(mut self, min: Decimal) -> Self {
+        self.liquidity_num_min = Some(min);
+        self
+    }
+
+    pub fn start_date_min(mut self, date: DateTime<Utc>) -> Self {
+        self.start_date_min = Some(date);
+        self
+    }
+
+    pub fn end_date_max(mut self, date: DateTime<Utc>) -> Self {
+        self.end_date_max = Some(date);
+        self
+    }
+
+    /// Params carrying every filter set on `profile`.
+    pub fn from_profile(profile: &FilterProfile) -> Self {
+        Self::from(profile)
+    }
+
+    /// Sort results by `order`, ascending or descending.
+    pub fn order_by(mut self, order: OrderBy, ascending: bool) -> Self {
+        self.order = Some(order);
+        self.ascending = Some(ascending);
+        self
+    }
+
     // Builder methods for new array filter parameters
     pub fn id(mut self, ids: Vec<i64>) -> Self {
         self.id = Some(ids);
@@ -1259,6 +1986,28 @@ impl GammaListParams {
         self
     }
 
+    /// Only populate the given Gamma fields on returned markets; everything
+    /// else comes back as `None`/default. `conditionId`, `slug`, `active`
+    /// and `closed` are always kept.
+    ///
+    /// The list is sent as the `fields` query parameter, but Gamma does not
+    /// currently honor it, so the projection is applied client-side after
+    /// the full payload has been downloaded. It trims memory, not bandwidth.
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Keep only markets with a CLOB order book ([`Market::has_order_book`]).
+    ///
+    /// Gamma has no such filter, so markets are dropped after each page is
+    /// fetched: pages may come back shorter than `limit`, while pagination
+    /// still advances by the full page.
+    pub fn order_book_only(mut self, only: bool) -> Self {
+        self.order_book_only = Some(only);
+        self
+    }
+
     // Builder methods for new numeric parameters
     pub fn liquidity_num_max(mut self, max: Decimal) -> Self {
         self.liquidity_num_max = Some(max);
@@ -1349,7 +2098,7 @@ impl GammaListParams {
             params.push(("related_tags", related_tags.clone()));
         }
         if let Some(order) = &self.order {
-            params.push(("order", order.clone()));
+            params.push(("order", order.as_str().to_string()));
         }
         if let Some(uma_resolution_status) = &self.uma_resolution_status {
             params.push(("uma_resolution_status", uma_resolution_status.clone()));
@@ -1390,86 +2139,67 @@ impl GammaListParams {
         }
 
         // Array filters - join with ","
-        if let Some(id) = &self.id {
-            if !id.is_empty() {
-                params.push((
-                    "id",
-                    id.iter()
-                        .map(|i| i.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ));
-            }
+        if let Some(id) = &self.id
+            && !id.is_empty()
+        {
+            params.push((
+                "id",
+                id.iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ));
         }
-        if let Some(slug) = &self.slug {
-            if !slug.is_empty() {
-                params.push(("slug", slug.join(",")));
-            }
+        if let Some(slug) = &self.slug
+            && !slug.is_empty()
+        {
+            params.push(("slug", slug.join(",")));
         }
-        if let Some(clob_token_ids) = &self.clob_token_ids {
-            if !clob_token_ids.is_empty() {
-                params.push(("clob_token_ids", clob_token_ids.join(",")));
-            }
+        if let Some(clob_token_ids) = &self.clob_token_ids
+            && !clob_token_ids.is_empty()
+        {
+            params.push(("clob_token_ids", clob_token_ids.join(",")));
         }
-        if let Some(condition_ids) = &self.condition_ids {
-            if !condition_ids.is_empty() {
-                params.push(("condition_ids", condition_ids.join(",")));
-            }
+        if let Some(condition_ids) = &self.condition_ids
+            && !condition_ids.is_empty()
+        {
+            params.push(("condition_ids", condition_ids.join(",")));
         }
-        if let Some(market_maker_address) = &self.market_maker_address {
-            if !market_maker_address.is_empty() {
-                params.push(("market_maker_address", market_maker_address.join(",")));
-            }
+        if let Some(market_maker_address) = &self.market_maker_address
+            && !market_maker_address.is_empty()
+        {
+            params.push(("market_maker_address", market_maker_address.join(",")));
         }
-        if let Some(sports_market_types) = &self.sports_market_types {
-            if !sports_market_types.is_empty() {
-                params.push(("sports_market_types", sports_market_types.join(",")));
-            }
+        if let Some(sports_market_types) = &self.sports_market_types
+            && !sports_market_types.is_empty()
+        {
+            params.push(("sports_market_types", sports_market_types.join(",")));
         }
-        if let Some(question_ids) = &self.question_ids {
-            if !question_ids.is_empty() {
-                params.push(("question_ids", question_ids.join(",")));
-            }
+        if let Some(question_ids) = &self.question_ids
+            && !question_ids.is_empty()
+        {
+            params.push(("question_ids", question_ids.join(",")));
+        }
+        if let Some(fields) = &self.fields
+            && !fields.is_empty()
+        {
+            params.push(("fields", fields.join(",")));
         }
 
         params
     }
 }
 
-/// Parameters supported by the Data API `/positions` endpoint.
-#[derive(Debug, Clone, Default)]
-pub struct DataApiPositionsParams {
-    /// Minimum position size to include in the response.
-    pub size_threshold: Option<u32>,
-    /// Maximum number of rows to return.
-    pub limit: Option<u32>,
-    /// Field to sort by.
-    pub sort_by: Option<DataApiSortBy>,
-    /// Direction to sort (`ASC` or `DESC`).
-    pub sort_direction: Option<DataApiSortDirection>,
-}
-
-impl DataApiPositionsParams {
-    pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
-        let size_threshold = self.size_threshold.unwrap_or(1);
-        let limit = self.limit.unwrap_or(100);
-        let sort_by = self.sort_by.unwrap_or_default();
-        let sort_direction = self.sort_direction.unwrap_or_default();
-
-        vec![
-            ("sizeThreshold", size_threshold.to_string()),
-            ("limit", limit.to_string()),
-            ("sortBy", sort_by.as_str().to_string()),
-            ("sortDirection", sort_direction.as_str().to_string()),
-        ]
-    }
-}
+/// Parameters supported by the Data API `/positions` endpoint; see
+/// [`PositionListParams`].
+pub type DataApiPositionsParams = PositionListParams;
 
 /// Fields allowed for sorting the `/positions` response.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum DataApiSortBy {
     Current,
     Initial,
+    #[default]
     Tokens,
     CashPnl,
     PercentPnl,
@@ -1495,16 +2225,11 @@ impl DataApiSortBy {
     }
 }
 
-impl Default for DataApiSortBy {
-    fn default() -> Self {
-        DataApiSortBy::Tokens
-    }
-}
-
 /// Sort direction for the Data API `/positions` response.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum DataApiSortDirection {
     Asc,
+    #[default]
     Desc,
 }
 
@@ -1517,40 +2242,49 @@ impl DataApiSortDirection {
     }
 }
 
-impl Default for DataApiSortDirection {
-    fn default() -> Self {
-        DataApiSortDirection::Desc
-    }
+/// A single row from the `/positions` endpoint; see [`Position`].
+pub type DataPosition = Position;
+
+/// Response returned by the `/value` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataPositionValue {
+    pub user: String,
+    pub value: Decimal,
 }
 
-/// A single row from the `/positions` endpoint.
+/// A wallet's holding of one outcome token, as returned by
+/// [`crate::api::DataClient::get_positions`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DataPosition {
-    #[serde(rename = "proxyWallet")]
+pub struct Position {
+    #[serde(rename = "proxyWallet", default)]
     pub proxy_wallet: String,
-    pub asset: String,
+    #[serde(rename = "asset")]
+    pub token_id: String,
     #[serde(rename = "conditionId")]
     pub condition_id: String,
     pub size: Decimal,
     #[serde(rename = "avgPrice")]
     pub avg_price: Decimal,
-    #[serde(rename = "initialValue")]
+    #[serde(rename = "initialValue", default)]
     pub initial_value: Decimal,
-    #[serde(rename = "currentValue")]
+    #[serde(rename = "curPrice", default)]
+    pub cur_price: Decimal,
+    #[serde(rename = "currentValue", default)]
     pub current_value: Decimal,
-    #[serde(rename = "cashPnl")]
-    pub cash_pnl: Decimal,
-    #[serde(rename = "percentPnl")]
-    pub percent_pnl: Decimal,
-    #[serde(rename = "totalBought")]
+    #[serde(rename = "totalBought", default)]
     pub total_bought: Decimal,
-    #[serde(rename = "realizedPnl")]
+    #[serde(rename = "realizedPnl", default)]
     pub realized_pnl: Decimal,
-    #[serde(rename = "percentRealizedPnl")]
+    #[serde(rename = "percentRealizedPnl", default)]
     pub percent_realized_pnl: Decimal,
-    #[serde(rename = "curPrice")]
-    pub cur_price: Decimal,
+    /// Mark-to-market PnL on the open size (`cashPnl` in the Data API).
+    #[serde(rename = "cashPnl", default)]
+    pub unrealized_pnl: Decimal,
+    #[serde(rename = "percentPnl", default)]
+    pub percent_pnl: Decimal,
+    #[serde(default)]
     pub redeemable: bool,
+    #[serde(default)]
     pub mergeable: bool,
     pub title: Option<String>,
     pub slug: Option<String>,
@@ -1572,11 +2306,211 @@ pub struct DataPosition {
     pub negative_risk: Option<bool>,
 }
 
-/// Response returned by the `/value` endpoint.
+/// A fill from the Data API `/trades` endpoint.
+///
+/// `side` is from `proxy_wallet`'s point of view: the taker's unless the
+/// query asked for maker fills with [`TradeQuery::taker_only`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DataPositionValue {
-    pub user: String,
-    pub value: Decimal,
+pub struct Trade {
+    #[serde(rename = "proxyWallet")]
+    pub proxy_wallet: String,
+    pub side: Side,
+    #[serde(rename = "asset")]
+    pub token_id: String,
+    #[serde(rename = "conditionId")]
+    pub condition_id: String,
+    pub size: Decimal,
+    pub price: Decimal,
+    #[serde(deserialize_with = "crate::decode::deserializers::datetime_from_timestamp")]
+    pub timestamp: DateTime<Utc>,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    #[serde(rename = "eventSlug")]
+    pub event_slug: Option<String>,
+    pub outcome: Option<String>,
+    #[serde(rename = "outcomeIndex")]
+    pub outcome_index: Option<u32>,
+    #[serde(rename = "transactionHash")]
+    pub transaction_hash: Option<String>,
+}
+
+/// Filters for [`crate::api::DataClient::get_trades`].
+#[derive(Debug, Clone, Default)]
+pub struct TradeQuery {
+    pub user: Option<String>,
+    /// Condition IDs to restrict the results to.
+    pub market: Option<Vec<String>>,
+    pub side: Option<Side>,
+    /// Only trades at or after this time. Applied client-side; the endpoint
+    /// has no time filter.
+    pub start: Option<DateTime<Utc>>,
+    /// Only trades at or before this time. Applied client-side.
+    pub end: Option<DateTime<Utc>>,
+    /// `true` (the server default) returns only the taker side of each
+    /// fill; `false` also returns fills where the user was the maker.
+    pub taker_only: Option<bool>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+impl TradeQuery {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn user(mut self, user: &str) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+
+    pub fn market(mut self, condition_ids: Vec<String>) -> Self {
+        self.market = Some(condition_ids);
+        self
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    pub fn time_range(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.start = Some(start);
+        self.end = Some(end);
+        self
+    }
+
+    pub fn taker_only(mut self, taker_only: bool) -> Self {
+        self.taker_only = Some(taker_only);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = Vec::with_capacity(6);
+
+        if let Some(user) = &self.user {
+            params.push(("user", user.clone()));
+        }
+        if let Some(market) = &self.market
+            && !market.is_empty()
+        {
+            params.push(("market", market.join(",")));
+        }
+        if let Some(side) = self.side {
+            params.push(("side", side.as_str().to_string()));
+        }
+        if let Some(taker_only) = self.taker_only {
+            params.push(("takerOnly", taker_only.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset", offset.to_string()));
+        }
+
+        params
+    }
+
+    /// Whether `trade` falls inside the query's time range.
+    pub fn in_range(&self, trade: &Trade) -> bool {
+        self.start.is_none_or(|start| trade.timestamp >= start)
+            && self.end.is_none_or(|end| trade.timestamp <= end)
+    }
+}
+
+/// Filters for [`crate::api::DataClient::get_positions_with_params`].
+/// Unset fields are left to the server's defaults.
+#[derive(Debug, Clone, Default)]
+pub struct PositionListParams {
+    /// Condition IDs to restrict the results to.
+    pub market: Option<Vec<String>>,
+    pub redeemable: Option<bool>,
+    /// Minimum position size to include in the response.
+    pub size_threshold: Option<u32>,
+    /// Maximum number of rows to return.
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Field to sort by.
+    pub sort_by: Option<DataApiSortBy>,
+    /// Direction to sort (`ASC` or `DESC`).
+    pub sort_direction: Option<DataApiSortDirection>,
+}
+
+impl PositionListParams {
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    pub fn market(mut self, condition_ids: Vec<String>) -> Self {
+        self.market = Some(condition_ids);
+        self
+    }
+
+    pub fn redeemable(mut self, redeemable: bool) -> Self {
+        self.redeemable = Some(redeemable);
+        self
+    }
+
+    pub fn size_threshold(mut self, size_threshold: u32) -> Self {
+        self.size_threshold = Some(size_threshold);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort(mut self, sort_by: DataApiSortBy, direction: DataApiSortDirection) -> Self {
+        self.sort_by = Some(sort_by);
+        self.sort_direction = Some(direction);
+        self
+    }
+
+    pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::with_capacity(7);
+
+        if let Some(market) = &self.market
+            && !market.is_empty()
+        {
+            params.push(("market", market.join(",")));
+        }
+        if let Some(redeemable) = self.redeemable {
+            params.push(("redeemable", redeemable.to_string()));
+        }
+        if let Some(size_threshold) = self.size_threshold {
+            params.push(("sizeThreshold", size_threshold.to_string()));
+        }
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset", offset.to_string()));
+        }
+        if let Some(sort_by) = self.sort_by {
+            params.push(("sortBy", sort_by.as_str().to_string()));
+        }
+        if let Some(sort_direction) = self.sort_direction {
+            params.push(("sortDirection", sort_direction.as_str().to_string()));
+        }
+
+        params
+    }
 }
 
 /// Gamma API tag
@@ -1587,6 +2521,15 @@ pub struct GammaTag {
     pub slug: Option<String>,
 }
 
+/// Mixed results from Gamma's public search
+#[derive(Debug, Clone, Default)]
+pub struct SearchResults {
+    pub markets: Vec<GammaMarket>,
+    pub events: Vec<GammaEvent>,
+    /// Raw profile objects, when the response includes any
+    pub profiles: Option<Vec<serde_json::Value>>,
+}
+
 /// Sports response from Gamma API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SportsResponse {
@@ -1601,13 +2544,31 @@ pub struct GammaEvent {
     pub slug: String,
     pub name: Option<String>,
     pub description: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::de_optional_bool_flexible"
+    )]
     pub active: Option<bool>,
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::de_optional_bool_flexible"
+    )]
     pub closed: Option<bool>,
     pub start_date_iso: Option<String>,
     pub end_date_iso: Option<String>,
     pub sport: Option<String>,
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::lenient_vec"
+    )]
     pub tags: Vec<Tag>,
+    /// Recurring series this event belongs to. Gamma sends a list; only the
+    /// first entry is kept.
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::lenient_first"
+    )]
+    pub series: Option<GammaSeries>,
     #[serde(default)]
     pub markets: Vec<GammaEventMarket>,
     #[serde(default)]
@@ -1615,6 +2576,39 @@ pub struct GammaEvent {
     pub metadata: serde_json::Value,
 }
 
+impl GammaEvent {
+    /// Slugs of the event's tags, in the order Gamma lists them.
+    pub fn tag_slugs(&self) -> Vec<&str> {
+        self.tags
+            .iter()
+            .filter_map(|tag| tag.slug.as_deref())
+            .collect()
+    }
+
+    /// The first tag Gamma lists for the event.
+    pub fn primary_tag(&self) -> Option<&Tag> {
+        self.tags.first()
+    }
+}
+
+/// Series metadata embedded in a Gamma event, or in the parent event refs
+/// of a Gamma market.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GammaSeries {
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_string_from_string_or_number"
+    )]
+    pub id: Option<String>,
+    pub slug: Option<String>,
+    pub title: Option<String>,
+    pub ticker: Option<String>,
+    pub recurrence: Option<String>,
+    #[serde(default)]
+    #[serde(flatten)]
+    pub metadata: serde_json::Value,
+}
+
 /// Lightweight market info returned inside a Gamma event listing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GammaEventMarket {
@@ -1633,8 +2627,13 @@ pub struct GammaEventMarket {
 /// Tag metadata for Gamma API filtering
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tag {
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_string_from_string_or_number"
+    )]
     pub id: Option<String>,
     pub slug: Option<String>,
+    #[serde(alias = "label")]
     pub name: Option<String>,
     pub description: Option<String>,
     #[serde(default)]
@@ -1642,6 +2641,32 @@ pub struct Tag {
     pub metadata: serde_json::Value,
 }
 
+/// Engagement counters for a Gamma market. Counters Gamma doesn't report
+/// for a market are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SocialStats {
+    #[serde(
+        rename = "commentCount",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
+    pub comment_count: Option<u64>,
+    #[serde(
+        rename = "holderCount",
+        alias = "holdersCount",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
+    pub holder_count: Option<u64>,
+    #[serde(
+        rename = "watchCount",
+        alias = "watchlistCount",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
+    pub watch_count: Option<u64>,
+}
+
 /// Sports metadata for Gamma API filtering
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sport {
@@ -1657,6 +2682,27 @@ pub struct Sport {
     pub metadata: serde_json::Value,
 }
 
+impl Sport {
+    /// Series ID from the `series` field of the `/sports` listing.
+    pub fn series_id(&self) -> Option<String> {
+        match self.metadata.get("series")? {
+            serde_json::Value::String(id) => Some(id.clone()),
+            serde_json::Value::Number(id) => Some(id.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Display label: `name`, or the short `sport` code (e.g. `nba`).
+    pub fn label(&self) -> Option<String> {
+        self.name.clone().or_else(|| {
+            self.metadata
+                .get("sport")
+                .and_then(|sport| sport.as_str())
+                .map(str::to_string)
+        })
+    }
+}
+
 /// Minimal Gamma market representation used for discovery
 #[derive(Debug, Clone, Deserialize)]
 pub struct GammaMarket {
@@ -1666,7 +2712,9 @@ pub struct GammaMarket {
     pub question: Option<String>,
     pub description: Option<String>,
     pub category: Option<String>,
+    #[serde(deserialize_with = "crate::decode::deserializers::de_bool_flexible")]
     pub active: bool,
+    #[serde(deserialize_with = "crate::decode::deserializers::de_bool_flexible")]
     pub closed: bool,
     pub outcomes: Option<String>,
     #[serde(rename = "clobTokenIds")]
@@ -1674,48 +2722,219 @@ pub struct GammaMarket {
     pub icon: Option<String>,
     #[serde(rename = "endDate")]
     pub end_date: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_string_from_string_or_number"
+    )]
     pub liquidity: Option<String>,
-    #[serde(rename = "liquidityNum")]
+    #[serde(
+        rename = "liquidityNum",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub liquidity_num: Option<Decimal>,
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_string_from_string_or_number"
+    )]
     pub volume: Option<String>,
-    #[serde(rename = "volumeNum")]
+    #[serde(
+        rename = "volumeNum",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_num: Option<Decimal>,
-    #[serde(rename = "volume24hr")]
+    #[serde(
+        rename = "volume24hr",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_24hr: Option<Decimal>,
-    #[serde(rename = "volume1wk")]
+    #[serde(
+        rename = "volume1wk",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1wk: Option<Decimal>,
-    #[serde(rename = "volume1mo")]
+    #[serde(
+        rename = "volume1mo",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1mo: Option<Decimal>,
-    #[serde(rename = "volume1yr")]
+    #[serde(
+        rename = "volume1yr",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1yr: Option<Decimal>,
-    #[serde(rename = "volume24hrAmm")]
+    #[serde(
+        rename = "volume24hrAmm",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_24hr_amm: Option<Decimal>,
-    #[serde(rename = "volume1wkAmm")]
+    #[serde(
+        rename = "volume1wkAmm",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1wk_amm: Option<Decimal>,
-    #[serde(rename = "volume1moAmm")]
+    #[serde(
+        rename = "volume1moAmm",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1mo_amm: Option<Decimal>,
-    #[serde(rename = "volume1yrAmm")]
+    #[serde(
+        rename = "volume1yrAmm",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1yr_amm: Option<Decimal>,
-    #[serde(rename = "volume24hrClob")]
+    #[serde(
+        rename = "volume24hrClob",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_24hr_clob: Option<Decimal>,
-    #[serde(rename = "volume1wkClob")]
+    #[serde(
+        rename = "volume1wkClob",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1wk_clob: Option<Decimal>,
-    #[serde(rename = "volume1moClob")]
+    #[serde(
+        rename = "volume1moClob",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1mo_clob: Option<Decimal>,
-    #[serde(rename = "volume1yrClob")]
+    #[serde(
+        rename = "volume1yrClob",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_1yr_clob: Option<Decimal>,
-    #[serde(rename = "volumeAmm")]
+    #[serde(
+        rename = "volumeAmm",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_amm: Option<Decimal>,
-    #[serde(rename = "volumeClob")]
+    #[serde(
+        rename = "volumeClob",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub volume_clob: Option<Decimal>,
-    #[serde(rename = "liquidityAmm")]
+    #[serde(
+        rename = "liquidityAmm",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub liquidity_amm: Option<Decimal>,
-    #[serde(rename = "liquidityClob")]
+    #[serde(
+        rename = "liquidityClob",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub liquidity_clob: Option<Decimal>,
-    #[serde(rename = "orderMinSize")]
+    #[serde(
+        rename = "orderMinSize",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub order_min_size: Option<Decimal>,
-    #[serde(rename = "orderPriceMinTickSize")]
+    #[serde(
+        rename = "orderPriceMinTickSize",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
     pub order_tick_size: Option<Decimal>,
+    #[serde(
+        rename = "negRisk",
+        default,
+        deserialize_with = "crate::decode::deserializers::de_optional_bool_flexible"
+    )]
+    pub neg_risk: Option<bool>,
+    #[serde(
+        rename = "enableOrderBook",
+        default,
+        deserialize_with = "crate::decode::deserializers::de_optional_bool_flexible"
+    )]
+    pub enable_order_book: Option<bool>,
+    #[serde(rename = "negRiskMarketID", default)]
+    pub neg_risk_market_id: Option<String>,
+    /// JSON-encoded array of outcome prices, aligned with `outcomes`.
+    #[serde(rename = "outcomePrices", default)]
+    pub outcome_prices: Option<String>,
+    #[serde(rename = "closedTime", default)]
+    pub closed_time: Option<String>,
+    #[serde(rename = "umaResolutionStatus", default)]
+    pub uma_resolution_status: Option<String>,
+    #[serde(
+        rename = "commentCount",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_number_from_string"
+    )]
+    pub comment_count: Option<u64>,
+    #[serde(
+        rename = "gameId",
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_string_from_string_or_number"
+    )]
+    pub game_id: Option<String>,
+    #[serde(rename = "gameStartTime", default)]
+    pub game_start_time: Option<String>,
+    #[serde(rename = "sportsMarketType", default)]
+    pub sports_market_type: Option<String>,
+    #[serde(rename = "resolutionSource", default)]
+    pub resolution_source: Option<String>,
+    /// UMA question ID the resolution request is filed under.
+    #[serde(rename = "questionID", default)]
+    pub question_id: Option<String>,
+    /// UMA ancillary data, when Gamma includes it.
+    #[serde(rename = "ancillaryData", default)]
+    pub ancillary_data: Option<String>,
+    #[serde(default)]
+    pub events: Vec<GammaMarketEventRef>,
+}
+
+/// How a market is resolved, from [`GammaMarket::resolution_details`].
+/// Fields Gamma leaves out or sends empty are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolutionDetails {
+    pub condition_id: String,
+    pub question: Option<String>,
+    /// Rules the market resolves by; Gamma carries these in the description.
+    pub resolution_criteria: Option<String>,
+    pub resolution_source: Option<String>,
+    pub question_id: Option<String>,
+    pub ancillary_data: Option<String>,
+    pub uma_resolution_status: Option<String>,
+    pub resolved_outcome: Option<String>,
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.trim().is_empty())
+}
+
+/// Parent event reference embedded in a Gamma market.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GammaMarketEventRef {
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::optional_string_from_string_or_number"
+    )]
+    pub id: Option<String>,
+    /// Series the event recurs in; only the first entry Gamma lists is kept,
+    /// as for [`GammaEvent::series`].
+    #[serde(
+        default,
+        deserialize_with = "crate::decode::deserializers::lenient_first"
+    )]
+    pub series: Option<GammaSeries>,
 }
 
 /// Rewards structure for markets
@@ -1783,3 +3002,498 @@ mod batch_order_response_tests {
         assert!(!response.has_error());
     }
 }
+
+#[cfg(test)]
+mod gamma_market_tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn parse_with(extra: &str) -> GammaMarket {
+        let json = format!(
+            r#"{{"conditionId": "0xabc", "slug": "s", "active": true, "closed": false{}}}"#,
+            extra
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_optional_numeric_fields_tolerate_all_shapes() {
+        let missing = parse_with("");
+        assert_eq!(missing.liquidity, None);
+        assert_eq!(missing.liquidity_num, None);
+
+        let null = parse_with(r#", "liquidity": null, "liquidityNum": null"#);
+        assert_eq!(null.liquidity, None);
+        assert_eq!(null.liquidity_num, None);
+
+        let empty = parse_with(r#", "liquidity": "", "liquidityNum": """#);
+        assert_eq!(empty.liquidity, None);
+        assert_eq!(empty.liquidity_num, None);
+
+        let number = parse_with(r#", "liquidity": 0, "liquidityNum": 1250.5"#);
+        assert_eq!(number.liquidity.as_deref(), Some("0"));
+        assert_eq!(number.liquidity_num, Some(dec!(1250.5)));
+
+        let string = parse_with(r#", "liquidity": "0", "liquidityNum": "1250.5""#);
+        assert_eq!(string.liquidity.as_deref(), Some("0"));
+        assert_eq!(string.liquidity_num, Some(dec!(1250.5)));
+    }
+
+    #[test]
+    fn test_series_id_from_parent_event() {
+        let with_series =
+            parse_with(r#", "events": [{"id": "9", "series": [{"id": 42, "slug": "weekly"}]}]"#);
+        assert_eq!(with_series.series_id().as_deref(), Some("42"));
+        assert_eq!(Market::from(with_series).series_id.as_deref(), Some("42"));
+
+        let bare = parse_with(r#", "events": [{"id": "9", "series": {"id": "43"}}]"#);
+        assert_eq!(bare.series_id().as_deref(), Some("43"));
+
+        let standalone = parse_with(r#", "events": [{"id": "9", "series": null}]"#);
+        assert_eq!(Market::from(standalone).series_id, None);
+    }
+
+    #[test]
+    fn test_comment_count_carries_into_market() {
+        let commented = parse_with(r#", "commentCount": 17"#);
+        assert_eq!(Market::from(commented).comment_count, Some(17));
+        assert_eq!(Market::from(parse_with("")).comment_count, None);
+    }
+
+    #[test]
+    fn test_bool_fields_accept_strings_and_numbers() {
+        for (active, closed) in [
+            ("true", "false"),
+            (r#""true""#, r#""false""#),
+            (r#""TRUE""#, r#""False""#),
+            ("1", "0"),
+            (r#""1""#, r#""0""#),
+        ] {
+            let json = format!(
+                r#"{{"conditionId": "0xabc", "slug": "s", "active": {active}, "closed": {closed}, "negRisk": {active}}}"#
+            );
+            let market: GammaMarket = serde_json::from_str(&json).unwrap();
+            assert!(market.active, "active = {active}");
+            assert!(!market.closed, "closed = {closed}");
+            assert_eq!(market.neg_risk, Some(true));
+        }
+
+        let bad = r#"{"conditionId": "0xabc", "slug": "s", "active": "yes", "closed": false}"#;
+        assert!(serde_json::from_str::<GammaMarket>(bad).is_err());
+        let two = r#"{"conditionId": "0xabc", "slug": "s", "active": 2, "closed": false}"#;
+        assert!(serde_json::from_str::<GammaMarket>(two).is_err());
+    }
+}
+
+#[cfg(test)]
+mod market_outcome_tests {
+    use super::*;
+
+    fn market_with_outcomes(yes: &str, no: &str) -> Market {
+        let gamma: GammaMarket = serde_json::from_value(serde_json::json!({
+            "conditionId": "0xabc",
+            "slug": "m",
+            "active": true,
+            "closed": false,
+            "outcomes": serde_json::to_string(&[yes, no]).unwrap(),
+            "clobTokenIds": "[\"111\",\"222\"]"
+        }))
+        .unwrap();
+        gamma.into()
+    }
+
+    #[test]
+    fn test_outcome_lookup_ignores_case_and_whitespace() {
+        let market = market_with_outcomes("YES", " No ");
+
+        for label in ["yes", "Yes", " YES ", "\tyes\n"] {
+            assert_eq!(market.token_id_for_outcome(label), Some("111"));
+        }
+        for label in ["no", "NO", "  no"] {
+            assert_eq!(market.token_id_for_outcome(label), Some("222"));
+        }
+        assert_eq!(market.token_id_for_outcome("maybe"), None);
+
+        // Original labels are kept for display.
+        assert_eq!(market.tokens[0].outcome, "YES");
+        assert_eq!(market.tokens[1].outcome, " No ");
+    }
+
+    #[test]
+    fn test_display_price_uses_config() {
+        use crate::utils::display::DisplayConfig;
+        use rust_decimal::RoundingStrategy;
+
+        let gamma: GammaMarket = serde_json::from_value(serde_json::json!({
+            "conditionId": "0xabc",
+            "slug": "m",
+            "active": true,
+            "closed": false,
+            "outcomes": "[\"Yes\",\"No\"]",
+            "outcomePrices": "[\"0.12345\",\"0.87655\"]",
+            "clobTokenIds": "[\"111\",\"222\"]"
+        }))
+        .unwrap();
+        let market: Market = gamma.into();
+
+        let config = DisplayConfig::default();
+        assert_eq!(
+            market.display_price("yes", &config).as_deref(),
+            Some("0.1235")
+        );
+        let config = DisplayConfig::new(2, RoundingStrategy::ToZero);
+        assert_eq!(market.display_price("No", &config).as_deref(), Some("0.87"));
+        assert_eq!(market.display_price("maybe", &config), None);
+        assert_eq!(
+            market_with_outcomes("Yes", "No").display_price("yes", &config),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod sports_tests {
+    use super::*;
+
+    #[test]
+    fn test_sports_market_metadata() {
+        let gamma: GammaMarket = serde_json::from_value(serde_json::json!({
+            "conditionId": "0xgame",
+            "slug": "nba-lal-bos-2026-01-12",
+            "active": true,
+            "closed": false,
+            "outcomes": "[\"Lakers\",\"Celtics\"]",
+            "clobTokenIds": "[\"111\",\"222\"]",
+            "gameId": 90210,
+            "gameStartTime": "2026-01-12 00:30:00+00",
+            "sportsMarketType": "moneyline",
+            "events": [{"id": "77", "series": [{"id": "10345", "slug": "nba"}]}]
+        }))
+        .unwrap();
+        let mut market: Market = gamma.into();
+
+        assert!(market.is_sports());
+        assert_eq!(
+            market.game_start_time.as_deref(),
+            Some("2026-01-12 00:30:00+00")
+        );
+        let sports: Vec<Sport> = serde_json::from_value(serde_json::json!([
+            {"sport": "nfl", "series": "10187"},
+            {"sport": "nba", "series": "10345"}
+        ]))
+        .unwrap();
+        market.resolve_league(&sports);
+
+        let info = market.sports.unwrap();
+        assert_eq!(info.teams, ["Lakers", "Celtics"]);
+        assert_eq!(info.game_id.as_deref(), Some("90210"));
+        assert_eq!(info.league.as_deref(), Some("nba"));
+        assert_eq!(info.market_type.as_deref(), Some("moneyline"));
+    }
+
+    #[test]
+    fn test_non_sports_market() {
+        let gamma: GammaMarket = serde_json::from_value(serde_json::json!({
+            "conditionId": "0xabc",
+            "slug": "fed-cut",
+            "active": true,
+            "closed": false
+        }))
+        .unwrap();
+        let market: Market = gamma.into();
+        assert!(!market.is_sports());
+    }
+}
+
+#[cfg(test)]
+mod tick_band_tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn market_with_tick(tick: &str) -> Market {
+        let gamma: GammaMarket = serde_json::from_value(serde_json::json!({
+            "conditionId": "0xabc",
+            "slug": "m",
+            "active": true,
+            "closed": false,
+            "orderPriceMinTickSize": tick,
+            "clobTokenIds": "[\"111\",\"222\"]"
+        }))
+        .unwrap();
+        gamma.into()
+    }
+
+    #[test]
+    fn test_flat_tick_market() {
+        let mut market = market_with_tick("0.001");
+        assert!(market.tick_bands.is_empty());
+
+        assert_eq!(market.tick_size_at(dec!(0.02)), dec!(0.001));
+        assert_eq!(market.tick_size_at(dec!(0.50)), dec!(0.001));
+        assert!(market.validate_order_price(dec!(0.555)).is_ok());
+        assert!(market.validate_order_price(dec!(0.5555)).is_err());
+        assert!(market.validate_order_price(dec!(0.9995)).is_err());
+
+        market.minimum_tick_size = dec!(0.01);
+        assert_eq!(market.tick_size_at(dec!(0.02)), dec!(0.01));
+        assert!(market.validate_order_price(dec!(0.55)).is_ok());
+        assert!(market.validate_order_price(dec!(0.555)).is_err());
+        assert!(market.validate_order_price(dec!(0.995)).is_err());
+    }
+
+    #[test]
+    fn test_banded_tick_market() {
+        let mut market = market_with_tick("0.01");
+        assert_eq!(market.tick_bands, TickBand::tail_bands(dec!(0.01)));
+
+        assert_eq!(market.tick_size_at(dec!(0.30)), dec!(0.01));
+        assert_eq!(market.tick_size_at(dec!(0.039)), dec!(0.001));
+        assert_eq!(market.tick_size_at(dec!(0.04)), dec!(0.01));
+        assert_eq!(market.tick_size_at(dec!(0.96)), dec!(0.01));
+        assert_eq!(market.tick_size_at(dec!(0.961)), dec!(0.001));
+        assert!(market.validate_order_price(dec!(0.305)).is_err());
+        assert!(market.validate_order_price(dec!(0.998)).is_ok());
+        assert!(market.validate_order_price(dec!(0.9995)).is_err());
+
+        market
+            .tick_bands
+            .push(TickBand::new(dec!(0.40), dec!(0.60), dec!(0.001)));
+        assert_eq!(market.tick_size_at(dec!(0.40)), dec!(0.001));
+        assert_eq!(market.tick_size_at(dec!(0.5)), dec!(0.001));
+        assert!(market.validate_order_price(dec!(0.505)).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod markets_diff_tests {
+    use super::*;
+
+    fn market(id: &str, yes: &str, no: &str) -> Market {
+        let gamma: GammaMarket = serde_json::from_value(serde_json::json!({
+            "conditionId": id,
+            "slug": id,
+            "active": true,
+            "closed": false,
+            "outcomePrices": serde_json::to_string(&[yes, no]).unwrap(),
+            "clobTokenIds": serde_json::to_string(&[format!("{id}-y"), format!("{id}-n")]).unwrap()
+        }))
+        .unwrap();
+        gamma.into()
+    }
+
+    fn page(markets: Vec<Market>) -> MarketsResponse {
+        MarketsResponse {
+            limit: Decimal::from(50),
+            count: Decimal::from(markets.len() as u64),
+            next_cursor: None,
+            data: markets,
+        }
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_moved() {
+        let previous = page(vec![
+            market("0xa", "0.40", "0.60"),
+            market("0xb", "0.25", "0.75"),
+            market("0xc", "0.90", "0.10"),
+        ]);
+        let current = page(vec![
+            market("0xa", "0.40", "0.60"),
+            market("0xb", "0.30", "0.70"),
+            market("0xd", "0.50", "0.50"),
+        ]);
+
+        let diff = current.diff(&previous);
+        assert_eq!(diff.added, ["0xd"]);
+        assert_eq!(diff.removed, ["0xc"]);
+        assert_eq!(diff.price_moved, ["0xb"]);
+        assert!(previous.diff(&previous).is_empty());
+    }
+
+    #[test]
+    fn test_diff_ignores_moves_within_tolerance() {
+        let previous = page(vec![market("0xa", "0.400", "0.600")]);
+        let current = page(vec![market("0xa", "0.404", "0.596")]);
+
+        assert_eq!(current.diff(&previous).price_moved, ["0xa"]);
+        let within = current.diff_with_tolerance(&previous, Decimal::new(5, 3));
+        assert!(within.price_moved.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod order_by_tests {
+    use super::*;
+
+    #[test]
+    fn test_order_by_maps_to_gamma_field_names() {
+        let cases = [
+            (OrderBy::Volume, "volumeNum"),
+            (OrderBy::Liquidity, "liquidityNum"),
+            (OrderBy::EndDate, "endDate"),
+            (OrderBy::StartDate, "startDate"),
+            (OrderBy::CreatedAt, "createdAt"),
+            (OrderBy::Raw("volume24hr".to_string()), "volume24hr"),
+        ];
+        for (order, expected) in cases {
+            let params = GammaListParams::builder().order_by(order, false);
+            let query = params.to_query_params();
+            assert!(
+                query.contains(&("order", expected.to_string())),
+                "{}",
+                expected
+            );
+            assert!(query.contains(&("ascending", "false".to_string())));
+            assert_eq!(OrderBy::from(expected).as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn test_builder_matches_hand_constructed_params() {
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let end = DateTime::from_timestamp(1_710_000_000, 0).unwrap();
+
+        let built = GammaListParams::builder()
+            .limit(5)
+            .offset(10)
+            .closed(false)
+            .tag_id("2")
+            .exclude_tag_id("7")
+            .related_tags("true")
+            .order_by(OrderBy::Volume, false)
+            .liquidity_num_min(Decimal::from(1000))
+            .liquidity_num_max(Decimal::from(50000))
+            .volume_num_min(Decimal::from(10))
+            .start_date_min(start)
+            .end_date_max(end)
+            .slug(vec!["fed-june".to_string()])
+            .cyom(false)
+            .build();
+
+        let by_hand = GammaListParams {
+            limit: Some(5),
+            offset: Some(10),
+            closed: Some(false),
+            tag_id: Some("2".to_string()),
+            exclude_tag_id: Some("7".to_string()),
+            related_tags: Some("true".to_string()),
+            order: Some(OrderBy::Volume),
+            ascending: Some(false),
+            liquidity_num_min: Some(Decimal::from(1000)),
+            liquidity_num_max: Some(Decimal::from(50000)),
+            volume_num_min: Some(Decimal::from(10)),
+            start_date_min: Some(start),
+            end_date_max: Some(end),
+            slug: Some(vec!["fed-june".to_string()]),
+            cyom: Some(false),
+            ..Default::default()
+        };
+
+        assert_eq!(built, by_hand);
+        assert_eq!(built.to_query_params(), by_hand.to_query_params());
+        assert_eq!(
+            GammaListParams::builder().build(),
+            GammaListParams::default()
+        );
+    }
+
+    #[test]
+    fn test_filter_profile_round_trips_into_params() {
+        let json = r#"{
+            "name": "high-liquidity politics",
+            "closed": false,
+            "tag_id": "2",
+            "order": "liquidityNum",
+            "ascending": false,
+            "liquidity_num_min": "50000",
+            "limit": 25
+        }"#;
+        let profile: FilterProfile = serde_json::from_str(json).unwrap();
+        let reloaded: FilterProfile =
+            serde_json::from_str(&serde_json::to_string(&profile).unwrap()).unwrap();
+        assert_eq!(reloaded, profile);
+        assert_eq!(reloaded.volume_num_min, None);
+
+        let params = GammaListParams::from_profile(&reloaded);
+        let expected = [
+            ("limit", "25"),
+            ("closed", "false"),
+            ("ascending", "false"),
+            ("tag_id", "2"),
+            ("order", "liquidityNum"),
+            ("liquidity_num_min", "50000"),
+        ]
+        .map(|(key, value)| (key, value.to_string()));
+        assert_eq!(params.to_query_params(), expected);
+    }
+}
+
+#[cfg(test)]
+mod gamma_event_tests {
+    use super::*;
+
+    #[test]
+    fn test_event_parses_tags_and_series() {
+        let event: GammaEvent = serde_json::from_str(
+            r#"{
+                "id": "16085",
+                "slug": "nba-lal-bos-2025-01-23",
+                "tags": [
+                    {"id": "1", "label": "Sports", "slug": "sports"},
+                    {"id": 745, "label": "NBA", "slug": "nba"},
+                    "not-a-tag"
+                ],
+                "series": [
+                    {"id": "2", "slug": "nba", "title": "NBA", "ticker": "nba",
+                     "recurrence": "daily", "active": true}
+                ],
+                "markets": []
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.tag_slugs(), vec!["sports", "nba"]);
+        let primary = event.primary_tag().unwrap();
+        assert_eq!(primary.name.as_deref(), Some("Sports"));
+        assert_eq!(event.tags[1].id.as_deref(), Some("745"));
+
+        let series = event.series.unwrap();
+        assert_eq!(series.id.as_deref(), Some("2"));
+        assert_eq!(series.slug.as_deref(), Some("nba"));
+        assert_eq!(series.recurrence.as_deref(), Some("daily"));
+    }
+
+    #[test]
+    fn test_event_tolerates_missing_tags_and_series() {
+        let event: GammaEvent =
+            serde_json::from_str(r#"{"id": "1", "slug": "e", "tags": null, "series": null}"#)
+                .unwrap();
+        assert!(event.tags.is_empty());
+        assert!(event.series.is_none());
+        assert!(event.primary_tag().is_none());
+        assert_eq!(event.active, None);
+        assert_eq!(event.closed, None);
+    }
+
+    #[test]
+    fn test_event_bool_fields_accept_strings_and_numbers() {
+        let parse = |active: &str, closed: &str| -> GammaEvent {
+            let json =
+                format!(r#"{{"id": "1", "slug": "e", "active": {active}, "closed": {closed}}}"#);
+            serde_json::from_str(&json).unwrap()
+        };
+
+        for event in [
+            parse("true", "false"),
+            parse(r#""true""#, r#""false""#),
+            parse("1", "0"),
+        ] {
+            assert_eq!(event.active, Some(true));
+            assert_eq!(event.closed, Some(false));
+        }
+
+        let blank = parse("null", r#""""#);
+        assert_eq!(blank.active, None);
+        assert_eq!(blank.closed, None);
+    }
+}
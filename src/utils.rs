@@ -151,6 +151,22 @@ pub mod math {
         price * size
     }
 
+    /// USDC value of `shares` at `price`
+    #[inline]
+    pub fn shares_to_usdc(shares: Decimal, price: Decimal) -> Decimal {
+        shares * price
+    }
+
+    /// Shares that `usdc` buys at `price`; zero when the price is zero or
+    /// negative
+    #[inline]
+    pub fn usdc_to_shares(usdc: Decimal, price: Decimal) -> Decimal {
+        if price <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        usdc / price
+    }
+
     /// Calculate spread as percentage (LEGACY - use fixed-point version when possible)
     #[inline]
     pub fn spread_pct(bid: Decimal, ask: Decimal) -> Option<Decimal> {
@@ -169,6 +185,17 @@ pub mod math {
         Some((bid + ask) / Decimal::from(2))
     }
 
+    /// Order-book imbalance `(bid - ask) / (bid + ask)`, in `[-1, 1]`;
+    /// `None` when both volumes are zero
+    #[inline]
+    pub fn book_imbalance(bid_volume: Decimal, ask_volume: Decimal) -> Option<Decimal> {
+        let total = bid_volume + ask_volume;
+        if total <= Decimal::ZERO {
+            return None;
+        }
+        Some((bid_volume - ask_volume) / total)
+    }
+
     // ========================================================================
     // HIGH-PERFORMANCE FIXED-POINT FUNCTIONS
     // ========================================================================
@@ -393,7 +420,7 @@ pub mod retry {
         Err(last_error.unwrap_or_else(|| {
             PolyError::internal(
                 "Retry loop failed",
-                std::io::Error::new(std::io::ErrorKind::Other, "No error captured"),
+                std::io::Error::other("No error captured"),
             )
         }))
     }
@@ -458,26 +485,45 @@ pub mod url {
 pub mod rate_limit {
     use super::*;
     use std::sync::{Arc, Mutex};
+    use tokio::time::Instant;
 
-    /// Simple token bucket rate limiter
-    #[derive(Debug)]
+    /// Simple token bucket rate limiter. Clones share the same bucket.
+    #[derive(Debug, Clone)]
     pub struct TokenBucket {
         capacity: usize,
         tokens: Arc<Mutex<usize>>,
         refill_rate: Duration,
-        last_refill: Arc<Mutex<SystemTime>>,
+        last_refill: Arc<Mutex<Instant>>,
     }
 
     impl TokenBucket {
+        /// A full bucket of `capacity` tokens refilled at `refill_per_second`.
+        /// The rate is clamped to between one token per second and one per
+        /// nanosecond.
         pub fn new(capacity: usize, refill_per_second: usize) -> Self {
+            let per_second = refill_per_second.clamp(1, u32::MAX as usize) as u32;
             Self {
                 capacity,
                 tokens: Arc::new(Mutex::new(capacity)),
-                refill_rate: Duration::from_secs(1) / refill_per_second as u32,
-                last_refill: Arc::new(Mutex::new(SystemTime::now())),
+                refill_rate: (Duration::from_secs(1) / per_second).max(Duration::from_nanos(1)),
+                last_refill: Arc::new(Mutex::new(Instant::now())),
             }
         }
 
+        /// Take a token, waiting for the next refill while the bucket is empty.
+        pub async fn acquire(&self) {
+            while !self.try_consume() {
+                let elapsed = self.last_refill.lock().unwrap().elapsed();
+                tokio::time::sleep(self.refill_rate.saturating_sub(elapsed)).await;
+            }
+        }
+
+        /// Tokens that can be taken right now without waiting.
+        pub fn available(&self) -> usize {
+            self.refill();
+            *self.tokens.lock().unwrap()
+        }
+
         /// Try to consume a token, return true if successful
         pub fn try_consume(&self) -> bool {
             self.refill();
@@ -492,24 +538,96 @@ pub mod rate_limit {
         }
 
         fn refill(&self) {
-            let now = SystemTime::now();
+            let now = Instant::now();
             let mut last_refill = self.last_refill.lock().unwrap();
-            let elapsed = now.duration_since(*last_refill).unwrap_or_default();
+            let elapsed = now.saturating_duration_since(*last_refill);
 
             if elapsed >= self.refill_rate {
                 let tokens_to_add = elapsed.as_nanos() / self.refill_rate.as_nanos();
                 let mut tokens = self.tokens.lock().unwrap();
-                *tokens = std::cmp::min(self.capacity, *tokens + tokens_to_add as usize);
-                *last_refill = now;
+                *tokens =
+                    std::cmp::min(self.capacity as u128, *tokens as u128 + tokens_to_add) as usize;
+                // Carry the partial interval over so refills don't drift late.
+                *last_refill = if *tokens == self.capacity {
+                    now
+                } else {
+                    let refilled = self.refill_rate.as_nanos() * tokens_to_add;
+                    *last_refill + Duration::from_nanos(refilled as u64)
+                };
             }
         }
     }
 }
 
+/// Display formatting for prices and sizes
+pub mod display {
+    use super::*;
+    use rust_decimal::RoundingStrategy;
+
+    /// How decimals are rendered for display.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DisplayConfig {
+        pub decimals: u32,
+        pub rounding: RoundingStrategy,
+    }
+
+    impl Default for DisplayConfig {
+        /// Four decimal places, rounding half away from zero.
+        fn default() -> Self {
+            Self {
+                decimals: 4,
+                rounding: RoundingStrategy::MidpointAwayFromZero,
+            }
+        }
+    }
+
+    impl DisplayConfig {
+        pub fn new(decimals: u32, rounding: RoundingStrategy) -> Self {
+            Self { decimals, rounding }
+        }
+    }
+
+    /// Round `value` per `config` and render it with exactly `config.decimals`
+    /// fractional digits.
+    pub fn format_decimal(value: Decimal, config: &DisplayConfig) -> String {
+        let rounded = value.round_dp_with_strategy(config.decimals, config.rounding);
+        format!("{:.*}", config.decimals as usize, rounded)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[tokio::test(start_paused = true)]
+    async fn test_token_bucket_refills_over_time() {
+        use rate_limit::TokenBucket;
+
+        let bucket = TokenBucket::new(2, 10);
+        assert!(bucket.try_consume());
+        assert!(bucket.try_consume());
+        assert!(!bucket.try_consume());
+
+        tokio::time::advance(Duration::from_millis(150)).await;
+        assert_eq!(bucket.available(), 1);
+        tokio::time::advance(Duration::from_millis(50)).await;
+        assert_eq!(bucket.available(), 2);
+    }
+
+    #[test]
+    fn test_token_bucket_clamps_extreme_rates() {
+        use rate_limit::TokenBucket;
+
+        // Above 1e9/s the per-token interval would round down to zero.
+        let fast = TokenBucket::new(1, 2_000_000_000);
+        assert!(fast.try_consume());
+        assert!(fast.available() <= 1);
+
+        let stalled = TokenBucket::new(1, 0);
+        assert!(stalled.try_consume());
+        assert!(!stalled.try_consume());
+    }
+
     #[test]
     fn test_round_to_tick() {
         use math::round_to_tick;
@@ -520,6 +638,25 @@ mod tests {
         assert_eq!(rounded, Decimal::from_str("0.57").unwrap());
     }
 
+    #[test]
+    fn test_usdc_share_conversions() {
+        use math::{shares_to_usdc, usdc_to_shares};
+
+        let price = Decimal::from_str("0.40").unwrap();
+        assert_eq!(
+            shares_to_usdc(Decimal::from(250), price),
+            Decimal::from(100)
+        );
+        assert_eq!(
+            usdc_to_shares(Decimal::from(100), price),
+            Decimal::from(250)
+        );
+        assert_eq!(
+            usdc_to_shares(Decimal::from(100), Decimal::ZERO),
+            Decimal::ZERO
+        );
+    }
+
     #[test]
     fn test_mid_price() {
         use math::mid_price;
@@ -552,4 +689,35 @@ mod tests {
         let invalid = "invalid_address";
         assert!(parse_address(invalid).is_err());
     }
+
+    #[test]
+    fn test_format_decimal_rounding_strategies() {
+        use display::{DisplayConfig, format_decimal};
+        use rust_decimal::RoundingStrategy;
+
+        let boundary = Decimal::from_str("0.12345").unwrap();
+
+        let half_up = DisplayConfig::default();
+        assert_eq!(format_decimal(boundary, &half_up), "0.1235");
+        assert_eq!(format_decimal(-boundary, &half_up), "-0.1235");
+
+        let half_even = DisplayConfig::new(4, RoundingStrategy::MidpointNearestEven);
+        assert_eq!(format_decimal(boundary, &half_even), "0.1234");
+        assert_eq!(
+            format_decimal(Decimal::from_str("0.12355").unwrap(), &half_even),
+            "0.1236"
+        );
+
+        let truncate = DisplayConfig::new(4, RoundingStrategy::ToZero);
+        assert_eq!(
+            format_decimal(Decimal::from_str("0.12349").unwrap(), &truncate),
+            "0.1234"
+        );
+
+        // Short values are padded to the configured width.
+        assert_eq!(
+            format_decimal(Decimal::from_str("0.5").unwrap(), &half_up),
+            "0.5000"
+        );
+    }
 }
@@ -6,20 +6,28 @@
 //! and exposes typed events for books, price changes, tick size changes, and
 //! last trade notifications.
 
+use crate::api::DataClient;
+use crate::client::SharedCredentials;
 use crate::errors::{PolyError, Result};
-use crate::types::{ApiCredentials, OrderSummary, Side};
+use crate::types::{
+    ApiCredentials, OpenOrder, OrderBookSummary, OrderSummary, Side, Trade, TradeQuery,
+};
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use futures::{SinkExt, StreamExt};
+use futures::{SinkExt, Stream, StreamExt};
 use serde::Deserialize;
 use serde_json::{Value, json};
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::path::Path;
 use std::time::Duration;
 use tokio::net::TcpStream;
-use tokio::time::{sleep, timeout};
+use tokio::time::{Instant, sleep, timeout};
 use tokio_tungstenite::{
-    MaybeTlsStream, WebSocketStream, connect_async, tungstenite::protocol::Message,
+    MaybeTlsStream, WebSocketStream, connect_async,
+    tungstenite::protocol::{CloseFrame, Message},
 };
-use tracing::warn;
+use tracing::{debug, error, warn};
 
 const DEFAULT_WSS_BASE: &str = "wss://ws-subscriptions-clob.polymarket.com";
 const MARKET_CHANNEL_PATH: &str = "/ws/market";
@@ -28,14 +36,64 @@ const BASE_RECONNECT_DELAY: Duration = Duration::from_millis(250);
 const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(10);
 const MAX_RECONNECT_ATTEMPTS: u32 = 8;
 const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(25);
+const DEFAULT_WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_RECONNECT_LOG_INTERVAL: Duration = Duration::from_secs(30);
+/// Disconnects older than this don't affect [`WssMarketClient::health_score`].
+const HEALTH_DISCONNECT_WINDOW: Duration = Duration::from_secs(600);
+/// Silence shorter than this is not penalised.
+const HEALTH_STALE_AFTER: Duration = Duration::from_secs(30);
+/// Silence this long drives the staleness factor to zero.
+const HEALTH_DEAD_AFTER: Duration = Duration::from_secs(300);
+/// A user channel socket closed this soon after authenticating, before any
+/// message arrived, counts as a silent rejection.
+const USER_AUTH_REJECT_WINDOW: Duration = Duration::from_secs(5);
+/// Silent rejections in a row after which the credentials are assumed bad.
+const MAX_SILENT_USER_CLOSES: u32 = 3;
 
 /// Represents a parsed market broadcast from the public market channel.
 #[derive(Debug, Clone)]
 pub enum WssMarketEvent {
     Book(MarketBook),
+    AggBook(AggBookMessage),
     PriceChange(PriceChangeMessage),
     TickSizeChange(TickSizeChangeMessage),
     LastTrade(LastTradeMessage),
+    /// The first `book` for `asset_id` after a reconnect doesn't match the
+    /// last hash seen before the drop, so updates were missed while
+    /// disconnected; discard local state and rebuild from the `Book` that
+    /// follows. Only emitted when [`WssConfig::resync_on_reconnect`] is set.
+    Resync {
+        asset_id: String,
+    },
+}
+
+impl WssMarketEvent {
+    /// Wire `event_type` this variant was parsed from.
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            WssMarketEvent::Book(_) => "book",
+            WssMarketEvent::AggBook(_) => "agg_order_book",
+            WssMarketEvent::PriceChange(_) => "price_change",
+            WssMarketEvent::TickSizeChange(_) => "tick_size_change",
+            WssMarketEvent::LastTrade(_) => "last_trade_price",
+            WssMarketEvent::Resync { .. } => "resync",
+        }
+    }
+
+    /// When the server stamped the event, accepting second or millisecond
+    /// epoch strings. `None` for client-synthesized events and unparseable
+    /// timestamps.
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        let raw = match self {
+            WssMarketEvent::Book(book) => &book.timestamp,
+            WssMarketEvent::AggBook(book) => &book.timestamp,
+            WssMarketEvent::PriceChange(change) => &change.timestamp,
+            WssMarketEvent::TickSizeChange(change) => &change.timestamp,
+            WssMarketEvent::LastTrade(trade) => &trade.timestamp,
+            WssMarketEvent::Resync { .. } => return None,
+        };
+        event_time(raw)
+    }
 }
 
 /// Events emitted by the authenticated user channel.
@@ -124,6 +182,126 @@ pub struct MarketBook {
     pub asks: Vec<OrderSummary>,
 }
 
+impl MarketBook {
+    /// This snapshot as a [`crate::types::OrderBook`], stamped with the event
+    /// time (or now, if it doesn't parse).
+    pub fn to_snapshot(&self) -> crate::types::OrderBook {
+        let levels = |side: &[OrderSummary]| {
+            side.iter()
+                .map(|level| crate::types::BookLevel {
+                    price: level.price,
+                    size: level.size,
+                })
+                .collect()
+        };
+        crate::types::OrderBook {
+            token_id: self.asset_id.clone(),
+            timestamp: event_time(&self.timestamp).unwrap_or_else(Utc::now),
+            bids: levels(&self.bids),
+            asks: levels(&self.asks),
+            sequence: 0,
+        }
+    }
+}
+
+impl From<OrderBookSummary> for MarketBook {
+    /// Build a synthetic `book` event from a REST order book snapshot.
+    fn from(summary: OrderBookSummary) -> Self {
+        Self {
+            event_type: "book".to_string(),
+            asset_id: summary.asset_id,
+            market: summary.market,
+            timestamp: summary.timestamp.to_string(),
+            hash: summary.hash,
+            bids: summary.bids,
+            asks: summary.asks,
+        }
+    }
+}
+
+/// Outcome of walking a [`MarketBook`] for a hypothetical taker order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FillEstimate {
+    /// Size that the book could absorb.
+    pub filled: rust_decimal::Decimal,
+    /// Size-weighted average price over the filled quantity.
+    pub avg_price: Option<rust_decimal::Decimal>,
+    /// Price of the deepest level touched.
+    pub worst_price: Option<rust_decimal::Decimal>,
+    /// Size left over when the book is too thin.
+    pub unfilled: rust_decimal::Decimal,
+}
+
+impl FillEstimate {
+    /// Whether the whole requested size would fill.
+    pub fn is_complete(&self) -> bool {
+        self.unfilled.is_zero()
+    }
+}
+
+impl MarketBook {
+    /// Estimate the fill of a `size` taker order against this snapshot.
+    ///
+    /// Buys walk the asks from the lowest price up, sells walk the bids from
+    /// the highest price down, regardless of the order levels arrived in.
+    pub fn simulate_fill(&self, side: Side, size: rust_decimal::Decimal) -> FillEstimate {
+        let mut levels: Vec<&OrderSummary> = match side {
+            Side::BUY => self.asks.iter().collect(),
+            Side::SELL => self.bids.iter().collect(),
+        };
+        match side {
+            Side::BUY => levels.sort_by_key(|level| level.price),
+            Side::SELL => levels.sort_by_key(|level| std::cmp::Reverse(level.price)),
+        }
+
+        let mut remaining = size.max(rust_decimal::Decimal::ZERO);
+        let mut filled = rust_decimal::Decimal::ZERO;
+        let mut notional = rust_decimal::Decimal::ZERO;
+        let mut worst_price = None;
+        for level in levels {
+            if remaining.is_zero() {
+                break;
+            }
+            if level.size <= rust_decimal::Decimal::ZERO {
+                continue;
+            }
+            let take = remaining.min(level.size);
+            filled += take;
+            notional += take * level.price;
+            remaining -= take;
+            worst_price = Some(level.price);
+        }
+
+        FillEstimate {
+            filled,
+            avg_price: (!filled.is_zero()).then(|| notional / filled),
+            worst_price,
+            unfilled: remaining,
+        }
+    }
+}
+
+/// Aggregated book snapshot.
+///
+/// Unlike [`MarketBook`], which mirrors the raw resting orders of the CLOB,
+/// levels here are already aggregated server-side, so a level's size may
+/// cover several price points and no book hash is guaranteed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AggBookMessage {
+    #[serde(rename = "event_type")]
+    pub event_type: String,
+    pub asset_id: String,
+    pub market: String,
+    #[serde(deserialize_with = "crate::decode::deserializers::string_from_string_or_number")]
+    pub timestamp: String,
+    #[serde(default)]
+    pub hash: Option<String>,
+    #[serde(default)]
+    pub bids: Vec<OrderSummary>,
+    #[serde(default)]
+    pub asks: Vec<OrderSummary>,
+}
+
 /// Payload for price change notifications.
 #[derive(Debug, Clone, Deserialize)]
 pub struct PriceChangeMessage {
@@ -151,6 +329,202 @@ pub struct PriceChangeEntry {
     pub best_ask: rust_decimal::Decimal,
 }
 
+impl PriceChangeEntry {
+    /// This entry as an [`crate::types::OrderDelta`]; a zero size removes the
+    /// level.
+    pub fn to_delta(&self, timestamp: DateTime<Utc>, sequence: u64) -> crate::types::OrderDelta {
+        crate::types::OrderDelta {
+            token_id: self.asset_id.clone(),
+            timestamp,
+            side: self.side,
+            price: self.price,
+            size: self.size,
+            sequence,
+        }
+    }
+}
+
+/// Best bid/ask for one asset, as last reported by a `price_change` entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopOfBook {
+    pub best_bid: rust_decimal::Decimal,
+    pub best_ask: rust_decimal::Decimal,
+    /// Timestamp of the `price_change` message that set these prices.
+    pub timestamp: String,
+}
+
+impl TopOfBook {
+    pub fn spread(&self) -> rust_decimal::Decimal {
+        self.best_ask - self.best_bid
+    }
+}
+
+/// Live order book for one asset, rebuilt from a `book` snapshot and the
+/// `price_change` deltas that follow it.
+///
+/// Levels are kept in a [`crate::book::OrderBook`], available through
+/// [`LocalBook::book`] for its analytics.
+#[derive(Debug, Clone)]
+pub struct LocalBook {
+    pub asset_id: String,
+    book: crate::book::OrderBook,
+    hash: String,
+}
+
+impl LocalBook {
+    /// Start from a snapshot; empty levels are dropped.
+    pub fn from_snapshot(book: &MarketBook) -> Result<Self> {
+        let mut local = crate::book::OrderBook::new(book.asset_id.clone(), usize::MAX);
+        local.apply_snapshot(&book.to_snapshot())?;
+        Ok(Self {
+            asset_id: book.asset_id.clone(),
+            book: local,
+            hash: book.hash.clone(),
+        })
+    }
+
+    /// Apply the entries of `change` that belong to this asset. Each entry
+    /// carries the new total size at its price; zero removes the level.
+    pub fn apply_price_change(&mut self, change: &PriceChangeMessage) -> Result<()> {
+        for entry in &change.price_changes {
+            if entry.asset_id == self.asset_id {
+                self.apply_entry(entry, change)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_entry(&mut self, entry: &PriceChangeEntry, change: &PriceChangeMessage) -> Result<()> {
+        let timestamp = event_time(&change.timestamp).unwrap_or_else(Utc::now);
+        self.book
+            .apply_delta(entry.to_delta(timestamp, self.book.sequence + 1))?;
+        self.hash = entry.hash.clone();
+        Ok(())
+    }
+
+    /// The underlying order book.
+    pub fn book(&self) -> &crate::book::OrderBook {
+        &self.book
+    }
+
+    /// Hash of the last snapshot or change applied.
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    pub fn best_bid(&self) -> Option<OrderSummary> {
+        self.book.best_bid().map(OrderSummary::from)
+    }
+
+    pub fn best_ask(&self) -> Option<OrderSummary> {
+        self.book.best_ask().map(OrderSummary::from)
+    }
+
+    /// Top `n` levels per side as `(bids, asks)`, best price first.
+    pub fn depth(&self, n: usize) -> (Vec<OrderSummary>, Vec<OrderSummary>) {
+        let levels =
+            |side: Vec<crate::types::BookLevel>| side.into_iter().map(OrderSummary::from).collect();
+        (
+            levels(self.book.bids(Some(n))),
+            levels(self.book.asks(Some(n))),
+        )
+    }
+}
+
+/// [`LocalBook`]s for every asset on a market stream.
+#[derive(Debug, Clone, Default)]
+pub struct LocalBooks {
+    books: HashMap<String, LocalBook>,
+}
+
+impl LocalBooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Book for `asset_id`, once a snapshot has arrived.
+    pub fn get(&self, asset_id: &str) -> Option<&LocalBook> {
+        self.books.get(asset_id)
+    }
+
+    /// Replace the asset's book with `book`.
+    pub fn apply_snapshot(&mut self, book: &MarketBook) -> Result<()> {
+        self.books
+            .insert(book.asset_id.clone(), LocalBook::from_snapshot(book)?);
+        Ok(())
+    }
+
+    /// Apply every entry whose asset has a snapshot. Entries for assets
+    /// without one are skipped and reported as a `BookUnavailable` error;
+    /// fetch a snapshot for them and carry on.
+    pub fn apply_price_change(&mut self, change: &PriceChangeMessage) -> Result<()> {
+        let mut missing = Vec::new();
+        for entry in &change.price_changes {
+            match self.books.get_mut(&entry.asset_id) {
+                Some(book) => book.apply_entry(entry, change)?,
+                None if !missing.contains(&entry.asset_id) => missing.push(entry.asset_id.clone()),
+                None => {}
+            }
+        }
+        if missing.is_empty() {
+            return Ok(());
+        }
+        Err(PolyError::market_data(
+            format!("price_change before snapshot for {}", missing.join(", ")),
+            crate::errors::MarketDataErrorKind::BookUnavailable,
+        ))
+    }
+
+    /// Feed a market event: `book` snapshots and `price_change` deltas
+    /// update the books, everything else is ignored.
+    pub fn apply(&mut self, evt: &WssMarketEvent) -> Result<()> {
+        match evt {
+            WssMarketEvent::Book(book) => self.apply_snapshot(book),
+            WssMarketEvent::PriceChange(change) => self.apply_price_change(change),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Downstream destination for market events forwarded by
+/// [`WssMarketClient::pipe_to`] (a queue producer, a database writer, ...).
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    async fn send(&self, event: &WssMarketEvent) -> Result<()>;
+}
+
+/// Forwards events into a tokio channel; fails once the receiver is dropped.
+#[async_trait]
+impl EventSink for tokio::sync::mpsc::Sender<WssMarketEvent> {
+    async fn send(&self, event: &WssMarketEvent) -> Result<()> {
+        tokio::sync::mpsc::Sender::send(self, event.clone())
+            .await
+            .map_err(|_| disconnected_error("Event sink receiver dropped"))
+    }
+}
+
+/// What [`WssMarketClient::pipe_to`] does when the sink rejects an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SinkErrorPolicy {
+    /// Return the sink error and stop forwarding.
+    #[default]
+    Stop,
+    /// Log the error, drop the event, and keep going.
+    LogAndContinue,
+}
+
+/// Which market channel frames the client keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubscriptionMode {
+    /// Deliver every event.
+    #[default]
+    Full,
+    /// Drop `book` and `agg_orderbook` snapshots as they arrive and rely on
+    /// `price_change` best bid/ask only. Keeps memory flat for large
+    /// watchlists; see [`WssMarketClient::top_of_book`].
+    TopOfBook,
+}
+
 /// Tick size change events.
 #[derive(Debug, Clone, Deserialize)]
 pub struct TickSizeChangeMessage {
@@ -166,12 +540,73 @@ pub struct TickSizeChangeMessage {
     pub timestamp: String,
 }
 
+impl TickSizeChangeMessage {
+    /// Orders on this message's asset that no longer sit on the new tick grid.
+    pub fn off_tick_orders(&self, orders: &[OpenOrder]) -> Vec<OffTickOrder> {
+        find_off_tick_orders(&self.asset_id, self.new_tick_size, orders)
+    }
+}
+
+/// Resting order left off the tick grid by a tick size change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OffTickOrder {
+    pub order_id: String,
+    pub side: Side,
+    pub price: rust_decimal::Decimal,
+    /// Closest on-tick price on the passive side: bids round down, asks
+    /// round up, so re-pricing never makes an order more aggressive.
+    pub suggested_price: rust_decimal::Decimal,
+}
+
+/// Report which of `orders` for `asset_id` are not multiples of `new_tick`
+/// and should be re-priced or cancelled.
+pub fn find_off_tick_orders(
+    asset_id: &str,
+    new_tick: rust_decimal::Decimal,
+    orders: &[OpenOrder],
+) -> Vec<OffTickOrder> {
+    if new_tick.is_zero() {
+        return Vec::new();
+    }
+
+    orders
+        .iter()
+        .filter(|order| order.asset_id == asset_id)
+        .filter(|order| !(order.price % new_tick).is_zero())
+        .map(|order| {
+            let steps = order.price / new_tick;
+            let steps = match order.side {
+                Side::BUY => steps.floor(),
+                Side::SELL => steps.ceil(),
+            };
+            OffTickOrder {
+                order_id: order.id.clone(),
+                side: order.side,
+                price: order.price,
+                suggested_price: (steps * new_tick).normalize(),
+            }
+        })
+        .collect()
+}
+
+/// Acknowledgment of a market channel subscription.
+///
+/// Acks are consumed by [`WssMarketClient::next_event`] rather than returned
+/// as events; the latest one is available through
+/// [`WssMarketClient::last_subscription_ack`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct SubscriptionAck {
+    #[serde(default, alias = "assets_ids")]
+    pub markets: Vec<String>,
+}
+
 /// Trade events emitted when a trade settles.
 #[derive(Debug, Clone, Deserialize)]
 pub struct LastTradeMessage {
     #[serde(rename = "event_type")]
     pub event_type: String,
     pub asset_id: String,
+    #[serde(deserialize_with = "crate::decode::deserializers::string_from_string_or_number")]
     pub fee_rate_bps: String,
     pub market: String,
     #[serde(with = "rust_decimal::serde::str")]
@@ -182,6 +617,95 @@ pub struct LastTradeMessage {
     pub timestamp: String,
 }
 
+impl LastTradeMessage {
+    /// Fee rate in basis points, or `None` when the raw value isn't a valid
+    /// non-negative integer.
+    pub fn fee_rate_bps_value(&self) -> Option<u32> {
+        self.fee_rate_bps.trim().parse().ok()
+    }
+}
+
+/// Collapses repeated warnings into one line per interval.
+#[derive(Debug, Clone)]
+struct LogThrottle {
+    interval: Duration,
+    last_emit: Option<Instant>,
+    suppressed: u64,
+}
+
+impl LogThrottle {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_emit: None,
+            suppressed: 0,
+        }
+    }
+
+    /// Returns the number of suppressed occurrences since the last emitted
+    /// line when a new line may be logged, or `None` to stay quiet.
+    fn check(&mut self) -> Option<u64> {
+        let now = Instant::now();
+        match self.last_emit {
+            Some(last) if now.duration_since(last) < self.interval => {
+                self.suppressed += 1;
+                None
+            }
+            _ => {
+                self.last_emit = Some(now);
+                Some(std::mem::take(&mut self.suppressed))
+            }
+        }
+    }
+}
+
+/// Reconnect tuning for [`WssMarketClient::with_config`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WssConfig {
+    /// Delay before the first retry; doubles with each further attempt.
+    pub base_reconnect_delay: Duration,
+    /// Upper bound on any single retry delay.
+    pub max_reconnect_delay: Duration,
+    /// Connection attempts before giving up with `ConnectionFailed`.
+    pub max_reconnect_attempts: u32,
+    /// Fraction of each delay, in `0.0..=1.0`, that is randomized away so
+    /// many clients don't reconnect in lockstep. Zero disables jitter.
+    pub jitter: f64,
+    /// Emit [`WssMarketEvent::Resync`] when a reconnect skipped updates.
+    pub resync_on_reconnect: bool,
+}
+
+impl Default for WssConfig {
+    fn default() -> Self {
+        Self {
+            base_reconnect_delay: BASE_RECONNECT_DELAY,
+            max_reconnect_delay: MAX_RECONNECT_DELAY,
+            max_reconnect_attempts: MAX_RECONNECT_ATTEMPTS,
+            jitter: 0.0,
+            resync_on_reconnect: false,
+        }
+    }
+}
+
+impl WssConfig {
+    /// Delay before retry number `attempts` (1-based): `base * 2^(attempts - 1)`
+    /// capped at `max_reconnect_delay`, then reduced by up to `jitter` of
+    /// itself.
+    pub fn reconnect_delay(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(31);
+        let delay = self
+            .base_reconnect_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_reconnect_delay);
+
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return delay;
+        }
+        delay.mul_f64(1.0 - jitter * rand::random::<f64>())
+    }
+}
+
 /// Simple stats for monitoring connection health.
 #[derive(Debug, Clone, Default)]
 pub struct WssStats {
@@ -189,16 +713,42 @@ pub struct WssStats {
     pub errors: u64,
     pub reconnect_count: u32,
     pub last_message_time: Option<DateTime<Utc>>,
+    /// Assets whose post-reconnect snapshot showed updates were missed.
+    pub dropped_gap_count: u64,
 }
 
+type ResubscribeHook = Box<dyn FnMut(&mut Vec<String>) + Send>;
+type OutboundObserver = Box<dyn Fn(&str) + Send + Sync>;
+
 /// Reconnecting client for the market channel.
 pub struct WssMarketClient {
     connect_url: String,
     connection: Option<WebSocketStream<MaybeTlsStream<TcpStream>>>,
+    /// The current socket has not been sent the subscription yet.
+    subscription_pending: bool,
     subscribed_asset_ids: Vec<String>,
     stats: WssStats,
     disconnect_history: VecDeque<DateTime<Utc>>,
     pending_events: VecDeque<WssMarketEvent>,
+    last_ack: Option<SubscriptionAck>,
+    mode: SubscriptionMode,
+    top_of_book: HashMap<String, TopOfBook>,
+    last_hashes: HashMap<String, String>,
+    awaiting_resync: HashSet<String>,
+    sink_error_policy: SinkErrorPolicy,
+    pre_resubscribe: Option<ResubscribeHook>,
+    outbound_observer: Option<OutboundObserver>,
+    gap_backfill: Option<DataClient>,
+    disconnected_at: Option<DateTime<Utc>>,
+    asset_markets: HashMap<String, String>,
+    backfilled_trades: HashMap<String, usize>,
+    has_connected: bool,
+    initial_connect_attempts: u32,
+    max_reconnect_attempts: u32,
+    config: WssConfig,
+    write_timeout: Duration,
+    auto_reconnect: bool,
+    reconnect_log: LogThrottle,
 }
 
 impl Default for WssMarketClient {
@@ -219,19 +769,237 @@ impl WssMarketClient {
         let connect_url = format!("{}{}", trimmed, MARKET_CHANNEL_PATH);
         Self {
             connection: None,
+            subscription_pending: false,
             subscribed_asset_ids: Vec::new(),
             stats: WssStats::default(),
             disconnect_history: VecDeque::with_capacity(5),
             connect_url,
             pending_events: VecDeque::new(),
+            last_ack: None,
+            mode: SubscriptionMode::Full,
+            top_of_book: HashMap::new(),
+            last_hashes: HashMap::new(),
+            awaiting_resync: HashSet::new(),
+            sink_error_policy: SinkErrorPolicy::Stop,
+            pre_resubscribe: None,
+            outbound_observer: None,
+            gap_backfill: None,
+            disconnected_at: None,
+            asset_markets: HashMap::new(),
+            backfilled_trades: HashMap::new(),
+            has_connected: false,
+            initial_connect_attempts: MAX_RECONNECT_ATTEMPTS,
+            max_reconnect_attempts: MAX_RECONNECT_ATTEMPTS,
+            config: WssConfig::default(),
+            write_timeout: DEFAULT_WRITE_TIMEOUT,
+            auto_reconnect: true,
+            reconnect_log: LogThrottle::new(DEFAULT_RECONNECT_LOG_INTERVAL),
         }
     }
 
+    /// Limit connection failure warnings to one per `interval`; the next
+    /// warning reports how many were suppressed. Every attempt is still
+    /// logged at `debug`.
+    pub fn with_reconnect_log_interval(mut self, interval: Duration) -> Self {
+        self.reconnect_log = LogThrottle::new(interval);
+        self
+    }
+
+    /// When disabled, `next_event` surfaces a `StreamErrorKind::Disconnected`
+    /// error as soon as the socket closes instead of reconnecting. Enabled by
+    /// default.
+    pub fn with_auto_reconnect(mut self, enabled: bool) -> Self {
+        self.auto_reconnect = enabled;
+        self
+    }
+
+    /// Choose which frames are kept. In [`SubscriptionMode::TopOfBook`] book
+    /// snapshots are never queued, so [`WssMarketClient::get_first_book`]
+    /// will only time out.
+    pub fn with_subscription_mode(mut self, mode: SubscriptionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Run `hook` on the asset list after every reconnect, right before the
+    /// subscription is replayed. Edits persist: whatever the hook leaves in
+    /// the list becomes [`WssMarketClient::subscribed_asset_ids`]. Not called
+    /// for the initial connection.
+    pub fn with_pre_resubscribe(
+        mut self,
+        hook: impl FnMut(&mut Vec<String>) + Send + 'static,
+    ) -> Self {
+        self.pre_resubscribe = Some(Box::new(hook));
+        self
+    }
+
+    /// Report the serialized JSON of every frame the client sends
+    /// (subscriptions included), just before it is written to the socket.
+    pub fn with_outbound_observer(
+        mut self,
+        observer: impl Fn(&str) + Send + Sync + 'static,
+    ) -> Self {
+        self.outbound_observer = Some(Box::new(observer));
+        self
+    }
+
+    /// After a reconnect, fetch the trades each subscribed asset printed
+    /// while the socket was down from the public Data API
+    /// ([`DataClient::get_trades`]) and queue them as `LastTrade` events
+    /// ahead of the live stream. Live trades that repeat a backfilled one are
+    /// dropped.
+    ///
+    /// The Data API filters by market, so an asset is only backfilled once a
+    /// frame naming its market has been received.
+    pub fn with_gap_backfill(mut self, data: DataClient) -> Self {
+        self.gap_backfill = Some(data);
+        self
+    }
+
+    /// Decide whether [`WssMarketClient::pipe_to`] stops on the first sink
+    /// error (the default) or logs it and moves on.
+    pub fn with_sink_error_policy(mut self, policy: SinkErrorPolicy) -> Self {
+        self.sink_error_policy = policy;
+        self
+    }
+
+    /// Bound how long a single outbound frame may take to send. On timeout
+    /// the connection is dropped and re-established on the next call.
+    pub fn with_write_timeout(mut self, write_timeout: Duration) -> Self {
+        self.write_timeout = write_timeout;
+        self
+    }
+
+    /// Override how many connection attempts are made before `connect`
+    /// gives up with a `ConnectionFailed` error, for both the first connect
+    /// and reconnects.
+    pub fn with_max_reconnect_attempts(mut self, attempts: u32) -> Self {
+        self.initial_connect_attempts = attempts.max(1);
+        self.max_reconnect_attempts = attempts.max(1);
+        self
+    }
+
+    /// Replace the reconnect tuning. Resets both attempt limits to
+    /// `config.max_reconnect_attempts`.
+    pub fn with_config(mut self, config: WssConfig) -> Self {
+        self.initial_connect_attempts = config.max_reconnect_attempts.max(1);
+        self.max_reconnect_attempts = config.max_reconnect_attempts.max(1);
+        self.config = config;
+        self
+    }
+
+    /// Attempts for the first connect only, so a bad URL fails fast.
+    pub fn with_initial_connect_attempts(mut self, attempts: u32) -> Self {
+        self.initial_connect_attempts = attempts.max(1);
+        self
+    }
+
+    /// Attempts for each reconnect after the connection has been up once.
+    pub fn with_reconnect_attempts(mut self, attempts: u32) -> Self {
+        self.max_reconnect_attempts = attempts.max(1);
+        self
+    }
+
     /// Access connection stats for observability.
     pub fn stats(&self) -> WssStats {
         self.stats.clone()
     }
 
+    /// Asset IDs currently tracked by the subscription.
+    pub fn subscribed_asset_ids(&self) -> &[String] {
+        &self.subscribed_asset_ids
+    }
+
+    /// Most recent subscription acknowledgment, if the server sent one.
+    pub fn last_subscription_ack(&self) -> Option<&SubscriptionAck> {
+        self.last_ack.as_ref()
+    }
+
+    /// Track the last hash per asset, queueing a `Resync` ahead of `evt` when
+    /// the first book after a reconnect doesn't match it. Opt-in through
+    /// [`WssConfig::resync_on_reconnect`].
+    fn track_hashes(&mut self, evt: &WssMarketEvent) {
+        match evt {
+            WssMarketEvent::Book(book) => {
+                let previous = self
+                    .last_hashes
+                    .insert(book.asset_id.clone(), book.hash.clone());
+                if self.awaiting_resync.remove(&book.asset_id)
+                    && previous.is_some_and(|previous| previous != book.hash)
+                {
+                    self.stats.dropped_gap_count += 1;
+                    self.pending_events.push_back(WssMarketEvent::Resync {
+                        asset_id: book.asset_id.clone(),
+                    });
+                }
+            }
+            WssMarketEvent::PriceChange(change) => {
+                for entry in &change.price_changes {
+                    self.last_hashes
+                        .insert(entry.asset_id.clone(), entry.hash.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Latest best bid/ask seen for `asset_id` in a `price_change` event.
+    pub fn top_of_book(&self, asset_id: &str) -> Option<&TopOfBook> {
+        self.top_of_book.get(asset_id)
+    }
+
+    fn record_frame(&mut self, evt: WssMarketEvent) {
+        if let WssMarketEvent::LastTrade(trade) = &evt
+            && let Some(count) = self.backfilled_trades.get_mut(&trade_key(trade))
+        {
+            *count -= 1;
+            if *count == 0 {
+                self.backfilled_trades.remove(&trade_key(trade));
+            }
+            return;
+        }
+        self.track_markets(&evt);
+        self.track_hashes(&evt);
+        if let WssMarketEvent::PriceChange(change) = &evt {
+            for entry in &change.price_changes {
+                self.top_of_book.insert(
+                    entry.asset_id.clone(),
+                    TopOfBook {
+                        best_bid: entry.best_bid,
+                        best_ask: entry.best_ask,
+                        timestamp: change.timestamp.clone(),
+                    },
+                );
+            }
+        }
+        let is_snapshot = matches!(evt, WssMarketEvent::Book(_) | WssMarketEvent::AggBook(_));
+        if self.mode == SubscriptionMode::TopOfBook && is_snapshot {
+            return;
+        }
+        self.pending_events.push_back(evt);
+    }
+
+    /// Remember which market each asset belongs to, for the gap backfill.
+    fn track_markets(&mut self, evt: &WssMarketEvent) {
+        let mut note = |asset_id: &str, market: &str| {
+            if !self.asset_markets.contains_key(asset_id) {
+                self.asset_markets
+                    .insert(asset_id.to_string(), market.to_string());
+            }
+        };
+        match evt {
+            WssMarketEvent::Book(book) => note(&book.asset_id, &book.market),
+            WssMarketEvent::AggBook(book) => note(&book.asset_id, &book.market),
+            WssMarketEvent::LastTrade(trade) => note(&trade.asset_id, &trade.market),
+            WssMarketEvent::PriceChange(change) => {
+                for entry in &change.price_changes {
+                    note(&entry.asset_id, &change.market);
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn format_subscription(&self) -> Value {
         json!({
             "type": "market",
@@ -249,36 +1017,57 @@ impl WssMarketClient {
     }
 
     async fn send_raw_message(&mut self, message: Value) -> Result<()> {
-        if let Some(connection) = self.connection.as_mut() {
-            let text = serde_json::to_string(&message).map_err(|e| {
-                PolyError::parse(
-                    format!("Failed to serialize subscription message: {}", e),
-                    None,
-                )
-            })?;
-            connection
-                .send(Message::Text(text.into()))
-                .await
-                .map_err(|e| {
-                    PolyError::stream(
-                        format!("Failed to send message: {}", e),
-                        crate::errors::StreamErrorKind::MessageCorrupted,
-                    )
-                })?;
-            return Ok(());
+        let Some(connection) = self.connection.as_mut() else {
+            return Err(PolyError::stream(
+                "WebSocket connection not established",
+                crate::errors::StreamErrorKind::ConnectionFailed,
+            ));
+        };
+        let text = serde_json::to_string(&message).map_err(|e| {
+            PolyError::parse(
+                format!("Failed to serialize subscription message: {}", e),
+                None,
+            )
+        })?;
+        if let Some(observer) = &self.outbound_observer {
+            observer(&text);
+        }
+
+        match timeout(
+            self.write_timeout,
+            connection.send(Message::Text(text.into())),
+        )
+        .await
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(PolyError::stream(
+                format!("Failed to send message: {}", e),
+                crate::errors::StreamErrorKind::MessageCorrupted,
+            )),
+            Err(_) => {
+                // A stalled socket is unusable; drop it so the next call reconnects.
+                self.connection = None;
+                self.stats.errors += 1;
+                Err(PolyError::stream(
+                    format!("WebSocket send timed out after {:?}", self.write_timeout),
+                    crate::errors::StreamErrorKind::WriteTimeout,
+                ))
+            }
         }
-        Err(PolyError::stream(
-            "WebSocket connection not established",
-            crate::errors::StreamErrorKind::ConnectionFailed,
-        ))
     }
 
     async fn connect(&mut self) -> Result<()> {
+        let max_attempts = if self.has_connected {
+            self.max_reconnect_attempts
+        } else {
+            self.initial_connect_attempts
+        };
         let mut attempts = 0;
         loop {
             match connect_async(&self.connect_url).await {
                 Ok((socket, _)) => {
                     self.connection = Some(socket);
+                    self.subscription_pending = true;
                     if attempts > 0 {
                         self.stats.reconnect_count += 1;
                     }
@@ -288,7 +1077,14 @@ impl WssMarketClient {
                     attempts += 1;
                     let delay = self.reconnect_delay(attempts);
                     self.stats.errors += 1;
-                    if attempts >= MAX_RECONNECT_ATTEMPTS {
+                    debug!("WebSocket connect attempt {} failed: {}", attempts, err);
+                    if let Some(suppressed) = self.reconnect_log.check() {
+                        warn!(
+                            "WebSocket connect failed: {} ({} similar failures suppressed)",
+                            err, suppressed
+                        );
+                    }
+                    if attempts >= max_attempts {
                         return Err(PolyError::stream(
                             format!("Failed to connect after {} attempts: {}", attempts, err),
                             crate::errors::StreamErrorKind::ConnectionFailed,
@@ -301,52 +1097,342 @@ impl WssMarketClient {
     }
 
     fn reconnect_delay(&self, attempts: u32) -> Duration {
-        let millis = BASE_RECONNECT_DELAY.as_millis() * attempts as u128;
-
-        Duration::from_millis(millis.min(MAX_RECONNECT_DELAY.as_millis()) as u64)
+        self.config.reconnect_delay(attempts)
     }
 
+    /// Connect, replay the subscription and backfill the gap, as needed.
+    ///
+    /// Each step is only marked done once it completes, so if the caller
+    /// drops this future (e.g. under `timeout`) the next call picks up where
+    /// it stopped instead of reading from an unsubscribed socket.
     async fn ensure_connection(&mut self) -> Result<()> {
         if self.connection.is_none() {
             self.connect().await?;
+            if self.has_connected
+                && let Some(hook) = self.pre_resubscribe.as_mut()
+            {
+                hook(&mut self.subscribed_asset_ids);
+            }
+            self.has_connected = true;
+        }
+        if self.subscription_pending {
             self.send_subscription().await?;
+            self.subscription_pending = false;
+        }
+        if let Some(since) = self.disconnected_at {
+            self.backfill_gap(since).await;
+            self.disconnected_at = None;
         }
         Ok(())
     }
 
+    /// Queue Data API trades printed since `since` for every subscribed
+    /// asset whose market is known. Failures are logged; the live stream
+    /// resumes either way.
+    async fn backfill_gap(&mut self, since: DateTime<Utc>) {
+        let Some(data) = self.gap_backfill.clone() else {
+            return;
+        };
+        self.backfilled_trades.clear();
+
+        let mut markets: Vec<&String> = Vec::new();
+        for asset_id in &self.subscribed_asset_ids {
+            match self.asset_markets.get(asset_id) {
+                Some(market) if !markets.contains(&market) => markets.push(market),
+                Some(_) => {}
+                None => debug!("gap backfill skipped {}: market not seen yet", asset_id),
+            }
+        }
+
+        let mut trades = Vec::new();
+        for market in markets {
+            let query = TradeQuery {
+                start: Some(since),
+                ..TradeQuery::builder().market(vec![market.clone()])
+            };
+            match data.get_trades(&query).await {
+                Ok(page) => trades.extend(
+                    page.iter()
+                        .filter(|trade| self.subscribed_asset_ids.contains(&trade.token_id))
+                        .map(trade_from_data),
+                ),
+                Err(err) => warn!("gap backfill for {} failed: {}", market, err),
+            }
+        }
+
+        // Data API pages are newest first; replay in the order they printed.
+        trades.sort_by_key(|trade| timestamp_secs(&trade.timestamp));
+        for trade in trades {
+            *self.backfilled_trades.entry(trade_key(&trade)).or_default() += 1;
+            self.pending_events
+                .push_back(WssMarketEvent::LastTrade(trade));
+        }
+    }
+
     /// Subscribe to the market channel for the provided token/market IDs.
+    ///
+    /// Duplicate IDs are dropped, keeping the first occurrence, so each
+    /// asset is subscribed once.
     pub async fn subscribe(&mut self, asset_ids: Vec<String>) -> Result<()> {
-        self.subscribed_asset_ids = asset_ids;
+        self.subscribed_asset_ids = dedup_ids(asset_ids);
         self.ensure_connection().await?;
         self.send_subscription().await
     }
 
-    /// Read the next market channel event, reconnecting transparently when
-    /// the socket drops.
-    pub async fn next_event(&mut self) -> Result<WssMarketEvent> {
-        loop {
-            if let Some(evt) = self.pending_events.pop_front() {
-                return Ok(evt);
-            }
-            self.ensure_connection().await?;
+    /// Add assets to the subscription without touching the existing ones.
+    ///
+    /// IDs already subscribed are ignored. On a live connection only the new
+    /// IDs are sent, as a `subscribe` operation.
+    pub async fn add_assets(&mut self, asset_ids: Vec<String>) -> Result<()> {
+        let added: Vec<String> = dedup_ids(asset_ids)
+            .into_iter()
+            .filter(|id| !self.subscribed_asset_ids.contains(id))
+            .collect();
+        if added.is_empty() {
+            return Ok(());
+        }
+        self.subscribed_asset_ids.extend(added.iter().cloned());
+        if self.connection.is_none() {
+            // Connecting replays the whole set.
+            return self.ensure_connection().await;
+        }
+        self.send_raw_message(json!({"assets_ids": added, "operation": "subscribe"}))
+            .await
+    }
 
-            match self.connection.as_mut().unwrap().next().await {
-                Some(Ok(Message::Text(text))) => {
-                    let trimmed = text.trim();
-                    if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong")
-                    {
-                        continue;
+    /// Drop assets from the subscription, sending an `unsubscribe`
+    /// operation for them when connected. IDs that aren't subscribed are
+    /// ignored.
+    pub async fn unsubscribe(&mut self, asset_ids: &[String]) -> Result<()> {
+        let removed: Vec<String> = self
+            .subscribed_asset_ids
+            .iter()
+            .filter(|id| asset_ids.contains(id))
+            .cloned()
+            .collect();
+        if removed.is_empty() {
+            return Ok(());
+        }
+        self.subscribed_asset_ids.retain(|id| !removed.contains(id));
+        if self.connection.is_none() {
+            return Ok(());
+        }
+        self.send_raw_message(json!({"assets_ids": removed, "operation": "unsubscribe"}))
+            .await
+    }
+
+    /// Read exactly `n` events, then unsubscribe from every asset and return
+    /// them. `n == 0` returns immediately without connecting.
+    pub async fn take_events(&mut self, n: usize) -> Result<Vec<WssMarketEvent>> {
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut events = Vec::with_capacity(n);
+        while events.len() < n {
+            events.push(self.next_event().await?);
+        }
+
+        let asset_ids = self.subscribed_asset_ids.clone();
+        self.unsubscribe(&asset_ids).await?;
+        Ok(events)
+    }
+
+    /// Consume the client as a stream of events, for use with `select!`,
+    /// `merge` and other stream combinators. Reconnects stay transparent; the
+    /// stream yields the first error [`WssMarketClient::next_event`] returns
+    /// and then ends. See [`crate::feed::market_events`].
+    pub fn into_stream(self) -> impl Stream<Item = Result<WssMarketEvent>> + Send {
+        crate::feed::market_events(self)
+    }
+
+    /// Wait for the next `book` snapshot of `asset_id`, subscribing to it
+    /// first if needed (existing subscriptions are kept).
+    ///
+    /// Other events read while waiting are queued back, in order, for the
+    /// next [`WssMarketClient::next_event`] calls. Timing out, even in the
+    /// middle of a reconnect, leaves the subscription intact.
+    pub async fn get_first_book(&mut self, asset_id: &str, wait: Duration) -> Result<MarketBook> {
+        if !self.subscribed_asset_ids.iter().any(|id| id == asset_id) {
+            let mut asset_ids = self.subscribed_asset_ids.clone();
+            asset_ids.push(asset_id.to_string());
+            self.subscribe(asset_ids).await?;
+        }
+
+        let mut skipped = Vec::new();
+        let result = timeout(wait, async {
+            loop {
+                match self.next_event().await? {
+                    WssMarketEvent::Book(book) if book.asset_id == asset_id => return Ok(book),
+                    other => skipped.push(other),
+                }
+            }
+        })
+        .await;
+
+        for evt in skipped.into_iter().rev() {
+            self.pending_events.push_front(evt);
+        }
+        result.unwrap_or_else(|_| {
+            Err(PolyError::timeout(
+                wait,
+                format!("waiting for first book of {}", asset_id),
+            ))
+        })
+    }
+
+    /// Forward every event to `sink` until the stream or the sink fails.
+    ///
+    /// With auto-reconnect disabled, a closed socket ends the loop with
+    /// `Ok(())`; any other stream error is returned. Sink errors are handled
+    /// according to [`WssMarketClient::with_sink_error_policy`].
+    pub async fn pipe_to(&mut self, sink: impl EventSink) -> Result<()> {
+        loop {
+            let evt = match self.next_event().await {
+                Ok(evt) => evt,
+                Err(PolyError::Stream {
+                    kind: crate::errors::StreamErrorKind::Disconnected,
+                    ..
+                }) if !self.auto_reconnect => return Ok(()),
+                Err(err) => return Err(err),
+            };
+            if let Err(err) = sink.send(&evt).await {
+                match self.sink_error_policy {
+                    SinkErrorPolicy::Stop => return Err(err),
+                    SinkErrorPolicy::LogAndContinue => {
+                        warn!("event sink rejected {} event: {}", evt.event_type(), err);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Subscribe to a watchlist of token IDs stored on disk.
+    ///
+    /// The file may be a JSON array of strings or plain text with one ID per
+    /// line; blank lines and `#` comments are skipped. Every ID must be a
+    /// decimal token ID, otherwise nothing is subscribed.
+    pub async fn subscribe_from_file(&mut self, path: &Path) -> Result<()> {
+        let asset_ids = load_watchlist(path)?;
+        self.subscribe(asset_ids).await
+    }
+
+    /// Persist the current subscription set, one token ID per line, in a
+    /// format `subscribe_from_file` can read back.
+    pub fn save_subscriptions(&self, path: &Path) -> Result<()> {
+        let mut contents = self.subscribed_asset_ids.join("\n");
+        contents.push('\n');
+        std::fs::write(path, contents).map_err(|e| {
+            PolyError::internal(format!("Failed to write watchlist {}", path.display()), e)
+        })
+    }
+
+    fn note_disconnect(&mut self) {
+        let now = Utc::now();
+        self.disconnect_history.push_back(now);
+        if self.disconnect_history.len() > 5 {
+            self.disconnect_history.pop_front();
+        }
+        self.connection = None;
+        self.disconnected_at.get_or_insert(now);
+        if self.config.resync_on_reconnect {
+            self.awaiting_resync
+                .extend(self.last_hashes.keys().cloned());
+        }
+    }
+
+    /// Connection health between 0.0 (recreate the client) and 1.0.
+    ///
+    /// The score is the product of three factors, each in `[0, 1]`:
+    ///
+    /// - disconnects: `1 - d / 5`, where `d` is the number of disconnects in
+    ///   the last 10 minutes (at most the 5 that are remembered);
+    /// - errors: `1 - errors / (errors + messages_received)`;
+    /// - staleness: 1 until 30s without a message, then falling linearly to
+    ///   0 at 5 minutes. A client that has not received anything yet is not
+    ///   penalised.
+    pub fn health_score(&self) -> f64 {
+        self.health_score_at(Utc::now())
+    }
+
+    fn health_score_at(&self, now: DateTime<Utc>) -> f64 {
+        let window = chrono::Duration::from_std(HEALTH_DISCONNECT_WINDOW).unwrap_or_default();
+        let recent = self
+            .disconnect_history
+            .iter()
+            .filter(|at| now - **at <= window)
+            .count();
+        let disconnects = 1.0 - (recent as f64 / 5.0).min(1.0);
+
+        let observed = self.stats.errors + self.stats.messages_received;
+        let errors = if observed == 0 {
+            1.0
+        } else {
+            1.0 - self.stats.errors as f64 / observed as f64
+        };
+
+        let staleness = match self.stats.last_message_time {
+            None => 1.0,
+            Some(last) => {
+                let idle = (now - last).to_std().unwrap_or_default();
+                let stale = HEALTH_STALE_AFTER.as_secs_f64();
+                let dead = HEALTH_DEAD_AFTER.as_secs_f64();
+                1.0 - ((idle.as_secs_f64() - stale) / (dead - stale)).clamp(0.0, 1.0)
+            }
+        };
+
+        (disconnects * errors * staleness).clamp(0.0, 1.0)
+    }
+
+    /// Like [`WssMarketClient::next_event`], but give up after `dur` and
+    /// return `Ok(None)`. The connection is left open and the timeout is not
+    /// counted in [`WssStats`], so callers can run their own liveness checks
+    /// against `last_message_time`. A timeout that lands mid-reconnect is
+    /// resumed by the next call.
+    pub async fn next_event_timeout(&mut self, dur: Duration) -> Result<Option<WssMarketEvent>> {
+        match timeout(dur, self.next_event()).await {
+            Ok(result) => result.map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Read the next market channel event, reconnecting transparently when
+    /// the socket drops.
+    ///
+    /// Cancel-safe: dropping the future loses no events and leaves no
+    /// half-finished reconnect behind, so it can be raced in `select!` or
+    /// wrapped in `timeout`.
+    pub async fn next_event(&mut self) -> Result<WssMarketEvent> {
+        loop {
+            if let Some(evt) = self.pending_events.pop_front() {
+                return Ok(evt);
+            }
+            self.ensure_connection().await?;
+            // A reconnect may have queued backfilled trades.
+            if let Some(evt) = self.pending_events.pop_front() {
+                return Ok(evt);
+            }
+
+            match self.connection.as_mut().unwrap().next().await {
+                Some(Ok(Message::Text(text))) => {
+                    let trimmed = text.trim();
+                    if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong")
+                    {
+                        continue;
                     }
                     let first_char = trimmed.chars().next();
                     if first_char != Some('{') && first_char != Some('[') {
                         warn!("ignoring unexpected text frame: {}", trimmed);
                         continue;
                     }
-                    let events = parse_market_events(&text)?;
-                    self.stats.messages_received += events.len() as u64;
+                    let frames = parse_market_frames(&text)?;
+                    self.stats.messages_received += frames.len() as u64;
                     self.stats.last_message_time = Some(Utc::now());
-                    for evt in events {
-                        self.pending_events.push_back(evt);
+                    for frame in frames {
+                        match frame {
+                            MarketFrame::Event(evt) => self.record_frame(evt),
+                            MarketFrame::Ack(ack) => self.last_ack = Some(ack),
+                        }
                     }
                     if let Some(evt) = self.pending_events.pop_front() {
                         return Ok(evt);
@@ -359,28 +1445,87 @@ impl WssMarketClient {
                     }
                 }
                 Some(Ok(Message::Pong(_))) => {}
-                Some(Ok(Message::Close(_))) => {
-                    self.disconnect_history.push_back(Utc::now());
-                    if self.disconnect_history.len() > 5 {
-                        self.disconnect_history.pop_front();
+                Some(Ok(Message::Close(frame))) => {
+                    self.note_disconnect();
+                    let reason = WssCloseReason::from_frame(frame.as_ref());
+                    reason.log();
+                    match reason.action() {
+                        _ if !self.auto_reconnect => return Err(reason.into_error()),
+                        WssCloseAction::Fail => return Err(reason.into_error()),
+                        WssCloseAction::Backoff => sleep(self.reconnect_delay(1)).await,
+                        WssCloseAction::Reconnect => {}
                     }
-                    self.connection = None;
                 }
                 Some(Ok(_)) => {}
                 Some(Err(err)) => {
-                    warn!("WebSocket error: {}", err);
-                    self.connection = None;
+                    if let Some(suppressed) = self.reconnect_log.check() {
+                        warn!(
+                            "WebSocket error: {} ({} similar errors suppressed)",
+                            err, suppressed
+                        );
+                    }
+                    self.note_disconnect();
                     self.stats.errors += 1;
+                    if !self.auto_reconnect {
+                        return Err(disconnected_error(format!("WebSocket error: {}", err)));
+                    }
                     continue;
                 }
                 None => {
-                    self.connection = None;
+                    self.note_disconnect();
+                    if !self.auto_reconnect {
+                        return Err(disconnected_error("WebSocket stream ended"));
+                    }
                 }
             }
         }
     }
 }
 
+/// Convert a Data API trade into the shape the market channel uses. The
+/// Data API doesn't report fee rates, so the fee is left at zero.
+fn trade_from_data(trade: &Trade) -> LastTradeMessage {
+    LastTradeMessage {
+        event_type: "last_trade_price".to_string(),
+        asset_id: trade.token_id.clone(),
+        fee_rate_bps: "0".to_string(),
+        market: trade.condition_id.clone(),
+        price: trade.price,
+        size: trade.size,
+        side: trade.side,
+        timestamp: trade.timestamp.timestamp_millis().to_string(),
+    }
+}
+
+/// Seconds since the epoch for a second or millisecond timestamp string.
+fn timestamp_secs(raw: &str) -> Option<i64> {
+    event_time(raw).map(|time| time.timestamp())
+}
+
+/// Parse a second or millisecond epoch timestamp string.
+fn event_time(raw: &str) -> Option<DateTime<Utc>> {
+    let value: i64 = raw.trim().parse().ok()?;
+    if value > 100_000_000_000 {
+        DateTime::from_timestamp_millis(value)
+    } else {
+        DateTime::from_timestamp(value, 0)
+    }
+}
+
+/// Identity used to match a live trade against a backfilled one. The Data
+/// API only reports whole seconds, so the timestamp is compared at that
+/// precision; identical trades within a second are told apart by counting.
+fn trade_key(trade: &LastTradeMessage) -> String {
+    format!(
+        "{}:{}:{}:{}:{}",
+        trade.asset_id,
+        trade.side.as_str(),
+        trade.price.normalize(),
+        trade.size.normalize(),
+        timestamp_secs(&trade.timestamp).unwrap_or_default()
+    )
+}
+
 /// Reconnecting client for the authenticated user channel.
 pub struct WssUserClient {
     connect_url: String,
@@ -389,7 +1534,13 @@ pub struct WssUserClient {
     stats: WssStats,
     disconnect_history: VecDeque<DateTime<Utc>>,
     pending_events: VecDeque<WssUserEvent>,
-    auth: ApiCredentials,
+    auth: SharedCredentials,
+    connected_at: Option<Instant>,
+    received_since_connect: bool,
+    silent_closes: u32,
+    write_timeout: Duration,
+    auto_reconnect: bool,
+    reconnect_log: LogThrottle,
 }
 
 impl WssUserClient {
@@ -409,10 +1560,47 @@ impl WssUserClient {
             disconnect_history: VecDeque::with_capacity(5),
             connect_url,
             pending_events: VecDeque::new(),
-            auth,
+            auth: std::sync::Arc::new(std::sync::RwLock::new(auth)),
+            connected_at: None,
+            received_since_connect: false,
+            silent_closes: 0,
+            write_timeout: DEFAULT_WRITE_TIMEOUT,
+            auto_reconnect: true,
+            reconnect_log: LogThrottle::new(DEFAULT_RECONNECT_LOG_INTERVAL),
         }
     }
 
+    /// Limit connection failure warnings to one per `interval`; the next
+    /// warning reports how many were suppressed. Every attempt is still
+    /// logged at `debug`.
+    pub fn with_reconnect_log_interval(mut self, interval: Duration) -> Self {
+        self.reconnect_log = LogThrottle::new(interval);
+        self
+    }
+
+    /// When disabled, `next_event` surfaces a `StreamErrorKind::Disconnected`
+    /// error as soon as the socket closes instead of reconnecting. Enabled by
+    /// default.
+    pub fn with_auto_reconnect(mut self, enabled: bool) -> Self {
+        self.auto_reconnect = enabled;
+        self
+    }
+
+    /// Bound how long a single outbound frame may take to send. On timeout
+    /// the connection is dropped and re-established on the next call.
+    pub fn with_write_timeout(mut self, write_timeout: Duration) -> Self {
+        self.write_timeout = write_timeout;
+        self
+    }
+
+    /// Authenticate with credentials shared with a [`crate::ClobClient`]
+    /// (see [`crate::ClobClient::shared_credentials`]). Refreshed credentials
+    /// are picked up on the next (re)subscription.
+    pub fn with_shared_credentials(mut self, credentials: SharedCredentials) -> Self {
+        self.auth = credentials;
+        self
+    }
+
     /// Access connection stats for observability.
     pub fn stats(&self) -> WssStats {
         self.stats.clone()
@@ -423,12 +1611,13 @@ impl WssUserClient {
             return None;
         }
 
+        let auth = self.auth.read().unwrap_or_else(|e| e.into_inner());
         Some(json!({
             "type": "user",
             "auth": {
-                "apiKey": self.auth.api_key,
-                "secret": self.auth.secret,
-                "passphrase": self.auth.passphrase,
+                "apiKey": auth.api_key,
+                "secret": auth.secret,
+                "passphrase": auth.passphrase,
             },
             "markets": self.subscribed_markets,
         }))
@@ -443,28 +1632,40 @@ impl WssUserClient {
     }
 
     async fn send_raw_message(&mut self, message: Value) -> Result<()> {
-        if let Some(connection) = self.connection.as_mut() {
-            let text = serde_json::to_string(&message).map_err(|e| {
-                PolyError::parse(
-                    format!("Failed to serialize subscription message: {}", e),
-                    None,
-                )
-            })?;
-            connection
-                .send(Message::Text(text.into()))
-                .await
-                .map_err(|e| {
-                    PolyError::stream(
-                        format!("Failed to send message: {}", e),
-                        crate::errors::StreamErrorKind::MessageCorrupted,
-                    )
-                })?;
-            return Ok(());
+        let Some(connection) = self.connection.as_mut() else {
+            return Err(PolyError::stream(
+                "WebSocket connection not established",
+                crate::errors::StreamErrorKind::ConnectionFailed,
+            ));
+        };
+        let text = serde_json::to_string(&message).map_err(|e| {
+            PolyError::parse(
+                format!("Failed to serialize subscription message: {}", e),
+                None,
+            )
+        })?;
+
+        match timeout(
+            self.write_timeout,
+            connection.send(Message::Text(text.into())),
+        )
+        .await
+        {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(PolyError::stream(
+                format!("Failed to send message: {}", e),
+                crate::errors::StreamErrorKind::MessageCorrupted,
+            )),
+            Err(_) => {
+                // A stalled socket is unusable; drop it so the next call reconnects.
+                self.connection = None;
+                self.stats.errors += 1;
+                Err(PolyError::stream(
+                    format!("WebSocket send timed out after {:?}", self.write_timeout),
+                    crate::errors::StreamErrorKind::WriteTimeout,
+                ))
+            }
         }
-        Err(PolyError::stream(
-            "WebSocket connection not established",
-            crate::errors::StreamErrorKind::ConnectionFailed,
-        ))
     }
 
     async fn connect(&mut self) -> Result<()> {
@@ -482,6 +1683,13 @@ impl WssUserClient {
                     attempts += 1;
                     let delay = self.reconnect_delay(attempts);
                     self.stats.errors += 1;
+                    debug!("WebSocket connect attempt {} failed: {}", attempts, err);
+                    if let Some(suppressed) = self.reconnect_log.check() {
+                        warn!(
+                            "WebSocket connect failed: {} ({} similar failures suppressed)",
+                            err, suppressed
+                        );
+                    }
                     if attempts >= MAX_RECONNECT_ATTEMPTS {
                         return Err(PolyError::stream(
                             format!("Failed to connect after {} attempts: {}", attempts, err),
@@ -495,22 +1703,49 @@ impl WssUserClient {
     }
 
     fn reconnect_delay(&self, attempts: u32) -> Duration {
-        let millis = BASE_RECONNECT_DELAY.as_millis() * attempts as u128;
-
-        Duration::from_millis(millis.min(MAX_RECONNECT_DELAY.as_millis()) as u64)
+        WssConfig::default().reconnect_delay(attempts)
     }
 
     async fn ensure_connection(&mut self) -> Result<()> {
         if self.connection.is_none() {
             self.connect().await?;
+            self.connected_at = Some(Instant::now());
+            self.received_since_connect = false;
             self.send_subscription().await?;
         }
         Ok(())
     }
 
+    /// Record a dropped socket. A drop right after authenticating, with
+    /// nothing received, is how the server rejects bad credentials; after
+    /// [`MAX_SILENT_USER_CLOSES`] of those in a row this fails instead of
+    /// reconnecting forever.
+    fn note_disconnect(&mut self) -> Result<()> {
+        self.disconnect_history.push_back(Utc::now());
+        if self.disconnect_history.len() > 5 {
+            self.disconnect_history.pop_front();
+        }
+        self.connection = None;
+
+        let silent = !self.received_since_connect
+            && self
+                .connected_at
+                .is_some_and(|at| at.elapsed() < USER_AUTH_REJECT_WINDOW);
+        self.silent_closes = if silent { self.silent_closes + 1 } else { 0 };
+        if self.silent_closes >= MAX_SILENT_USER_CLOSES {
+            return Err(auth_rejected_error(format!(
+                "user channel closed right after authenticating {} times in a row",
+                self.silent_closes
+            )));
+        }
+        Ok(())
+    }
+
     /// Subscribe to the user channel for the provided market IDs.
+    ///
+    /// Duplicate IDs are dropped, keeping the first occurrence.
     pub async fn subscribe(&mut self, market_ids: Vec<String>) -> Result<()> {
-        self.subscribed_markets = market_ids;
+        self.subscribed_markets = dedup_ids(market_ids);
         self.ensure_connection().await?;
         self.send_subscription().await
     }
@@ -531,12 +1766,18 @@ impl WssUserClient {
                     {
                         continue;
                     }
+                    if let Some(reason) = auth_rejection(trimmed) {
+                        self.connection = None;
+                        return Err(auth_rejected_error(reason));
+                    }
                     let first_char = trimmed.chars().next();
                     if first_char != Some('{') && first_char != Some('[') {
                         warn!("ignoring unexpected text frame: {}", trimmed);
                         continue;
                     }
                     let events = parse_user_events(&text)?;
+                    self.received_since_connect = true;
+                    self.silent_closes = 0;
                     self.stats.messages_received += events.len() as u64;
                     self.stats.last_message_time = Some(Utc::now());
                     for evt in events {
@@ -553,22 +1794,37 @@ impl WssUserClient {
                     }
                 }
                 Ok(Some(Ok(Message::Pong(_)))) => {}
-                Ok(Some(Ok(Message::Close(_)))) => {
-                    self.disconnect_history.push_back(Utc::now());
-                    if self.disconnect_history.len() > 5 {
-                        self.disconnect_history.pop_front();
+                Ok(Some(Ok(Message::Close(frame)))) => {
+                    self.note_disconnect()?;
+                    let reason = WssCloseReason::from_frame(frame.as_ref());
+                    reason.log();
+                    match reason.action() {
+                        _ if !self.auto_reconnect => return Err(reason.into_error()),
+                        WssCloseAction::Fail => return Err(reason.into_error()),
+                        WssCloseAction::Backoff => sleep(self.reconnect_delay(1)).await,
+                        WssCloseAction::Reconnect => {}
                     }
-                    self.connection = None;
                 }
                 Ok(Some(Ok(_))) => {}
                 Ok(Some(Err(err))) => {
-                    warn!("WebSocket error: {}", err);
-                    self.connection = None;
+                    if let Some(suppressed) = self.reconnect_log.check() {
+                        warn!(
+                            "WebSocket error: {} ({} similar errors suppressed)",
+                            err, suppressed
+                        );
+                    }
                     self.stats.errors += 1;
+                    self.note_disconnect()?;
+                    if !self.auto_reconnect {
+                        return Err(disconnected_error(format!("WebSocket error: {}", err)));
+                    }
                     continue;
                 }
                 Ok(None) => {
-                    self.connection = None;
+                    self.note_disconnect()?;
+                    if !self.auto_reconnect {
+                        return Err(disconnected_error("WebSocket stream ended"));
+                    }
                 }
                 Err(_) => {
                     if let Some(connection) = self.connection.as_mut() {
@@ -580,17 +1836,280 @@ impl WssUserClient {
     }
 }
 
-fn parse_market_events(text: &str) -> Result<Vec<WssMarketEvent>> {
+/// Parse a watchlist file of token IDs (JSON array or one per line).
+pub fn load_watchlist(path: &Path) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        PolyError::internal(format!("Failed to read watchlist {}", path.display()), e)
+    })?;
+
+    let ids: Vec<String> = if contents.trim_start().starts_with('[') {
+        serde_json::from_str(&contents).map_err(|e| {
+            PolyError::parse(
+                format!("Invalid JSON watchlist {}: {}", path.display(), e),
+                Some(Box::new(e)),
+            )
+        })?
+    } else {
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect()
+    };
+
+    for id in &ids {
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(PolyError::validation(format!(
+                "Invalid token id in watchlist: {:?}",
+                id
+            )));
+        }
+    }
+
+    Ok(ids)
+}
+
+fn disconnected_error(message: impl Into<String>) -> PolyError {
+    PolyError::stream(message, crate::errors::StreamErrorKind::Disconnected)
+}
+
+fn auth_rejected_error(reason: impl fmt::Display) -> PolyError {
+    PolyError::stream(
+        format!("User channel rejected the API credentials: {}", reason),
+        crate::errors::StreamErrorKind::SubscriptionFailed,
+    )
+}
+
+/// The server's explanation if `text` rejects the user channel auth, either
+/// as bare text or as a JSON `error` frame.
+fn auth_rejection(text: &str) -> Option<String> {
+    let mentions_auth = |message: &str| {
+        let lower = message.to_ascii_lowercase();
+        lower.contains("auth") || lower.contains("api key") || lower.contains("apikey")
+    };
+    match serde_json::from_str::<Value>(text) {
+        Ok(value) => value
+            .get("error")
+            .and_then(Value::as_str)
+            .filter(|message| mentions_auth(message))
+            .map(str::to_string),
+        Err(_) => mentions_auth(text).then(|| text.to_string()),
+    }
+}
+
+/// Why the server closed the socket, parsed from its close frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WssCloseReason {
+    /// 1000: orderly shutdown.
+    Normal { reason: String },
+    /// 1001: the server is restarting or moving the connection elsewhere.
+    GoingAway { reason: String },
+    /// 1008: the server rejected something the client sent.
+    PolicyViolation { reason: String },
+    /// Any other close code.
+    Other { code: u16, reason: String },
+    /// Close without a frame, so no code.
+    Unspecified,
+}
+
+/// What a client does after the server closes the socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WssCloseAction {
+    /// Reconnect right away.
+    Reconnect,
+    /// Wait a reconnect delay, then reconnect.
+    Backoff,
+    /// Stop and return an error; reconnecting would be rejected again.
+    Fail,
+}
+
+impl WssCloseReason {
+    pub fn from_frame(frame: Option<&CloseFrame>) -> Self {
+        let Some(frame) = frame else {
+            return Self::Unspecified;
+        };
+        let reason = frame.reason.to_string();
+        match u16::from(frame.code) {
+            1000 => Self::Normal { reason },
+            1001 => Self::GoingAway { reason },
+            1008 => Self::PolicyViolation { reason },
+            code => Self::Other { code, reason },
+        }
+    }
+
+    pub fn code(&self) -> Option<u16> {
+        match self {
+            Self::Normal { .. } => Some(1000),
+            Self::GoingAway { .. } => Some(1001),
+            Self::PolicyViolation { .. } => Some(1008),
+            Self::Other { code, .. } => Some(*code),
+            Self::Unspecified => None,
+        }
+    }
+
+    pub fn action(&self) -> WssCloseAction {
+        match self {
+            Self::Normal { .. } | Self::GoingAway { .. } | Self::Unspecified => {
+                WssCloseAction::Reconnect
+            }
+            Self::PolicyViolation { .. } => WssCloseAction::Fail,
+            Self::Other { .. } => WssCloseAction::Backoff,
+        }
+    }
+
+    fn log(&self) {
+        match self.action() {
+            WssCloseAction::Reconnect => debug!("WebSocket closed by server: {}", self),
+            WssCloseAction::Backoff => warn!("WebSocket closed by server: {}", self),
+            WssCloseAction::Fail => error!("WebSocket closed by server: {}", self),
+        }
+    }
+
+    fn into_error(self) -> PolyError {
+        let kind = match self.action() {
+            WssCloseAction::Fail => crate::errors::StreamErrorKind::PolicyViolation,
+            _ => crate::errors::StreamErrorKind::Disconnected,
+        };
+        PolyError::stream(format!("WebSocket closed by server: {}", self), kind)
+    }
+}
+
+impl fmt::Display for WssCloseReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normal { reason }
+            | Self::GoingAway { reason }
+            | Self::PolicyViolation { reason }
+            | Self::Other { reason, .. } => {
+                write!(f, "code {}", self.code().unwrap_or_default())?;
+                if !reason.is_empty() {
+                    write!(f, " ({})", reason)?;
+                }
+                Ok(())
+            }
+            Self::Unspecified => write!(f, "no close code"),
+        }
+    }
+}
+
+/// Outcome of replaying a corpus of raw market channel frames.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseSummary {
+    /// Parsed events keyed by `event_type`.
+    pub counts: BTreeMap<String, usize>,
+    /// Keepalives and non-JSON frames that `next_event` skips.
+    pub ignored: usize,
+    /// Parse errors, prefixed with the zero-based frame index.
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for ParseSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (event_type, count) in &self.counts {
+            writeln!(f, "{}: {}", event_type, count)?;
+        }
+        writeln!(f, "ignored: {}", self.ignored)?;
+        for error in &self.errors {
+            writeln!(f, "error: {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run recorded market channel frames through the same parser used by
+/// [`WssMarketClient::next_event`] and summarize what came out.
+///
+/// Intended for regression tests: render the summary with `Display` and
+/// compare it against a committed golden file.
+pub fn replay_and_summarize(frames: &[String]) -> ParseSummary {
+    let mut summary = ParseSummary::default();
+    for (index, frame) in frames.iter().enumerate() {
+        let trimmed = frame.trim();
+        if !trimmed.starts_with('{') && !trimmed.starts_with('[') {
+            summary.ignored += 1;
+            continue;
+        }
+        match parse_market_frames(trimmed) {
+            Ok(frames) => {
+                for frame in frames {
+                    *summary
+                        .counts
+                        .entry(frame.event_type().to_string())
+                        .or_default() += 1;
+                }
+            }
+            Err(err) => summary.errors.push(format!("frame {}: {}", index, err)),
+        }
+    }
+    summary
+}
+
+/// Remove repeated IDs while preserving first-seen order.
+fn dedup_ids(ids: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::with_capacity(ids.len());
+    ids.into_iter()
+        .filter(|id| seen.insert(id.clone()))
+        .collect()
+}
+
+/// A decoded market channel frame: either data or subscription status.
+enum MarketFrame {
+    Event(WssMarketEvent),
+    Ack(SubscriptionAck),
+}
+
+impl MarketFrame {
+    fn event_type(&self) -> &'static str {
+        match self {
+            MarketFrame::Event(evt) => evt.event_type(),
+            MarketFrame::Ack(_) => "subscribed",
+        }
+    }
+}
+
+fn parse_market_frames(text: &str) -> Result<Vec<MarketFrame>> {
     let value: Value = serde_json::from_str(text)
         .map_err(|err| PolyError::parse(format!("Invalid JSON: {}", err), Some(Box::new(err))))?;
 
     if let Some(array) = value.as_array() {
         array
             .iter()
-            .map(parse_market_event_value)
+            .map(parse_market_frame_value)
             .collect::<Result<Vec<_>>>()
     } else {
-        Ok(vec![parse_market_event_value(&value)?])
+        Ok(vec![parse_market_frame_value(&value)?])
+    }
+}
+
+fn parse_market_frame_value(value: &Value) -> Result<MarketFrame> {
+    let event_type = value
+        .get("event_type")
+        .and_then(|v| v.as_str())
+        .or_else(|| value.get("type").and_then(|v| v.as_str()));
+
+    match event_type {
+        Some("subscribed") => {
+            let ack = serde_json::from_value::<SubscriptionAck>(value.clone()).map_err(|err| {
+                PolyError::parse(
+                    format!("Failed to parse subscription ack: {}", err),
+                    Some(Box::new(err)),
+                )
+            })?;
+            Ok(MarketFrame::Ack(ack))
+        }
+        Some("error") => {
+            let message = value
+                .get("message")
+                .or_else(|| value.get("error"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("no reason given");
+            Err(PolyError::stream(
+                format!("Subscription rejected: {}", message),
+                crate::errors::StreamErrorKind::SubscriptionFailed,
+            ))
+        }
+        _ => parse_market_event_value(value).map(MarketFrame::Event),
     }
 }
 
@@ -611,6 +2130,16 @@ fn parse_market_event_value(value: &Value) -> Result<WssMarketEvent> {
             })?;
             Ok(WssMarketEvent::Book(parsed))
         }
+        "agg_order_book" | "agg_orderbook" => {
+            let parsed =
+                serde_json::from_value::<AggBookMessage>(value.clone()).map_err(|err| {
+                    PolyError::parse(
+                        format!("Failed to parse aggregated book: {}", err),
+                        Some(Box::new(err)),
+                    )
+                })?;
+            Ok(WssMarketEvent::AggBook(parsed))
+        }
         "price_change" => {
             let parsed =
                 serde_json::from_value::<PriceChangeMessage>(value.clone()).map_err(|err| {
@@ -695,3 +2224,1426 @@ fn parse_user_event_value(value: &Value) -> Result<WssUserEvent> {
         )),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use tokio::net::TcpListener;
+
+    /// Accept a single websocket client and drain whatever it sends.
+    async fn spawn_draining_server() -> (String, tokio::task::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+        (format!("ws://{}", addr), handle)
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("polysqueeze-{}-{}", uuid::Uuid::new_v4(), name))
+    }
+
+    fn sample_book() -> MarketBook {
+        let level = |price: &str, size: &str| OrderSummary {
+            price: price.parse().unwrap(),
+            size: size.parse().unwrap(),
+        };
+        MarketBook {
+            event_type: "book".to_string(),
+            asset_id: "asset".to_string(),
+            market: "market".to_string(),
+            timestamp: "0".to_string(),
+            hash: "h".to_string(),
+            // Wire order: best bid and best ask last.
+            bids: vec![
+                level("0.40", "30"),
+                level("0.45", "20"),
+                level("0.48", "10"),
+            ],
+            asks: vec![
+                level("0.60", "30"),
+                level("0.55", "20"),
+                level("0.52", "10"),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_simulate_fill_full() {
+        let book = sample_book();
+        let buy = book.simulate_fill(Side::BUY, "25".parse().unwrap());
+        assert!(buy.is_complete());
+        assert_eq!(buy.filled, "25".parse().unwrap());
+        // 10 @ 0.52 + 15 @ 0.55 = 13.45
+        assert_eq!(buy.avg_price, Some("0.538".parse().unwrap()));
+        assert_eq!(buy.worst_price, Some("0.55".parse().unwrap()));
+
+        let sell = book.simulate_fill(Side::SELL, "10".parse().unwrap());
+        assert!(sell.is_complete());
+        assert_eq!(sell.avg_price, Some("0.48".parse().unwrap()));
+        assert_eq!(sell.worst_price, Some("0.48".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_simulate_fill_partial() {
+        let book = sample_book();
+        let sell = book.simulate_fill(Side::SELL, "100".parse().unwrap());
+        assert!(!sell.is_complete());
+        assert_eq!(sell.filled, "60".parse().unwrap());
+        assert_eq!(sell.unfilled, "40".parse().unwrap());
+        // 10 @ 0.48 + 20 @ 0.45 + 30 @ 0.40 = 25.8
+        assert_eq!(sell.avg_price, Some("0.43".parse().unwrap()));
+        assert_eq!(sell.worst_price, Some("0.40".parse().unwrap()));
+
+        let empty = MarketBook {
+            asks: Vec::new(),
+            ..book
+        };
+        let buy = empty.simulate_fill(Side::BUY, "5".parse().unwrap());
+        assert_eq!(buy.avg_price, None);
+        assert_eq!(buy.unfilled, "5".parse().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_from_file_round_trip() {
+        let path = temp_path("watchlist.txt");
+        std::fs::write(&path, "# my watchlist\n111\n\n  222  \n333\n").unwrap();
+
+        let (url, server) = spawn_draining_server().await;
+        let mut client = WssMarketClient::with_url(&url);
+        client.subscribe_from_file(&path).await.unwrap();
+        assert_eq!(client.subscribed_asset_ids(), ["111", "222", "333"]);
+
+        let saved = temp_path("saved.txt");
+        client.save_subscriptions(&saved).unwrap();
+        assert_eq!(load_watchlist(&saved).unwrap(), ["111", "222", "333"]);
+
+        std::fs::write(&path, r#"["444", "555"]"#).unwrap();
+        assert_eq!(load_watchlist(&path).unwrap(), ["444", "555"]);
+
+        std::fs::write(&path, "111\nnot-a-token\n").unwrap();
+        assert!(matches!(
+            load_watchlist(&path),
+            Err(PolyError::Validation { .. })
+        ));
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&saved);
+        server.abort();
+    }
+
+    fn open_order(id: &str, asset_id: &str, price: &str, side: &str) -> OpenOrder {
+        serde_json::from_value(json!({
+            "associate_trades": [],
+            "id": id,
+            "status": "LIVE",
+            "market": "0xmarket",
+            "original_size": "10",
+            "outcome": "Yes",
+            "maker_address": "0xmaker",
+            "owner": "owner",
+            "price": price,
+            "side": side,
+            "size_matched": "0",
+            "asset_id": asset_id,
+            "expiration": "0",
+            "type": "GTC",
+            "created_at": "0"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_tick_increase_flags_off_tick_orders() {
+        let change = match parse_market_event_value(&json!({
+            "event_type": "tick_size_change",
+            "asset_id": "123",
+            "market": "0xmarket",
+            "old_tick_size": "0.001",
+            "new_tick_size": "0.01",
+            "side": "BUY",
+            "timestamp": "1"
+        }))
+        .unwrap()
+        {
+            WssMarketEvent::TickSizeChange(change) => change,
+            other => panic!("unexpected event: {:?}", other),
+        };
+
+        let orders = vec![
+            open_order("on-tick", "123", "0.45", "BUY"),
+            open_order("fine-bid", "123", "0.457", "BUY"),
+            open_order("fine-ask", "123", "0.512", "SELL"),
+            open_order("other-asset", "456", "0.457", "BUY"),
+        ];
+
+        let flagged = change.off_tick_orders(&orders);
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged[0].order_id, "fine-bid");
+        assert_eq!(flagged[0].suggested_price.to_string(), "0.45");
+        assert_eq!(flagged[1].order_id, "fine-ask");
+        assert_eq!(flagged[1].suggested_price.to_string(), "0.52");
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_surfaces_when_auto_reconnect_disabled() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _subscription = ws.next().await;
+            ws.close(None).await.unwrap();
+        });
+
+        let mut client =
+            WssMarketClient::with_url(&format!("ws://{}", addr)).with_auto_reconnect(false);
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+
+        let err = client.next_event().await.unwrap_err();
+        assert!(matches!(
+            err,
+            PolyError::Stream {
+                kind: crate::errors::StreamErrorKind::Disconnected,
+                ..
+            }
+        ));
+        assert!(client.connection.is_none());
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_times_out_when_peer_never_reads() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            // Hold the socket open without ever draining it.
+            sleep(Duration::from_secs(30)).await;
+            drop(ws);
+        });
+
+        let mut client = WssMarketClient::with_url(&format!("ws://{}", addr))
+            .with_write_timeout(Duration::from_millis(200));
+        client.ensure_connection().await.unwrap();
+
+        // Large enough to overrun the kernel socket buffers on both ends.
+        let payload = json!({ "padding": "x".repeat(16 * 1024 * 1024) });
+        let err = client.send_raw_message(payload).await.unwrap_err();
+
+        assert!(matches!(
+            err,
+            PolyError::Stream {
+                kind: crate::errors::StreamErrorKind::WriteTimeout,
+                ..
+            }
+        ));
+        assert!(client.connection.is_none());
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_get_first_book_returns_matching_book() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _subscription = ws.next().await;
+            let book = |asset_id: &str| {
+                json!({
+                    "event_type": "book",
+                    "asset_id": asset_id,
+                    "market": "0xabc",
+                    "timestamp": "1",
+                    "hash": "h",
+                    "bids": [{"price": "0.40", "size": "10"}],
+                    "asks": []
+                })
+                .to_string()
+            };
+            ws.send(Message::Text(book("999").into())).await.unwrap();
+            ws.send(Message::Text(book("123").into())).await.unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let mut client = WssMarketClient::with_url(&format!("ws://{}", addr));
+        let book = client
+            .get_first_book("123", Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(book.asset_id, "123");
+        assert_eq!(book.bids.len(), 1);
+
+        // The unrelated book is still delivered.
+        match client.next_event().await.unwrap() {
+            WssMarketEvent::Book(book) => assert_eq!(book.asset_id, "999"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_get_first_book_times_out() {
+        let (url, server) = spawn_draining_server().await;
+        let mut client = WssMarketClient::with_url(&url);
+
+        let err = client
+            .get_first_book("123", Duration::from_millis(100))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Timeout { .. }));
+        assert_eq!(client.subscribed_asset_ids(), ["123"]);
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_get_first_book_timeout_mid_reconnect_keeps_subscription() {
+        let mut rest = mockito::Server::new_async().await;
+        let trades = crate::mock_wss::mock_stalled_backfill(&mut rest).await;
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url())
+            .with_gap_backfill(crate::mock_wss::gap_backfill_data(&rest.url()));
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+        server.push_json(crate::mock_wss::book_frame());
+        client.next_event().await.unwrap();
+        // The first connect subscribes twice.
+        let before = server.wait_for_subscriptions(2).await.len();
+
+        server.drop_connection();
+        let err = client
+            .get_first_book("123", Duration::from_millis(300))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Timeout { .. }));
+
+        server.push_json(crate::mock_wss::book_frame());
+        let book = client
+            .get_first_book("123", Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(book.asset_id, "123");
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            WssMarketEvent::LastTrade(_)
+        ));
+
+        trades.assert_async().await;
+        assert_eq!(server.connection_count(), 2);
+        assert_eq!(server.subscriptions().len(), before + 1);
+    }
+
+    #[tokio::test]
+    async fn test_pre_resubscribe_hook_edits_replayed_assets() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (replayed_tx, mut replayed_rx) = tokio::sync::mpsc::unbounded_channel();
+        let server = tokio::spawn(async move {
+            // First connection: take the subscription, then drop the client.
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _subscription = ws.next().await;
+            ws.close(None).await.unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+
+            // Second connection: report the replayed subscription.
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            if let Some(Ok(Message::Text(text))) = ws.next().await {
+                let value: Value = serde_json::from_str(&text).unwrap();
+                replayed_tx.send(value["assets_ids"].clone()).unwrap();
+            }
+            let trade = json!({
+                "event_type": "last_trade_price",
+                "asset_id": "111",
+                "fee_rate_bps": "0",
+                "market": "0xabc",
+                "price": "0.50",
+                "side": "BUY",
+                "size": "1",
+                "timestamp": "1"
+            });
+            ws.send(Message::Text(trade.to_string().into()))
+                .await
+                .unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let mut client = WssMarketClient::with_url(&format!("ws://{}", addr))
+            .with_pre_resubscribe(|assets| assets.retain(|id| id != "222"));
+        client
+            .subscribe(vec!["111".to_string(), "222".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(client.subscribed_asset_ids(), ["111", "222"]);
+
+        client.next_event().await.unwrap();
+        assert_eq!(client.subscribed_asset_ids(), ["111"]);
+        assert_eq!(replayed_rx.recv().await.unwrap(), json!(["111"]));
+        server.abort();
+    }
+
+    #[test]
+    fn test_user_client_reads_shared_credentials() {
+        let creds = |key: &str| ApiCredentials {
+            api_key: key.to_string(),
+            secret: "s".to_string(),
+            passphrase: "p".to_string(),
+        };
+        let shared = std::sync::Arc::new(std::sync::RwLock::new(creds("old-key")));
+        let mut client =
+            WssUserClient::new(creds("unused")).with_shared_credentials(shared.clone());
+        client.subscribed_markets = vec!["0xabc".to_string()];
+
+        *shared.write().unwrap() = creds("new-key");
+        let frame = client.format_subscription().unwrap();
+        assert_eq!(frame["auth"]["apiKey"], "new-key");
+    }
+
+    fn user_creds() -> ApiCredentials {
+        ApiCredentials {
+            api_key: "key".to_string(),
+            secret: "secret".to_string(),
+            passphrase: "pass".to_string(),
+        }
+    }
+
+    fn order_frame() -> Value {
+        json!({
+            "event_type": "order",
+            "asset_id": "123",
+            "id": "0xorder",
+            "market": "0xabc",
+            "order_owner": "owner",
+            "original_size": "10",
+            "outcome": "Yes",
+            "owner": "owner",
+            "price": "0.45",
+            "side": "BUY",
+            "size_matched": "0",
+            "timestamp": "1700000000",
+            "type": "PLACEMENT"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_user_channel_authenticates_and_delivers_order() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssUserClient::with_url(server.url(), user_creds());
+        client.subscribe(vec!["0xabc".to_string()]).await.unwrap();
+
+        let frames = server.wait_for_subscriptions(1).await;
+        assert_eq!(frames[0]["type"], "user");
+        assert_eq!(frames[0]["auth"]["apiKey"], "key");
+        assert_eq!(frames[0]["markets"], json!(["0xabc"]));
+
+        server.push_json(order_frame());
+        match client.next_event().await.unwrap() {
+            WssUserEvent::Order(order) => {
+                assert_eq!(order.id, "0xorder");
+                assert_eq!(order.price, dec!(0.45));
+                assert_eq!(order.message_type, "PLACEMENT");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_user_channel_auth_rejection_is_an_error() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssUserClient::with_url(server.url(), user_creds());
+        client.subscribe(vec!["0xabc".to_string()]).await.unwrap();
+
+        server.push("INVALID AUTHENTICATION");
+        let err = client.next_event().await.unwrap_err();
+        assert!(matches!(
+            err,
+            PolyError::Stream {
+                kind: crate::errors::StreamErrorKind::SubscriptionFailed,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_user_channel_stops_after_repeated_silent_closes() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssUserClient::with_url(server.url(), user_creds());
+        client.subscribe(vec!["0xabc".to_string()]).await.unwrap();
+        let before = server.wait_for_subscriptions(1).await.len();
+
+        // A drop after traffic reconnects and replays the auth frame.
+        server.drop_connection();
+        server.push_json(order_frame());
+        client.next_event().await.unwrap();
+        let frames = server.wait_for_subscriptions(before + 1).await;
+        assert_eq!(frames.last().unwrap()["auth"]["apiKey"], "key");
+
+        // The connection that delivered the order, then silent rejections.
+        for _ in 0..=MAX_SILENT_USER_CLOSES {
+            server.drop_connection();
+        }
+        let err = client.next_event().await.unwrap_err();
+        assert!(matches!(
+            err,
+            PolyError::Stream {
+                kind: crate::errors::StreamErrorKind::SubscriptionFailed,
+                ..
+            }
+        ));
+        assert_eq!(
+            server.connection_count(),
+            2 + MAX_SILENT_USER_CLOSES as usize
+        );
+    }
+
+    fn price_change(entries: &[(&str, &str, &str, &str)]) -> PriceChangeMessage {
+        let entries: Vec<Value> = entries
+            .iter()
+            .enumerate()
+            .map(|(i, (asset_id, side, price, size))| {
+                json!({
+                    "asset_id": asset_id,
+                    "price": price,
+                    "size": size,
+                    "side": side,
+                    "hash": format!("h{}", i + 1),
+                    "best_bid": "0",
+                    "best_ask": "0"
+                })
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "event_type": "price_change",
+            "market": "0xabc",
+            "timestamp": "2",
+            "price_changes": entries
+        }))
+        .unwrap()
+    }
+
+    fn local_snapshot() -> MarketBook {
+        serde_json::from_value(json!({
+            "event_type": "book",
+            "asset_id": "123",
+            "market": "0xabc",
+            "timestamp": "1",
+            "hash": "h0",
+            "bids": [{"price": "0.40", "size": "10"}, {"price": "0.45", "size": "5"}],
+            "asks": [{"price": "0.55", "size": "8"}, {"price": "0.50", "size": "3"}]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_local_book_applies_deltas() {
+        let mut book = LocalBook::from_snapshot(&local_snapshot()).unwrap();
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.45));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.50));
+
+        // New best bid, best ask taken out, deeper ask resized.
+        book.apply_price_change(&price_change(&[
+            ("123", "BUY", "0.47", "12"),
+            ("123", "SELL", "0.50", "0"),
+            ("123", "SELL", "0.55", "20"),
+            ("999", "BUY", "0.90", "1"),
+        ]))
+        .unwrap();
+
+        let best_bid = book.best_bid().unwrap();
+        assert_eq!((best_bid.price, best_bid.size), (dec!(0.47), dec!(12)));
+        let best_ask = book.best_ask().unwrap();
+        assert_eq!((best_ask.price, best_ask.size), (dec!(0.55), dec!(20)));
+        assert_eq!(book.hash(), "h3");
+
+        let (bids, asks) = book.depth(2);
+        let prices = |levels: &[OrderSummary]| levels.iter().map(|l| l.price).collect::<Vec<_>>();
+        assert_eq!(prices(&bids), [dec!(0.47), dec!(0.45)]);
+        assert_eq!(prices(&asks), [dec!(0.55)]);
+    }
+
+    #[test]
+    fn test_local_books_reports_missing_snapshot() {
+        let mut books = LocalBooks::new();
+        let change = price_change(&[("123", "BUY", "0.48", "1"), ("456", "BUY", "0.30", "1")]);
+
+        let err = books.apply_price_change(&change).unwrap_err();
+        assert!(matches!(
+            err,
+            PolyError::MarketData {
+                kind: crate::errors::MarketDataErrorKind::BookUnavailable,
+                ..
+            }
+        ));
+
+        books
+            .apply(&WssMarketEvent::Book(local_snapshot()))
+            .unwrap();
+        assert!(books.apply_price_change(&change).is_err());
+        assert_eq!(
+            books.get("123").unwrap().best_bid().unwrap().price,
+            dec!(0.48)
+        );
+        assert!(books.get("456").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_gap_backfill_replays_missed_trades_once() {
+        use crate::mock_wss::{BACKFILL_TRADE_SECS, data_trade};
+
+        let mut rest = mockito::Server::new_async().await;
+        let trades = rest
+            .mock("GET", "/trades")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "market".into(),
+                "0xabc".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!([
+                    data_trade("SELL", 0.56, 5.0, BACKFILL_TRADE_SECS + 1),
+                    data_trade("BUY", 0.55, 10.0, BACKFILL_TRADE_SECS),
+                    data_trade("BUY", 0.55, 10.0, BACKFILL_TRADE_SECS),
+                ])
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url())
+            .with_gap_backfill(crate::mock_wss::gap_backfill_data(&rest.url()));
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+        server.wait_for_subscriptions(1).await;
+        server.push_json(crate::mock_wss::book_frame());
+        client.next_event().await.unwrap();
+
+        server.drop_connection();
+        // The live stream repeats both identical gap trades before moving on.
+        let mut repeat = last_trade_frame(json!("0"));
+        repeat["timestamp"] = json!(format!("{}000", BACKFILL_TRADE_SECS));
+        server.push_json(repeat.clone());
+        server.push_json(repeat);
+        let mut next_live = last_trade_frame(json!("0"));
+        next_live["price"] = json!("0.57");
+        next_live["timestamp"] = json!(format!("{}000", BACKFILL_TRADE_SECS + 2));
+        server.push_json(next_live);
+
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            match client.next_event().await.unwrap() {
+                WssMarketEvent::LastTrade(trade) => {
+                    let secs = timestamp_secs(&trade.timestamp).unwrap() - BACKFILL_TRADE_SECS;
+                    seen.push((trade.price.to_string(), secs))
+                }
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+
+        trades.assert_async().await;
+        assert_eq!(
+            seen,
+            [
+                ("0.55".to_string(), 0),
+                ("0.55".to_string(), 0),
+                ("0.56".to_string(), 1),
+                ("0.57".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_health_score_degrades_with_bad_stats() {
+        let now = Utc::now();
+        let mut client = WssMarketClient::new();
+        assert_eq!(client.health_score_at(now), 1.0);
+
+        client.stats.messages_received = 100;
+        client.stats.last_message_time = Some(now - chrono::Duration::seconds(5));
+        let healthy = client.health_score_at(now);
+        assert_eq!(healthy, 1.0);
+
+        client.stats.errors = 25;
+        let with_errors = client.health_score_at(now);
+        assert!((with_errors - 0.8).abs() < 1e-9);
+
+        client
+            .disconnect_history
+            .push_back(now - chrono::Duration::minutes(1));
+        client
+            .disconnect_history
+            .push_back(now - chrono::Duration::hours(1));
+        let with_disconnects = client.health_score_at(now);
+        assert!((with_disconnects - 0.8 * 0.8).abs() < 1e-9);
+
+        client.stats.last_message_time = Some(now - chrono::Duration::seconds(165));
+        let stale = client.health_score_at(now);
+        assert!((stale - 0.8 * 0.8 * 0.5).abs() < 1e-9);
+
+        client.stats.last_message_time = Some(now - chrono::Duration::minutes(10));
+        assert_eq!(client.health_score_at(now), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_with_missed_updates_emits_resync() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let config = WssConfig {
+            resync_on_reconnect: true,
+            ..WssConfig::default()
+        };
+        let mut client = WssMarketClient::with_url(server.url()).with_config(config);
+        client
+            .subscribe(vec!["111".to_string(), "222".to_string()])
+            .await
+            .unwrap();
+        let book = |asset_id: &str, hash: &str| {
+            json!({
+                "event_type": "book", "asset_id": asset_id, "market": "0xabc",
+                "timestamp": "1", "hash": hash, "bids": [], "asks": []
+            })
+        };
+
+        server.push_json(book("111", "a1"));
+        server.push_json(book("222", "b1"));
+        client.next_event().await.unwrap();
+        client.next_event().await.unwrap();
+
+        server.close_with(1001, "restart");
+        server.push_json(book("111", "a2"));
+        server.push_json(book("222", "b1"));
+        match client.next_event().await.unwrap() {
+            WssMarketEvent::Resync { asset_id } => assert_eq!(asset_id, "111"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        match client.next_event().await.unwrap() {
+            WssMarketEvent::Book(book) => assert_eq!(book.hash, "a2"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        // 222's snapshot is unchanged, so nothing was missed.
+        match client.next_event().await.unwrap() {
+            WssMarketEvent::Book(book) => assert_eq!(book.asset_id, "222"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert_eq!(client.stats().dropped_gap_count, 1);
+        assert_eq!(server.connection_count(), 2);
+
+        // Only the first book after the reconnect is compared.
+        server.push_json(book("111", "a3"));
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            WssMarketEvent::Book(_)
+        ));
+        assert_eq!(client.stats().dropped_gap_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_into_stream_yields_events_across_reconnects() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        client.subscribe(vec!["111".to_string()]).await.unwrap();
+        let book = |hash: &str| {
+            json!({
+                "event_type": "book", "asset_id": "111", "market": "0xabc",
+                "timestamp": "1", "hash": hash, "bids": [], "asks": []
+            })
+        };
+
+        server.push_json(book("a1"));
+        server.push_json(book("a2"));
+        server.drop_connection();
+        server.push_json(book("a3"));
+
+        let hashes: Vec<_> = client
+            .into_stream()
+            .take(3)
+            .map(|evt| match evt.unwrap() {
+                WssMarketEvent::Book(book) => book.hash,
+                other => panic!("unexpected event: {:?}", other),
+            })
+            .collect()
+            .await;
+
+        assert_eq!(hashes, ["a1", "a2", "a3"]);
+        assert_eq!(server.connection_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resync_is_off_by_default() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        client.subscribe(vec!["111".to_string()]).await.unwrap();
+        let book = |hash: &str| {
+            json!({
+                "event_type": "book", "asset_id": "111", "market": "0xabc",
+                "timestamp": "1", "hash": hash, "bids": [], "asks": []
+            })
+        };
+
+        server.push_json(book("a1"));
+        client.next_event().await.unwrap();
+        server.drop_connection();
+        server.push_json(book("a2"));
+        match client.next_event().await.unwrap() {
+            WssMarketEvent::Book(book) => assert_eq!(book.hash, "a2"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert_eq!(client.stats().dropped_gap_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_add_assets_and_unsubscribe_maintain_set() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+        client.subscribe(ids(&["1", "2"])).await.unwrap();
+        client.add_assets(ids(&["2", "3"])).await.unwrap();
+        assert_eq!(client.subscribed_asset_ids(), ["1", "2", "3"]);
+
+        client.unsubscribe(&ids(&["1", "9"])).await.unwrap();
+        assert_eq!(client.subscribed_asset_ids(), ["2", "3"]);
+
+        let frames = server.wait_for_subscriptions(4).await;
+        let ops: Vec<_> = frames[2..]
+            .iter()
+            .map(|frame| (frame["operation"].clone(), frame["assets_ids"].clone()))
+            .collect();
+        assert_eq!(
+            ops,
+            [
+                (json!("subscribe"), json!(["3"])),
+                (json!("unsubscribe"), json!(["1"])),
+            ]
+        );
+
+        // A reconnect replays the reduced set.
+        server.drop_connection();
+        server.push_json(last_trade_frame(json!("0")));
+        client.next_event().await.unwrap();
+        let frames = server.wait_for_subscriptions(5).await;
+        assert_eq!(
+            frames.last().unwrap(),
+            &json!({"type": "market", "assets_ids": ["2", "3"]})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_take_events_reads_n_then_unsubscribes() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+
+        assert!(client.take_events(0).await.unwrap().is_empty());
+        assert_eq!(server.connection_count(), 0);
+
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+        for ts in 0..5 {
+            server.push_json(last_trade_frame(json!(ts.to_string())));
+        }
+
+        let events = client.take_events(3).await.unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(
+            events
+                .iter()
+                .all(|evt| matches!(evt, WssMarketEvent::LastTrade(_)))
+        );
+        assert!(client.subscribed_asset_ids().is_empty());
+
+        let frames = server.wait_for_subscriptions(3).await;
+        assert_eq!(
+            frames.last().unwrap(),
+            &json!({"assets_ids": ["123"], "operation": "unsubscribe"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_event_timeout_keeps_connection() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+
+        let started = Instant::now();
+        let evt = client
+            .next_event_timeout(Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert!(evt.is_none());
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(client.stats().errors, 0);
+
+        server.push_json(last_trade_frame(json!("0")));
+        let evt = client
+            .next_event_timeout(Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert!(matches!(evt, Some(WssMarketEvent::LastTrade(_))));
+        assert_eq!(server.connection_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_during_reconnect_resumes_subscription_and_backfill() {
+        let mut rest = mockito::Server::new_async().await;
+        let trades = crate::mock_wss::mock_stalled_backfill(&mut rest).await;
+
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url())
+            .with_gap_backfill(crate::mock_wss::gap_backfill_data(&rest.url()));
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+        server.push_json(crate::mock_wss::book_frame());
+        client.next_event().await.unwrap();
+        // The first connect subscribes twice.
+        let before = server.wait_for_subscriptions(2).await.len();
+
+        server.drop_connection();
+        let evt = client
+            .next_event_timeout(Duration::from_millis(300))
+            .await
+            .unwrap();
+        assert!(evt.is_none());
+        assert_eq!(server.connection_count(), 2);
+        server.wait_for_subscriptions(before + 1).await;
+
+        // The interrupted backfill is retried on the same socket.
+        let evt = client
+            .next_event_timeout(Duration::from_secs(5))
+            .await
+            .unwrap();
+        match evt {
+            Some(WssMarketEvent::LastTrade(trade)) => assert_eq!(
+                timestamp_secs(&trade.timestamp),
+                Some(crate::mock_wss::BACKFILL_TRADE_SECS)
+            ),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        trades.assert_async().await;
+        assert_eq!(server.connection_count(), 2);
+        assert_eq!(server.subscriptions().len(), before + 1);
+    }
+
+    #[test]
+    fn test_close_reason_decides_reconnect() {
+        use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+        let frame = |code: u16, reason: &str| CloseFrame {
+            code: CloseCode::from(code),
+            reason: reason.to_string().into(),
+        };
+
+        let going_away = WssCloseReason::from_frame(Some(&frame(1001, "restart")));
+        assert_eq!(
+            going_away,
+            WssCloseReason::GoingAway {
+                reason: "restart".to_string()
+            }
+        );
+        assert_eq!(going_away.action(), WssCloseAction::Reconnect);
+
+        let policy = WssCloseReason::from_frame(Some(&frame(1008, "bad subscription")));
+        assert_eq!(policy.action(), WssCloseAction::Fail);
+        assert_eq!(policy.to_string(), "code 1008 (bad subscription)");
+
+        let overloaded = WssCloseReason::from_frame(Some(&frame(1013, "")));
+        assert_eq!(overloaded.code(), Some(1013));
+        assert_eq!(overloaded.action(), WssCloseAction::Backoff);
+
+        assert_eq!(
+            WssCloseReason::from_frame(None).action(),
+            WssCloseAction::Reconnect
+        );
+    }
+
+    #[tokio::test]
+    async fn test_going_away_reconnects_and_policy_violation_fails() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url());
+        client.subscribe(vec!["111".to_string()]).await.unwrap();
+        server.wait_for_subscriptions(1).await;
+
+        server.close_with(1001, "going away");
+        server.push_json(json!({
+            "event_type": "book",
+            "asset_id": "111",
+            "market": "0xabc",
+            "timestamp": "1",
+            "hash": "h",
+            "bids": [],
+            "asks": []
+        }));
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            WssMarketEvent::Book(_)
+        ));
+        assert_eq!(server.connection_count(), 2);
+
+        server.close_with(1008, "policy violation");
+        let err = client.next_event().await.unwrap_err();
+        assert!(matches!(
+            err,
+            PolyError::Stream {
+                kind: crate::errors::StreamErrorKind::PolicyViolation,
+                ..
+            }
+        ));
+        assert!(!err.is_retryable());
+        assert_eq!(server.connection_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_outbound_observer_sees_subscription() {
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let sink = seen.clone();
+        let mut client = WssMarketClient::with_url(server.url())
+            .with_outbound_observer(move |text| sink.lock().unwrap().push(text.to_string()));
+
+        client.subscribe(vec!["111".to_string()]).await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert!(!seen.is_empty());
+        let frame: Value = serde_json::from_str(&seen[0]).unwrap();
+        assert_eq!(frame, json!({"type": "market", "assets_ids": ["111"]}));
+    }
+
+    #[tokio::test]
+    async fn test_top_of_book_mode_drops_books() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _subscription = ws.next().await;
+            let book = json!({
+                "event_type": "book",
+                "asset_id": "123",
+                "market": "0xabc",
+                "timestamp": "1",
+                "hash": "h",
+                "bids": [{"price": "0.40", "size": "10"}],
+                "asks": [{"price": "0.60", "size": "10"}]
+            });
+            let change = |ts: &str, bid: &str, ask: &str| {
+                json!({
+                    "event_type": "price_change",
+                    "market": "0xabc",
+                    "timestamp": ts,
+                    "price_changes": [{
+                        "asset_id": "123",
+                        "price": bid,
+                        "size": "5",
+                        "side": "BUY",
+                        "hash": "h",
+                        "best_bid": bid,
+                        "best_ask": ask
+                    }]
+                })
+            };
+            for frame in [
+                book,
+                change("2", "0.41", "0.60"),
+                change("3", "0.42", "0.58"),
+            ] {
+                ws.send(Message::Text(frame.to_string().into()))
+                    .await
+                    .unwrap();
+            }
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let mut client = WssMarketClient::with_url(&format!("ws://{}", addr))
+            .with_subscription_mode(SubscriptionMode::TopOfBook);
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+
+        for expected_ts in ["2", "3"] {
+            match client.next_event().await.unwrap() {
+                WssMarketEvent::PriceChange(change) => assert_eq!(change.timestamp, expected_ts),
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        assert!(client.pending_events.is_empty());
+
+        let top = client.top_of_book("123").unwrap();
+        assert_eq!(top.best_bid, "0.42".parse().unwrap());
+        assert_eq!(top.best_ask, "0.58".parse().unwrap());
+        assert_eq!(top.spread(), "0.16".parse().unwrap());
+        assert_eq!(top.timestamp, "3");
+        assert!(client.top_of_book("999").is_none());
+        server.abort();
+    }
+
+    /// Serve `count` last trade frames, then close the socket.
+    async fn spawn_trade_server(count: usize) -> (String, tokio::task::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _subscription = ws.next().await;
+            for i in 0..count {
+                let trade = json!({
+                    "event_type": "last_trade_price",
+                    "asset_id": "123",
+                    "fee_rate_bps": "0",
+                    "market": "0xabc",
+                    "price": "0.50",
+                    "side": "BUY",
+                    "size": "1",
+                    "timestamp": i.to_string()
+                });
+                ws.send(Message::Text(trade.to_string().into()))
+                    .await
+                    .unwrap();
+            }
+            ws.close(None).await.unwrap();
+            // Finish the close handshake so the client sees a clean close.
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+        (format!("ws://{}", addr), handle)
+    }
+
+    struct CountingSink {
+        received: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+        fail_on: Option<usize>,
+    }
+
+    #[async_trait]
+    impl EventSink for CountingSink {
+        async fn send(&self, _event: &WssMarketEvent) -> Result<()> {
+            let n = self
+                .received
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if Some(n) == self.fail_on {
+                return Err(PolyError::internal_simple("sink full"));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipe_to_forwards_all_events() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        for (policy, expected_received, expect_err) in [
+            (SinkErrorPolicy::LogAndContinue, 5, false),
+            (SinkErrorPolicy::Stop, 2, true),
+        ] {
+            let (url, server) = spawn_trade_server(5).await;
+            let received = std::sync::Arc::new(AtomicUsize::new(0));
+            let sink = CountingSink {
+                received: received.clone(),
+                fail_on: Some(1),
+            };
+            let mut client = WssMarketClient::with_url(&url)
+                .with_auto_reconnect(false)
+                .with_sink_error_policy(policy);
+            client.subscribe(vec!["123".to_string()]).await.unwrap();
+
+            let result = client.pipe_to(sink).await;
+            assert_eq!(result.is_err(), expect_err, "{:?}", policy);
+            assert_eq!(received.load(Ordering::SeqCst), expected_received);
+            server.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipe_to_mpsc_sink() {
+        let (url, server) = spawn_trade_server(3).await;
+        let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+        let mut client = WssMarketClient::with_url(&url).with_auto_reconnect(false);
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+
+        client.pipe_to(tx).await.unwrap();
+        let mut seen = Vec::new();
+        while let Some(evt) = rx.recv().await {
+            seen.push(evt.event_type());
+        }
+        assert_eq!(seen, ["last_trade_price"; 3]);
+        server.abort();
+    }
+
+    #[test]
+    fn test_parse_aggregated_book() {
+        let event = parse_market_event_value(&json!({
+            "event_type": "agg_order_book",
+            "asset_id": "123",
+            "market": "0xabc",
+            "timestamp": 1700000000000u64,
+            "bids": [{"price": "0.40", "size": "250"}],
+            "asks": [{"price": "0.60", "size": "125"}]
+        }))
+        .unwrap();
+
+        match event {
+            WssMarketEvent::AggBook(book) => {
+                assert_eq!(book.asset_id, "123");
+                assert_eq!(book.timestamp, "1700000000000");
+                assert_eq!(book.hash, None);
+                assert_eq!(book.bids.len(), 1);
+                assert_eq!(book.asks[0].size.to_string(), "125");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_dedups_asset_ids() {
+        let (url, server) = spawn_draining_server().await;
+        let mut client = WssMarketClient::with_url(&url);
+
+        let ids = ["222", "111", "222", "333", "111"]
+            .map(String::from)
+            .to_vec();
+        client.subscribe(ids).await.unwrap();
+
+        assert_eq!(client.subscribed_asset_ids(), ["222", "111", "333"]);
+        assert_eq!(
+            client.format_subscription()["assets_ids"],
+            json!(["222", "111", "333"])
+        );
+        server.abort();
+    }
+
+    #[test]
+    fn test_subscription_frames_are_not_data_events() {
+        let frames =
+            parse_market_frames(r#"{"event_type": "subscribed", "assets_ids": ["123", "456"]}"#)
+                .unwrap();
+        assert_eq!(frames.len(), 1);
+        match &frames[0] {
+            MarketFrame::Ack(ack) => assert_eq!(ack.markets, vec!["123", "456"]),
+            MarketFrame::Event(evt) => panic!("ack surfaced as event: {:?}", evt),
+        }
+
+        let err = parse_market_frames(r#"{"event_type": "error", "message": "bad asset"}"#)
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            PolyError::Stream {
+                kind: crate::errors::StreamErrorKind::SubscriptionFailed,
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("bad asset"));
+    }
+
+    #[tokio::test]
+    async fn test_next_event_consumes_subscription_ack() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _subscription = ws.next().await;
+            ws.send(Message::Text(
+                r#"{"event_type": "subscribed", "assets_ids": ["123"]}"#.into(),
+            ))
+            .await
+            .unwrap();
+            ws.send(Message::Text(
+                json!({
+                    "event_type": "book",
+                    "asset_id": "123",
+                    "market": "0xabc",
+                    "timestamp": "1",
+                    "hash": "h",
+                    "bids": [],
+                    "asks": []
+                })
+                .to_string()
+                .into(),
+            ))
+            .await
+            .unwrap();
+            let _ = ws.next().await;
+        });
+
+        let mut client = WssMarketClient::with_url(&format!("ws://{}", addr));
+        client.subscribe(vec!["123".to_string()]).await.unwrap();
+
+        match client.next_event().await.unwrap() {
+            WssMarketEvent::Book(book) => assert_eq!(book.asset_id, "123"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert_eq!(
+            client.last_subscription_ack(),
+            Some(&SubscriptionAck {
+                markets: vec!["123".to_string()]
+            })
+        );
+        server.abort();
+    }
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_failures_warn_once_per_interval() {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        // Nothing listens on port 1, so every attempt is refused immediately.
+        let mut client = WssMarketClient::with_url("ws://127.0.0.1:1")
+            .with_max_reconnect_attempts(4)
+            .with_reconnect_log_interval(Duration::from_secs(3600));
+        assert!(client.connect().await.is_err());
+
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        let warns = output.lines().filter(|l| l.contains("WARN")).count();
+        let debugs = output
+            .lines()
+            .filter(|l| l.contains("DEBUG") && l.contains("connect attempt"))
+            .count();
+        assert_eq!(warns, 1);
+        assert_eq!(debugs, 4);
+    }
+
+    #[test]
+    fn test_reconnect_delay_grows_exponentially_and_caps() {
+        let config = WssConfig {
+            base_reconnect_delay: Duration::from_millis(100),
+            max_reconnect_delay: Duration::from_secs(1),
+            ..WssConfig::default()
+        };
+        let delays: Vec<_> = (1..=6)
+            .map(|n| config.reconnect_delay(n).as_millis())
+            .collect();
+        assert_eq!(delays, [100, 200, 400, 800, 1000, 1000]);
+        assert_eq!(config.reconnect_delay(u32::MAX), Duration::from_secs(1));
+
+        let client = WssMarketClient::new().with_config(config.clone());
+        assert_eq!(client.reconnect_delay(3), Duration::from_millis(400));
+
+        let jittered = WssConfig {
+            jitter: 0.5,
+            ..config
+        };
+        for _ in 0..20 {
+            let delay = jittered.reconnect_delay(3);
+            assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_initial_connect_and_reconnect_use_separate_limits() {
+        let mut client = WssMarketClient::with_url("ws://127.0.0.1:1")
+            .with_initial_connect_attempts(1)
+            .with_reconnect_attempts(3);
+        let err = client.subscribe(vec!["1".to_string()]).await.unwrap_err();
+        assert!(err.to_string().contains("after 1 attempts"), "{}", err);
+
+        let server = crate::mock_wss::MockWssServer::start().await;
+        let mut client = WssMarketClient::with_url(server.url())
+            .with_initial_connect_attempts(1)
+            .with_reconnect_attempts(3);
+        client.subscribe(vec!["1".to_string()]).await.unwrap();
+        // Stopping the server closes the socket and the listener, so every
+        // reconnect attempt is refused.
+        drop(server);
+        let err = client.next_event().await.unwrap_err();
+        assert!(err.to_string().contains("after 3 attempts"), "{}", err);
+    }
+
+    fn last_trade_frame(fee_rate_bps: Value) -> Value {
+        json!({
+            "event_type": "last_trade_price",
+            "asset_id": "123",
+            "fee_rate_bps": fee_rate_bps,
+            "market": "0xabc",
+            "price": "0.55",
+            "size": "10",
+            "side": "BUY",
+            "timestamp": "1700000000000"
+        })
+    }
+
+    #[test]
+    fn test_market_event_timestamp_for_every_variant() {
+        let millis = DateTime::from_timestamp_millis(1_700_000_000_123).unwrap();
+        let secs = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let frames = [
+            json!({"event_type": "book", "asset_id": "1", "market": "m",
+                   "timestamp": "1700000000123", "hash": "h", "bids": [], "asks": []}),
+            json!({"event_type": "agg_orderbook", "asset_id": "1", "market": "m",
+                   "timestamp": 1700000000123u64}),
+            json!({"event_type": "price_change", "market": "m", "price_changes": [],
+                   "timestamp": "1700000000123"}),
+            json!({"event_type": "tick_size_change", "asset_id": "1", "market": "m",
+                   "old_tick_size": "0.01", "new_tick_size": "0.001", "side": "BUY",
+                   "timestamp": "1700000000"}),
+            last_trade_frame(json!("0")),
+        ];
+        let parsed: Vec<_> = frames
+            .iter()
+            .map(|frame| parse_market_event_value(frame).unwrap().timestamp())
+            .collect();
+        assert_eq!(
+            parsed,
+            [
+                Some(millis),
+                Some(millis),
+                Some(millis),
+                Some(secs),
+                Some(secs)
+            ]
+        );
+
+        let resync = WssMarketEvent::Resync {
+            asset_id: "1".to_string(),
+        };
+        assert_eq!(resync.timestamp(), None);
+
+        let mut trade = parse_last_trade(&last_trade_frame(json!("0")));
+        trade.timestamp = "not a time".to_string();
+        assert_eq!(WssMarketEvent::LastTrade(trade).timestamp(), None);
+    }
+
+    fn parse_last_trade(value: &Value) -> LastTradeMessage {
+        match parse_market_event_value(value).unwrap() {
+            WssMarketEvent::LastTrade(trade) => trade,
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_fee_rate_bps_from_string() {
+        let trade = parse_last_trade(&last_trade_frame(json!("25")));
+        assert_eq!(trade.fee_rate_bps, "25");
+        assert_eq!(trade.fee_rate_bps_value(), Some(25));
+    }
+
+    #[test]
+    fn test_fee_rate_bps_from_number() {
+        let trade = parse_last_trade(&last_trade_frame(json!(0)));
+        assert_eq!(trade.fee_rate_bps, "0");
+        assert_eq!(trade.fee_rate_bps_value(), Some(0));
+    }
+
+    #[test]
+    fn test_fee_rate_bps_malformed_is_none() {
+        let trade = parse_last_trade(&last_trade_frame(json!("abc")));
+        assert_eq!(trade.fee_rate_bps_value(), None);
+    }
+}
@@ -3,7 +3,7 @@ use polysqueeze::errors::Result;
 use std::env;
 
 fn env_var(key: &str) -> String {
-    env::var(key).expect(&format!("{} must be set for auth test", key))
+    env::var(key).unwrap_or_else(|_| panic!("{} must be set for auth test", key))
 }
 
 fn should_run() -> bool {
@@ -0,0 +1,28 @@
+use polysqueeze::snapshot_market;
+use std::env;
+
+#[tokio::test]
+async fn snapshot_market_live() {
+    let Ok(condition_id) = env::var("SNAPSHOT_CONDITION_ID") else {
+        eprintln!("Skipping snapshot_market live test (set SNAPSHOT_CONDITION_ID)");
+        return;
+    };
+
+    let snapshot = snapshot_market(&condition_id)
+        .await
+        .expect("snapshot_market failed");
+
+    assert_eq!(snapshot.market.condition_id, condition_id);
+    assert_eq!(
+        snapshot.yes_book.asset_id,
+        snapshot.market.tokens[0].token_id
+    );
+    assert_eq!(
+        snapshot.no_book.asset_id,
+        snapshot.market.tokens[1].token_id
+    );
+    println!(
+        "midpoint={:?} last_trade={:?}",
+        snapshot.midpoint, snapshot.last_trade
+    );
+}
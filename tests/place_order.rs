@@ -3,11 +3,10 @@ use polysqueeze::errors::Result;
 use polysqueeze::types::{OrderType, PostOrder, Side};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromStr;
-use serde_json;
 use std::env;
 
 fn env_var(key: &str) -> String {
-    env::var(key).expect(&format!("{} must be set for place_order test", key))
+    env::var(key).unwrap_or_else(|_| panic!("{} must be set for place_order test", key))
 }
 
 fn should_run() -> bool {
@@ -0,0 +1,21 @@
+use polysqueeze::wss::replay_and_summarize;
+
+const FRAMES: &str = include_str!("fixtures/market_frames.jsonl");
+const GOLDEN: &str = include_str!("fixtures/market_frames.golden");
+
+#[test]
+fn replayed_frames_match_golden_summary() {
+    let frames: Vec<String> = FRAMES
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::to_string)
+        .collect();
+
+    let summary = replay_and_summarize(&frames);
+
+    assert_eq!(
+        summary.to_string(),
+        GOLDEN,
+        "parse summary drifted from tests/fixtures/market_frames.golden"
+    );
+}